@@ -33,8 +33,8 @@ pub mod transaction;
 pub use transaction::BlobTransactionValidationError;
 pub use transaction::{
     SignableTransaction, Transaction, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant,
-    TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxSeismic, TxType, Typed2718,
-    TypedTransaction,
+    TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxSeismic, TxSeismicEip1559, TxType,
+    Typed2718, TypedTransaction,
 };
 
 pub use alloy_eips::eip4844::{
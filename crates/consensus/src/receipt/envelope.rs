@@ -45,8 +45,11 @@ pub enum ReceiptEnvelope<T = Log> {
     #[cfg_attr(feature = "serde", serde(rename = "0x4", alias = "0x04"))]
     Eip7702(ReceiptWithBloom<Receipt<T>>),
     /// Receipt envelope with type flag 4A, containing a [Seismic] receipt.
-    #[cfg_attr(feature = "serde", serde(rename = "0x4A", alias = "0x04A"))]
+    #[cfg_attr(feature = "serde", serde(rename = "0x4a", alias = "0x4A", alias = "0x04A"))]
     Seismic(ReceiptWithBloom<Receipt<T>>),
+    /// Receipt envelope with type flag 4B, containing a dynamic-fee [Seismic] receipt.
+    #[cfg_attr(feature = "serde", serde(rename = "0x4b", alias = "0x4B", alias = "0x04B"))]
+    SeismicEip1559(ReceiptWithBloom<Receipt<T>>),
 }
 
 impl<T> ReceiptEnvelope<T> {
@@ -56,6 +59,7 @@ impl<T> ReceiptEnvelope<T> {
         match self {
             Self::Legacy(_) => TxType::Legacy,
             Self::Seismic(_) => TxType::Seismic,
+            Self::SeismicEip1559(_) => TxType::SeismicEip1559,
             Self::Eip2930(_) => TxType::Eip2930,
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
@@ -94,6 +98,7 @@ impl<T> ReceiptEnvelope<T> {
         match self {
             Self::Legacy(t)
             | Self::Seismic(t)
+            | Self::SeismicEip1559(t)
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
@@ -107,6 +112,7 @@ impl<T> ReceiptEnvelope<T> {
         match self {
             Self::Legacy(t)
             | Self::Seismic(t)
+            | Self::SeismicEip1559(t)
             | Self::Eip2930(t)
             | Self::Eip1559(t)
             | Self::Eip4844(t)
@@ -187,6 +193,7 @@ impl Encodable2718 for ReceiptEnvelope {
         match self {
             Self::Legacy(_) => None,
             Self::Seismic(_) => Some(TxType::Seismic as u8),
+            Self::SeismicEip1559(_) => Some(TxType::SeismicEip1559 as u8),
             Self::Eip2930(_) => Some(TxType::Eip2930 as u8),
             Self::Eip1559(_) => Some(TxType::Eip1559 as u8),
             Self::Eip4844(_) => Some(TxType::Eip4844 as u8),
@@ -217,6 +224,7 @@ impl Decodable2718 for ReceiptEnvelope {
             TxType::Eip7702 => Ok(Self::Eip7702(receipt)),
             TxType::Legacy => Err(Eip2718Error::UnexpectedType(0)),
             TxType::Seismic => Ok(Self::Seismic(receipt)),
+            TxType::SeismicEip1559 => Ok(Self::SeismicEip1559(receipt)),
         }
     }
 
@@ -240,6 +248,7 @@ where
             3 => Ok(Self::Eip4844(receipt)),
             4 => Ok(Self::Eip7702(receipt)),
             74 => Ok(Self::Seismic(receipt)),
+            75 => Ok(Self::SeismicEip1559(receipt)),
             _ => unreachable!(),
         }
     }
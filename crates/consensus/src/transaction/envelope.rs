@@ -8,7 +8,7 @@ use crate::{
 use alloy_eips::{
     eip2718::{Decodable2718, Eip2718Error, Eip2718Result, Encodable2718},
     eip2930::AccessList,
-    eip712::{Decodable712, Eip712Result, TypedDataRequest},
+    eip712::{Decodable712, Eip712Result, Encodable712, TypedDataRequest},
 };
 use alloy_primitives::{
     Bytes, ChainId, PrimitiveSignature as Signature, TxKind, B256, U256, U64, U8,
@@ -16,7 +16,7 @@ use alloy_primitives::{
 use alloy_rlp::{Decodable, Encodable};
 use core::fmt;
 
-use super::{SignableTransaction, TxSeismic};
+use super::{SignableTransaction, TxSeismic, TxSeismicEip1559};
 
 /// Ethereum `TransactionType` flags as specified in EIPs [2718], [1559], [2930],
 /// [4844], and [7702].
@@ -45,6 +45,8 @@ pub enum TxType {
     Eip7702 = 4,
     /// Seismic transaction type
     Seismic = 0x4A,
+    /// Dynamic-fee seismic transaction type
+    SeismicEip1559 = 0x4B,
 }
 
 impl From<TxType> for u8 {
@@ -64,6 +66,7 @@ impl fmt::Display for TxType {
         match self {
             Self::Legacy => write!(f, "Legacy"),
             Self::Seismic => write!(f, "Seismic"),
+            Self::SeismicEip1559 => write!(f, "Seismic EIP-1559"),
             Self::Eip2930 => write!(f, "EIP-2930"),
             Self::Eip1559 => write!(f, "EIP-1559"),
             Self::Eip4844 => write!(f, "EIP-4844"),
@@ -102,6 +105,7 @@ impl TryFrom<u8> for TxType {
             3 => Self::Eip4844,
             4 => Self::Eip7702,
             74 => Self::Seismic,
+            75 => Self::SeismicEip1559,
             _ => return Err(Eip2718Error::UnexpectedType(value)),
         })
     }
@@ -173,6 +177,8 @@ pub enum TxEnvelope {
     Legacy(Signed<TxLegacy>),
     /// An untagged [`TxSeismic`].
     Seismic(Signed<TxSeismic>),
+    /// A [`TxSeismicEip1559`] tagged with type 0x4B.
+    SeismicEip1559(Signed<TxSeismicEip1559>),
     /// A [`TxEip2930`] tagged with type 1.
     Eip2930(Signed<TxEip2930>),
     /// A [`TxEip1559`] tagged with type 2.
@@ -239,6 +245,12 @@ impl From<Signed<TxSeismic>> for TxEnvelope {
     }
 }
 
+impl From<Signed<TxSeismicEip1559>> for TxEnvelope {
+    fn from(v: Signed<TxSeismicEip1559>) -> Self {
+        Self::SeismicEip1559(v)
+    }
+}
+
 impl TxEnvelope {
     /// Returns true if the transaction is a legacy transaction.
     #[inline]
@@ -252,6 +264,12 @@ impl TxEnvelope {
         matches!(self, Self::Seismic(_))
     }
 
+    /// Returns true if the transaction is a dynamic-fee seismic transaction.
+    #[inline]
+    pub const fn is_seismic_eip1559(&self) -> bool {
+        matches!(self, Self::SeismicEip1559(_))
+    }
+
     /// Returns true if the transaction is an EIP-2930 transaction.
     #[inline]
     pub const fn is_eip2930(&self) -> bool {
@@ -308,6 +326,15 @@ impl TxEnvelope {
         }
     }
 
+    /// Returns the [`TxSeismicEip1559`] variant if the transaction is a dynamic-fee seismic
+    /// transaction.
+    pub const fn as_seismic_eip1559(&self) -> Option<&Signed<TxSeismicEip1559>> {
+        match self {
+            Self::SeismicEip1559(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
     /// Returns the [`TxEip2930`] variant if the transaction is an EIP-2930 transaction.
     pub const fn as_eip2930(&self) -> Option<&Signed<TxEip2930>> {
         match self {
@@ -347,7 +374,8 @@ impl TxEnvelope {
     ) -> Result<alloy_primitives::Address, alloy_primitives::SignatureError> {
         match self {
             Self::Legacy(tx) => tx.recover_signer(),
-            Self::Seismic(tx) => tx.recover_signer(),
+            Self::Seismic(tx) => tx.recover_caller(),
+            Self::SeismicEip1559(tx) => tx.recover_caller(),
             Self::Eip2930(tx) => tx.recover_signer(),
             Self::Eip1559(tx) => tx.recover_signer(),
             Self::Eip4844(tx) => tx.recover_signer(),
@@ -360,6 +388,7 @@ impl TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.signature_hash(),
             Self::Seismic(tx) => tx.signature_hash(),
+            Self::SeismicEip1559(tx) => tx.signature_hash(),
             Self::Eip2930(tx) => tx.signature_hash(),
             Self::Eip1559(tx) => tx.signature_hash(),
             Self::Eip4844(tx) => tx.signature_hash(),
@@ -372,6 +401,7 @@ impl TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.signature(),
             Self::Seismic(tx) => tx.signature(),
+            Self::SeismicEip1559(tx) => tx.signature(),
             Self::Eip2930(tx) => tx.signature(),
             Self::Eip1559(tx) => tx.signature(),
             Self::Eip4844(tx) => tx.signature(),
@@ -385,6 +415,7 @@ impl TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.hash(),
             Self::Seismic(tx) => tx.hash(),
+            Self::SeismicEip1559(tx) => tx.hash(),
             Self::Eip2930(tx) => tx.hash(),
             Self::Eip1559(tx) => tx.hash(),
             Self::Eip4844(tx) => tx.hash(),
@@ -398,6 +429,7 @@ impl TxEnvelope {
         match self {
             Self::Legacy(_) => TxType::Legacy,
             Self::Seismic(_) => TxType::Seismic,
+            Self::SeismicEip1559(_) => TxType::SeismicEip1559,
             Self::Eip2930(_) => TxType::Eip2930,
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
@@ -410,6 +442,7 @@ impl TxEnvelope {
         match self {
             Self::Legacy(t) => t.eip2718_encoded_length(),
             Self::Seismic(t) => t.eip2718_encoded_length(),
+            Self::SeismicEip1559(t) => t.eip2718_encoded_length(),
             Self::Eip2930(t) => t.eip2718_encoded_length(),
             Self::Eip1559(t) => t.eip2718_encoded_length(),
             Self::Eip4844(t) => t.eip2718_encoded_length(),
@@ -443,6 +476,7 @@ impl Decodable2718 for TxEnvelope {
             TxType::Eip7702 => Ok(TxEip7702::rlp_decode_signed(buf)?.into()),
             TxType::Legacy => Err(Eip2718Error::UnexpectedType(0)),
             TxType::Seismic => Ok(TxSeismic::rlp_decode_signed(buf)?.into()),
+            TxType::SeismicEip1559 => Ok(TxSeismicEip1559::rlp_decode_signed(buf)?.into()),
         }
     }
 
@@ -456,6 +490,7 @@ impl Encodable2718 for TxEnvelope {
         match self {
             Self::Legacy(_) => None,
             Self::Seismic(_) => Some(TxType::Seismic.into()),
+            Self::SeismicEip1559(_) => Some(TxType::SeismicEip1559.into()),
             Self::Eip2930(_) => Some(TxType::Eip2930.into()),
             Self::Eip1559(_) => Some(TxType::Eip1559.into()),
             Self::Eip4844(_) => Some(TxType::Eip4844.into()),
@@ -472,6 +507,7 @@ impl Encodable2718 for TxEnvelope {
             // Legacy transactions have no difference between network and 2718
             Self::Legacy(tx) => tx.eip2718_encode(out),
             Self::Seismic(tx) => tx.eip2718_encode(out),
+            Self::SeismicEip1559(tx) => tx.eip2718_encode(out),
             Self::Eip2930(tx) => {
                 tx.eip2718_encode(out);
             }
@@ -491,6 +527,7 @@ impl Encodable2718 for TxEnvelope {
         match self {
             Self::Legacy(tx) => *tx.hash(),
             Self::Seismic(tx) => *tx.hash(),
+            Self::SeismicEip1559(tx) => *tx.hash(),
             Self::Eip2930(tx) => *tx.hash(),
             Self::Eip1559(tx) => *tx.hash(),
             Self::Eip4844(tx) => *tx.hash(),
@@ -501,17 +538,32 @@ impl Encodable2718 for TxEnvelope {
 
 impl Decodable712 for TxEnvelope {
     fn decode_712(typed_data: &TypedDataRequest) -> Eip712Result<Self> {
+        if typed_data.data.primary_type == "TxSeismicEip1559" {
+            let tx = TxSeismicEip1559::eip712_decode(&typed_data.data)?;
+            return Ok(Self::SeismicEip1559(tx.into_signed(typed_data.signature)));
+        }
         let tx = TxSeismic::eip712_decode(&typed_data.data)?.into_signed(typed_data.signature);
         Ok(Self::Seismic(tx))
     }
 }
 
+impl Encodable712 for TxEnvelope {
+    fn encode_712(&self) -> Eip712Result<Option<TypedDataRequest>> {
+        match self {
+            Self::Seismic(tx) => tx.encode_712(),
+            Self::SeismicEip1559(tx) => tx.encode_712(),
+            _ => Ok(None),
+        }
+    }
+}
+
 impl Transaction for TxEnvelope {
     #[inline]
     fn chain_id(&self) -> Option<ChainId> {
         match self {
             Self::Legacy(tx) => tx.tx().chain_id(),
             Self::Seismic(tx) => tx.tx().chain_id(),
+            Self::SeismicEip1559(tx) => tx.tx().chain_id(),
             Self::Eip2930(tx) => tx.tx().chain_id(),
             Self::Eip1559(tx) => tx.tx().chain_id(),
             Self::Eip4844(tx) => tx.tx().chain_id(),
@@ -524,6 +576,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().nonce(),
             Self::Seismic(tx) => tx.tx().nonce(),
+            Self::SeismicEip1559(tx) => tx.tx().nonce(),
             Self::Eip2930(tx) => tx.tx().nonce(),
             Self::Eip1559(tx) => tx.tx().nonce(),
             Self::Eip4844(tx) => tx.tx().nonce(),
@@ -536,6 +589,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().gas_limit(),
             Self::Seismic(tx) => tx.tx().gas_limit(),
+            Self::SeismicEip1559(tx) => tx.tx().gas_limit(),
             Self::Eip2930(tx) => tx.tx().gas_limit(),
             Self::Eip1559(tx) => tx.tx().gas_limit(),
             Self::Eip4844(tx) => tx.tx().gas_limit(),
@@ -548,6 +602,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().gas_price(),
             Self::Seismic(tx) => tx.tx().gas_price(),
+            Self::SeismicEip1559(tx) => tx.tx().gas_price(),
             Self::Eip2930(tx) => tx.tx().gas_price(),
             Self::Eip1559(tx) => tx.tx().gas_price(),
             Self::Eip4844(tx) => tx.tx().gas_price(),
@@ -560,6 +615,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().max_fee_per_gas(),
             Self::Seismic(tx) => tx.tx().max_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip2930(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip1559(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip4844(tx) => tx.tx().max_fee_per_gas(),
@@ -572,6 +628,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Seismic(tx) => tx.tx().max_priority_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip2930(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip1559(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip4844(tx) => tx.tx().max_priority_fee_per_gas(),
@@ -584,6 +641,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Seismic(tx) => tx.tx().max_fee_per_blob_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip2930(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip1559(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip4844(tx) => tx.tx().max_fee_per_blob_gas(),
@@ -596,6 +654,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().priority_fee_or_price(),
             Self::Seismic(tx) => tx.tx().priority_fee_or_price(),
+            Self::SeismicEip1559(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip2930(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip1559(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip4844(tx) => tx.tx().priority_fee_or_price(),
@@ -607,6 +666,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Seismic(tx) => tx.tx().effective_gas_price(base_fee),
+            Self::SeismicEip1559(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip2930(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip1559(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip4844(tx) => tx.tx().effective_gas_price(base_fee),
@@ -619,6 +679,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().is_dynamic_fee(),
             Self::Seismic(tx) => tx.tx().is_dynamic_fee(),
+            Self::SeismicEip1559(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip2930(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip1559(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip4844(tx) => tx.tx().is_dynamic_fee(),
@@ -631,6 +692,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().kind(),
             Self::Seismic(tx) => tx.tx().kind(),
+            Self::SeismicEip1559(tx) => tx.tx().kind(),
             Self::Eip2930(tx) => tx.tx().kind(),
             Self::Eip1559(tx) => tx.tx().kind(),
             Self::Eip4844(tx) => tx.tx().kind(),
@@ -643,6 +705,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().is_create(),
             Self::Seismic(tx) => tx.tx().is_create(),
+            Self::SeismicEip1559(tx) => tx.tx().is_create(),
             Self::Eip2930(tx) => tx.tx().is_create(),
             Self::Eip1559(tx) => tx.tx().is_create(),
             Self::Eip4844(tx) => tx.tx().is_create(),
@@ -655,6 +718,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().value(),
             Self::Seismic(tx) => tx.tx().value(),
+            Self::SeismicEip1559(tx) => tx.tx().value(),
             Self::Eip2930(tx) => tx.tx().value(),
             Self::Eip1559(tx) => tx.tx().value(),
             Self::Eip4844(tx) => tx.tx().value(),
@@ -667,6 +731,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().input(),
             Self::Seismic(tx) => tx.tx().input(),
+            Self::SeismicEip1559(tx) => tx.tx().input(),
             Self::Eip2930(tx) => tx.tx().input(),
             Self::Eip1559(tx) => tx.tx().input(),
             Self::Eip4844(tx) => tx.tx().input(),
@@ -679,6 +744,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().access_list(),
             Self::Seismic(tx) => tx.tx().access_list(),
+            Self::SeismicEip1559(tx) => tx.tx().access_list(),
             Self::Eip2930(tx) => tx.tx().access_list(),
             Self::Eip1559(tx) => tx.tx().access_list(),
             Self::Eip4844(tx) => tx.tx().access_list(),
@@ -691,6 +757,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().blob_versioned_hashes(),
             Self::Seismic(tx) => tx.tx().blob_versioned_hashes(),
+            Self::SeismicEip1559(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip2930(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip1559(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip4844(tx) => tx.tx().blob_versioned_hashes(),
@@ -702,6 +769,7 @@ impl Transaction for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().authorization_list(),
             Self::Seismic(tx) => tx.tx().authorization_list(),
+            Self::SeismicEip1559(tx) => tx.tx().authorization_list(),
             Self::Eip2930(tx) => tx.tx().authorization_list(),
             Self::Eip1559(tx) => tx.tx().authorization_list(),
             Self::Eip4844(tx) => tx.tx().authorization_list(),
@@ -713,6 +781,7 @@ impl Transaction for TxEnvelope {
     fn encryption_pubkey(&self) -> Option<&crate::transaction::EncryptionPublicKey> {
         match self {
             Self::Seismic(tx) => tx.tx().encryption_pubkey(),
+            Self::SeismicEip1559(tx) => tx.tx().encryption_pubkey(),
             _ => None,
         }
     }
@@ -721,6 +790,7 @@ impl Transaction for TxEnvelope {
     fn message_version(&self) -> Option<u8> {
         match self {
             Self::Seismic(tx) => tx.tx().message_version(),
+            Self::SeismicEip1559(tx) => tx.tx().message_version(),
             _ => None,
         }
     }
@@ -731,6 +801,7 @@ impl Typed2718 for TxEnvelope {
         match self {
             Self::Legacy(tx) => tx.tx().ty(),
             Self::Seismic(tx) => tx.tx().ty(),
+            Self::SeismicEip1559(tx) => tx.tx().ty(),
             Self::Eip2930(tx) => tx.tx().ty(),
             Self::Eip1559(tx) => tx.tx().ty(),
             Self::Eip4844(tx) => tx.tx().ty(),
@@ -751,8 +822,8 @@ mod serde_from {
     //! We serialize via [`TaggedTxEnvelope`] and deserialize via
     //! [`MaybeTaggedTxEnvelope`].
     use crate::{
-        transaction::TxSeismic, Signed, TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702,
-        TxEnvelope, TxLegacy,
+        transaction::{TxSeismic, TxSeismicEip1559},
+        Signed, TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy,
     };
 
     #[derive(Debug, serde::Deserialize)]
@@ -780,8 +851,10 @@ mod serde_from {
         Eip4844(Signed<TxEip4844Variant>),
         #[serde(rename = "0x4", alias = "0x04")]
         Eip7702(Signed<TxEip7702>),
-        #[serde(rename = "0x4A", alias = "0x4A")]
+        #[serde(rename = "0x4a", alias = "0x4A")]
         Seismic(Signed<TxSeismic>),
+        #[serde(rename = "0x4b", alias = "0x4B")]
+        SeismicEip1559(Signed<TxSeismicEip1559>),
     }
 
     impl From<MaybeTaggedTxEnvelope> for TxEnvelope {
@@ -798,6 +871,7 @@ mod serde_from {
             match value {
                 TaggedTxEnvelope::Legacy(signed) => Self::Legacy(signed),
                 TaggedTxEnvelope::Seismic(signed) => Self::Seismic(signed),
+                TaggedTxEnvelope::SeismicEip1559(signed) => Self::SeismicEip1559(signed),
                 TaggedTxEnvelope::Eip2930(signed) => Self::Eip2930(signed),
                 TaggedTxEnvelope::Eip1559(signed) => Self::Eip1559(signed),
                 TaggedTxEnvelope::Eip4844(signed) => Self::Eip4844(signed),
@@ -811,6 +885,7 @@ mod serde_from {
             match value {
                 TxEnvelope::Legacy(signed) => Self::Legacy(signed),
                 TxEnvelope::Seismic(signed) => Self::Seismic(signed),
+                TxEnvelope::SeismicEip1559(signed) => Self::SeismicEip1559(signed),
                 TxEnvelope::Eip2930(signed) => Self::Eip2930(signed),
                 TxEnvelope::Eip1559(signed) => Self::Eip1559(signed),
                 TxEnvelope::Eip4844(signed) => Self::Eip4844(signed),
@@ -1377,6 +1452,57 @@ mod tests {
         test_serde_roundtrip(tx);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_roundtrip_seismic_eip1559() {
+        use crate::transaction::EncryptionPublicKey;
+
+        let tx = TxSeismicEip1559 {
+            chain_id: 1,
+            nonce: 100,
+            gas_limit: 50_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: Address::default().into(),
+            value: U256::from(10e18),
+            access_list: AccessList(vec![AccessListItem {
+                address: Address::random(),
+                storage_keys: vec![B256::random()],
+            }]),
+            encryption_pubkey: EncryptionPublicKey::new([0u8; 33]),
+            message_version: 0,
+            input: Bytes::new(),
+        };
+        test_serde_roundtrip(tx);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_seismic_type_tag() {
+        use crate::transaction::EncryptionPublicKey;
+
+        let tx = TxSeismic {
+            chain_id: 1,
+            nonce: 100,
+            gas_price: 3_000_000_000,
+            gas_limit: 50_000,
+            to: Address::default().into(),
+            value: U256::from(10e18),
+            encryption_pubkey: EncryptionPublicKey::new([2u8; 33]),
+            message_version: 0,
+            input: Bytes::new(),
+        };
+        let tx_envelope: TxEnvelope = tx.into_signed(Signature::test_signature()).into();
+
+        // nodes serialize the type as a quantity, in lowercase
+        let mut json = serde_json::to_value(&tx_envelope).unwrap();
+        assert_eq!(json["type"], "0x4a");
+        assert_eq!(serde_json::from_value::<TxEnvelope>(json.clone()).unwrap(), tx_envelope);
+
+        json["type"] = "0x4A".into();
+        assert_eq!(serde_json::from_value::<TxEnvelope>(json).unwrap(), tx_envelope);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_tx_from_contract_call() {
@@ -53,13 +53,16 @@ pub mod serde_bincode_compat {
     pub use super::{
         eip1559::serde_bincode_compat::*, eip2930::serde_bincode_compat::*,
         eip7702::serde_bincode_compat::*, legacy::serde_bincode_compat::*,
-        seismic::serde_bincode_compat::*,
+        seismic::serde_bincode_compat::*, seismic_eip1559::serde_bincode_compat::*,
     };
 }
 
 mod seismic;
 pub use seismic::{EncryptionPublicKey, TxSeismic};
 
+mod seismic_eip1559;
+pub use seismic_eip1559::TxSeismicEip1559;
+
 /// Represents a minimal EVM transaction.
 #[doc(alias = "Tx")]
 #[auto_impl::auto_impl(&, Arc)]
@@ -358,6 +361,11 @@ impl<T: Transaction> Transaction for alloy_serde::WithOtherFields<T> {
     fn encryption_pubkey(&self) -> Option<&EncryptionPublicKey> {
         self.inner.encryption_pubkey()
     }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        self.inner.message_version()
+    }
 }
 
 /// A trait that helps to determine the type of the transaction.
@@ -17,7 +17,7 @@ use alloy_primitives::{
 use alloy_rlp::{Decodable, Encodable, Header};
 use core::hash::{Hash, Hasher};
 
-use super::TxSeismic;
+use super::{TxSeismic, TxSeismicEip1559};
 
 /// All possible transactions that can be included in a response to `GetPooledTransactions`.
 /// A response to `GetPooledTransactions`. This can include either a blob transaction, or a
@@ -42,6 +42,8 @@ pub enum PooledTransaction {
     Eip7702(Signed<TxEip7702>),
     /// A [`TxSeismic`] tagged with type 4.
     Seismic(Signed<TxSeismic>),
+    /// A [`TxSeismicEip1559`] tagged with type 0x4B.
+    SeismicEip1559(Signed<TxSeismicEip1559>),
 }
 
 impl PooledTransaction {
@@ -51,6 +53,7 @@ impl PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.signature_hash(),
             Self::Seismic(tx) => tx.signature_hash(),
+            Self::SeismicEip1559(tx) => tx.signature_hash(),
             Self::Eip2930(tx) => tx.signature_hash(),
             Self::Eip1559(tx) => tx.signature_hash(),
             Self::Eip7702(tx) => tx.signature_hash(),
@@ -63,6 +66,7 @@ impl PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.hash(),
             Self::Seismic(tx) => tx.hash(),
+            Self::SeismicEip1559(tx) => tx.hash(),
             Self::Eip2930(tx) => tx.hash(),
             Self::Eip1559(tx) => tx.hash(),
             Self::Eip7702(tx) => tx.hash(),
@@ -75,6 +79,7 @@ impl PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.signature(),
             Self::Seismic(tx) => tx.signature(),
+            Self::SeismicEip1559(tx) => tx.signature(),
             Self::Eip2930(tx) => tx.signature(),
             Self::Eip1559(tx) => tx.signature(),
             Self::Eip7702(tx) => tx.signature(),
@@ -100,7 +105,8 @@ impl PooledTransaction {
     ) -> Result<alloy_primitives::Address, alloy_primitives::SignatureError> {
         match self {
             Self::Legacy(tx) => tx.recover_signer(),
-            Self::Seismic(tx) => tx.recover_signer(),
+            Self::Seismic(tx) => tx.recover_caller(),
+            Self::SeismicEip1559(tx) => tx.recover_caller(),
             Self::Eip2930(tx) => tx.recover_signer(),
             Self::Eip1559(tx) => tx.recover_signer(),
             Self::Eip4844(tx) => tx.recover_signer(),
@@ -114,6 +120,7 @@ impl PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().encode_for_signing(out),
             Self::Seismic(tx) => tx.tx().encode_for_signing(out),
+            Self::SeismicEip1559(tx) => tx.tx().encode_for_signing(out),
             Self::Eip2930(tx) => tx.tx().encode_for_signing(out),
             Self::Eip1559(tx) => tx.tx().encode_for_signing(out),
             Self::Eip4844(tx) => tx.tx().encode_for_signing(out),
@@ -126,6 +133,7 @@ impl PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.into(),
             Self::Seismic(tx) => tx.into(),
+            Self::SeismicEip1559(tx) => tx.into(),
             Self::Eip2930(tx) => tx.into(),
             Self::Eip1559(tx) => tx.into(),
             Self::Eip7702(tx) => tx.into(),
@@ -188,6 +196,15 @@ impl PooledTransaction {
             _ => None,
         }
     }
+
+    /// Returns the [`TxSeismicEip1559`] variant if the transaction is a dynamic-fee seismic
+    /// transaction.
+    pub const fn as_seismic_eip1559(&self) -> Option<&TxSeismicEip1559> {
+        match self {
+            Self::SeismicEip1559(tx) => Some(tx.tx()),
+            _ => None,
+        }
+    }
 }
 
 impl From<Signed<TxLegacy>> for PooledTransaction {
@@ -227,6 +244,12 @@ impl From<Signed<TxSeismic>> for PooledTransaction {
     }
 }
 
+impl From<Signed<TxSeismicEip1559>> for PooledTransaction {
+    fn from(v: Signed<TxSeismicEip1559>) -> Self {
+        Self::SeismicEip1559(v)
+    }
+}
+
 impl Hash for PooledTransaction {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.trie_hash().hash(state);
@@ -265,6 +288,7 @@ impl Encodable2718 for PooledTransaction {
         match self {
             Self::Legacy(_) => None,
             Self::Seismic(_) => None,
+            Self::SeismicEip1559(_) => Some(0x4B),
             Self::Eip2930(_) => Some(0x01),
             Self::Eip1559(_) => Some(0x02),
             Self::Eip4844(_) => Some(0x03),
@@ -276,6 +300,7 @@ impl Encodable2718 for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.eip2718_encoded_length(),
             Self::Seismic(tx) => tx.eip2718_encoded_length(),
+            Self::SeismicEip1559(tx) => tx.eip2718_encoded_length(),
             Self::Eip2930(tx) => tx.eip2718_encoded_length(),
             Self::Eip1559(tx) => tx.eip2718_encoded_length(),
             Self::Eip7702(tx) => tx.eip2718_encoded_length(),
@@ -287,6 +312,7 @@ impl Encodable2718 for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.eip2718_encode(out),
             Self::Seismic(tx) => tx.eip2718_encode(out),
+            Self::SeismicEip1559(tx) => tx.eip2718_encode(out),
             Self::Eip2930(tx) => tx.eip2718_encode(out),
             Self::Eip1559(tx) => tx.eip2718_encode(out),
             Self::Eip7702(tx) => tx.eip2718_encode(out),
@@ -308,6 +334,7 @@ impl Decodable2718 for PooledTransaction {
             TxType::Eip7702 => Ok(TxEip7702::rlp_decode_signed(buf)?.into()),
             TxType::Legacy => Err(Eip2718Error::UnexpectedType(0)),
             TxType::Seismic => Ok(TxSeismic::rlp_decode_signed(buf)?.into()),
+            TxType::SeismicEip1559 => Ok(TxSeismicEip1559::rlp_decode_signed(buf)?.into()),
         }
     }
 
@@ -321,6 +348,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().chain_id(),
             Self::Seismic(tx) => tx.tx().chain_id(),
+            Self::SeismicEip1559(tx) => tx.tx().chain_id(),
             Self::Eip2930(tx) => tx.tx().chain_id(),
             Self::Eip1559(tx) => tx.tx().chain_id(),
             Self::Eip7702(tx) => tx.tx().chain_id(),
@@ -332,6 +360,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().nonce(),
             Self::Seismic(tx) => tx.tx().nonce(),
+            Self::SeismicEip1559(tx) => tx.tx().nonce(),
             Self::Eip2930(tx) => tx.tx().nonce(),
             Self::Eip1559(tx) => tx.tx().nonce(),
             Self::Eip7702(tx) => tx.tx().nonce(),
@@ -343,6 +372,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().gas_limit(),
             Self::Seismic(tx) => tx.tx().gas_limit(),
+            Self::SeismicEip1559(tx) => tx.tx().gas_limit(),
             Self::Eip2930(tx) => tx.tx().gas_limit(),
             Self::Eip1559(tx) => tx.tx().gas_limit(),
             Self::Eip7702(tx) => tx.tx().gas_limit(),
@@ -354,6 +384,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().gas_price(),
             Self::Seismic(tx) => tx.tx().gas_price(),
+            Self::SeismicEip1559(tx) => tx.tx().gas_price(),
             Self::Eip2930(tx) => tx.tx().gas_price(),
             Self::Eip1559(tx) => tx.tx().gas_price(),
             Self::Eip7702(tx) => tx.tx().gas_price(),
@@ -365,6 +396,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().max_fee_per_gas(),
             Self::Seismic(tx) => tx.tx().max_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip2930(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip1559(tx) => tx.tx().max_fee_per_gas(),
             Self::Eip7702(tx) => tx.tx().max_fee_per_gas(),
@@ -376,6 +408,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Seismic(tx) => tx.tx().max_priority_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip2930(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip1559(tx) => tx.tx().max_priority_fee_per_gas(),
             Self::Eip7702(tx) => tx.tx().max_priority_fee_per_gas(),
@@ -387,6 +420,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Seismic(tx) => tx.tx().max_fee_per_blob_gas(),
+            Self::SeismicEip1559(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip2930(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip1559(tx) => tx.tx().max_fee_per_blob_gas(),
             Self::Eip7702(tx) => tx.tx().max_fee_per_blob_gas(),
@@ -398,6 +432,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().priority_fee_or_price(),
             Self::Seismic(tx) => tx.tx().priority_fee_or_price(),
+            Self::SeismicEip1559(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip2930(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip1559(tx) => tx.tx().priority_fee_or_price(),
             Self::Eip7702(tx) => tx.tx().priority_fee_or_price(),
@@ -409,6 +444,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Seismic(tx) => tx.tx().effective_gas_price(base_fee),
+            Self::SeismicEip1559(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip2930(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip1559(tx) => tx.tx().effective_gas_price(base_fee),
             Self::Eip7702(tx) => tx.tx().effective_gas_price(base_fee),
@@ -420,6 +456,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().is_dynamic_fee(),
             Self::Seismic(tx) => tx.tx().is_dynamic_fee(),
+            Self::SeismicEip1559(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip2930(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip1559(tx) => tx.tx().is_dynamic_fee(),
             Self::Eip7702(tx) => tx.tx().is_dynamic_fee(),
@@ -431,6 +468,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().kind(),
             Self::Seismic(tx) => tx.tx().kind(),
+            Self::SeismicEip1559(tx) => tx.tx().kind(),
             Self::Eip2930(tx) => tx.tx().kind(),
             Self::Eip1559(tx) => tx.tx().kind(),
             Self::Eip7702(tx) => tx.tx().kind(),
@@ -442,6 +480,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().is_create(),
             Self::Seismic(tx) => tx.tx().is_create(),
+            Self::SeismicEip1559(tx) => tx.tx().is_create(),
             Self::Eip2930(tx) => tx.tx().is_create(),
             Self::Eip1559(tx) => tx.tx().is_create(),
             Self::Eip7702(tx) => tx.tx().is_create(),
@@ -453,6 +492,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().value(),
             Self::Seismic(tx) => tx.tx().value(),
+            Self::SeismicEip1559(tx) => tx.tx().value(),
             Self::Eip2930(tx) => tx.tx().value(),
             Self::Eip1559(tx) => tx.tx().value(),
             Self::Eip7702(tx) => tx.tx().value(),
@@ -464,6 +504,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().input(),
             Self::Seismic(tx) => tx.tx().input(),
+            Self::SeismicEip1559(tx) => tx.tx().input(),
             Self::Eip2930(tx) => tx.tx().input(),
             Self::Eip1559(tx) => tx.tx().input(),
             Self::Eip7702(tx) => tx.tx().input(),
@@ -475,6 +516,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().access_list(),
             Self::Seismic(tx) => tx.tx().access_list(),
+            Self::SeismicEip1559(tx) => tx.tx().access_list(),
             Self::Eip2930(tx) => tx.tx().access_list(),
             Self::Eip1559(tx) => tx.tx().access_list(),
             Self::Eip7702(tx) => tx.tx().access_list(),
@@ -486,6 +528,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().blob_versioned_hashes(),
             Self::Seismic(tx) => tx.tx().blob_versioned_hashes(),
+            Self::SeismicEip1559(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip2930(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip1559(tx) => tx.tx().blob_versioned_hashes(),
             Self::Eip7702(tx) => tx.tx().blob_versioned_hashes(),
@@ -497,6 +540,7 @@ impl Transaction for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().authorization_list(),
             Self::Seismic(tx) => tx.tx().authorization_list(),
+            Self::SeismicEip1559(tx) => tx.tx().authorization_list(),
             Self::Eip2930(tx) => tx.tx().authorization_list(),
             Self::Eip1559(tx) => tx.tx().authorization_list(),
             Self::Eip7702(tx) => tx.tx().authorization_list(),
@@ -507,6 +551,7 @@ impl Transaction for PooledTransaction {
     fn encryption_pubkey(&self) -> Option<&crate::transaction::EncryptionPublicKey> {
         match self {
             Self::Seismic(tx) => tx.tx().encryption_pubkey(),
+            Self::SeismicEip1559(tx) => tx.tx().encryption_pubkey(),
             _ => None,
         }
     }
@@ -517,6 +562,7 @@ impl Typed2718 for PooledTransaction {
         match self {
             Self::Legacy(tx) => tx.tx().ty(),
             Self::Seismic(tx) => tx.tx().ty(),
+            Self::SeismicEip1559(tx) => tx.tx().ty(),
             Self::Eip2930(tx) => tx.tx().ty(),
             Self::Eip1559(tx) => tx.tx().ty(),
             Self::Eip7702(tx) => tx.tx().ty(),
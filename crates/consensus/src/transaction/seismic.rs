@@ -1,8 +1,9 @@
 use crate::{transaction::RlpEcdsaTx, SignableTransaction, Signed, Transaction, TxType, Typed2718};
+use alloc::vec::Vec;
 use alloy_dyn_abi::TypedData;
 use alloy_eips::{
     eip2930::AccessList,
-    eip712::{Eip712Error, Eip712Result, TypedDataRequest},
+    eip712::{Eip712Error, Eip712Result, Encodable712, TypedDataRequest},
     eip7702::SignedAuthorization,
 };
 use alloy_primitives::{
@@ -15,6 +16,267 @@ use core::mem;
 /// Compressed secp256k1 public key
 pub type EncryptionPublicKey = FixedBytes<33>;
 
+/// Name of the EIP-712 field marking contract creation, see
+/// [`TxSeismic::EIP712_EXPLICIT_CREATE_VERSION`].
+const EIP712_IS_CREATE: &str = "isCreate";
+
+/// An EIP-712 field of a seismic transaction: its name, its type and its value.
+pub(super) type Eip712Field = (&'static str, &'static str, serde_json::Value);
+
+/// A seismic transaction type.
+///
+/// Seismic transactions are signed either over their 2718 encoding, like any other typed
+/// transaction, or, from message version 2 on, over EIP-712 typed data. The typed data of every
+/// seismic transaction type shares its domain and most of its fields, so implementors only
+/// describe the fields that differ, and the free functions of this module implement the rest.
+pub(super) trait SeismicTx: Transaction + RlpEcdsaTx + Encodable {
+    /// Name of the EIP-712 struct of the transaction.
+    const EIP712_PRIMARY_TYPE: &'static str;
+
+    /// Returns the EIP-712 fee fields of the transaction, which come between `nonce` and `to`.
+    fn eip712_fee_fields(&self) -> Vec<Eip712Field>;
+
+    /// Returns the EIP-712 fields of the transaction which come between `value` and
+    /// `encryptionPubkey`, along with the struct types they refer to.
+    fn eip712_extra_fields(&self) -> (Vec<Eip712Field>, serde_json::Value) {
+        (Vec::new(), serde_json::json!({}))
+    }
+}
+
+/// Returns the EIP-712 version of `tx`, `0` if it is signed over its RLP encoding.
+fn message_version<T: SeismicTx>(tx: &T) -> u8 {
+    tx.message_version().unwrap_or_default()
+}
+
+/// Returns true if `tx` is signed over EIP-712 typed data.
+pub(super) fn is_eip712<T: SeismicTx>(tx: &T) -> bool {
+    message_version(tx) >= 2
+}
+
+/// Encodes `tx` into [`TypedData`].
+pub(super) fn eip712_to_type_data<T: SeismicTx>(tx: &T) -> Eip712Result<TypedData> {
+    let version = message_version(tx);
+    let (extra_fields, mut types) = tx.eip712_extra_fields();
+    let fields = [
+        ("chainId", "uint64", tx.chain_id().unwrap_or_default().to_string().into()),
+        ("nonce", "uint64", tx.nonce().to_string().into()),
+    ]
+    .into_iter()
+    .chain(tx.eip712_fee_fields())
+    .chain([
+        // if blank, we assume it's a create
+        ("to", "address", tx.kind().to().copied().unwrap_or_default().to_string().into()),
+        ("value", "uint256", tx.value().to_string().into()),
+    ])
+    .chain(extra_fields)
+    .chain([
+        // compressed secp256k1 public key (33 bytes)
+        (
+            "encryptionPubkey",
+            "bytes",
+            tx.encryption_pubkey().map(|key| key.to_string()).unwrap_or_default().into(),
+        ),
+        ("messageVersion", "uint8", version.into()),
+        ("input", "bytes", tx.input().to_string().into()),
+    ]);
+
+    let mut schema = Vec::new();
+    let mut message = serde_json::Map::new();
+    for (name, ty, value) in fields {
+        schema.push(serde_json::json!({ "name": name, "type": ty }));
+        message.insert(name.into(), value);
+    }
+    if version >= TxSeismic::EIP712_EXPLICIT_CREATE_VERSION {
+        schema.push(serde_json::json!({ "name": EIP712_IS_CREATE, "type": "bool" }));
+        message.insert(EIP712_IS_CREATE.into(), tx.is_create().into());
+    }
+
+    types["EIP712Domain"] = serde_json::json!([
+        { "name": "name", "type": "string" },
+        { "name": "version", "type": "string" },
+        { "name": "chainId", "type": "uint256" },
+        { "name": "verifyingContract", "type": "address" },
+    ]);
+    types[T::EIP712_PRIMARY_TYPE] = schema.into();
+
+    serde_json::from_value(serde_json::json!({
+        "types": types,
+        "primaryType": T::EIP712_PRIMARY_TYPE,
+        "domain": {
+            "name": "Seismic Transaction",
+            "version": version.to_string(),
+            "chainId": tx.chain_id().unwrap_or_default(),
+            // no verifying contract since this happens in RPC
+            "verifyingContract": "0x0000000000000000000000000000000000000000",
+        },
+        "message": message,
+    }))
+    .map_err(|e| {
+        Eip712Error::EncodeError(format!(
+            "Failed to convert seismic transaction to typed data: {e}"
+        ))
+    })
+}
+
+/// Calculates the signing hash of the EIP-712 typed data of `tx`.
+pub(super) fn eip712_signature_hash<T: SeismicTx>(tx: &T) -> Eip712Result<B256> {
+    eip712_to_type_data(tx)?
+        .eip712_signing_hash()
+        .map_err(|e| Eip712Error::EncodeError(format!("Failed to hash seismic transaction: {e}")))
+}
+
+/// Encodes the EIP-712 typed data of `tx` for signing.
+fn eip712_encode_for_signing<T: SeismicTx>(tx: &T) -> Eip712Result<Vec<u8>> {
+    eip712_to_type_data(tx)?
+        .eip712_encode_for_signing()
+        .map_err(|e| Eip712Error::EncodeError(format!("Failed to encode seismic transaction: {e}")))
+}
+
+/// Encodes `tx` for signing over its RLP encoding.
+fn rlp_encode_for_signing<T: SeismicTx>(tx: &T, out: &mut dyn BufMut) {
+    out.put_u8(T::DEFAULT_TX_TYPE);
+    tx.encode(out)
+}
+
+/// Returns the hash of `tx` signed with `signature`, given the payload it is signed over.
+///
+/// The hash of a transaction signed over EIP-712 typed data covers the typed data followed by
+/// the RLP encoding of the signed transaction.
+fn eip712_tx_hash<T: SeismicTx>(tx: &T, signature: &Signature, mut payload: Vec<u8>) -> B256 {
+    tx.rlp_encode_signed(signature, &mut payload);
+    keccak256(payload)
+}
+
+/// Encodes `tx` for signing, as its EIP-712 typed data if it is signed over them.
+pub(super) fn try_encode_for_signing<T: SeismicTx>(
+    tx: &T,
+    out: &mut dyn BufMut,
+) -> Eip712Result<()> {
+    if is_eip712(tx) {
+        out.put_slice(&eip712_encode_for_signing(tx)?);
+    } else {
+        rlp_encode_for_signing(tx, out);
+    }
+    Ok(())
+}
+
+/// Returns the length of the payload `tx` is signed over.
+pub(super) fn try_payload_len_for_signature<T: SeismicTx>(tx: &T) -> Eip712Result<usize> {
+    if is_eip712(tx) {
+        Ok(eip712_to_type_data(tx)?.eip712_encode_for_signing_len())
+    } else {
+        Ok(tx.length() + 1)
+    }
+}
+
+/// Returns the hash `tx` is signed over.
+pub(super) fn try_signature_hash<T: SeismicTx>(tx: &T) -> Eip712Result<B256> {
+    if is_eip712(tx) {
+        eip712_signature_hash(tx)
+    } else {
+        let mut buf = Vec::with_capacity(tx.length() + 1);
+        rlp_encode_for_signing(tx, &mut buf);
+        Ok(keccak256(buf))
+    }
+}
+
+/// Signs `tx` with `signature`.
+pub(super) fn try_into_signed<T: SeismicTx>(
+    tx: T,
+    signature: Signature,
+) -> Eip712Result<Signed<T>> {
+    let tx_hash = if is_eip712(&tx) {
+        eip712_tx_hash(&tx, &signature, eip712_encode_for_signing(&tx)?)
+    } else {
+        tx.tx_hash(&signature)
+    };
+    Ok(Signed::new_unchecked(tx, signature, tx_hash))
+}
+
+/// Encodes `tx` for signing, see [`try_encode_for_signing`].
+///
+/// [`SignableTransaction`] cannot fail, so if the typed data of `tx` cannot be encoded, this
+/// falls back to its RLP encoding, which the node rejects for EIP-712 message versions.
+pub(super) fn encode_for_signing<T: SeismicTx>(tx: &T, out: &mut dyn BufMut) {
+    match is_eip712(tx).then(|| eip712_encode_for_signing(tx)) {
+        Some(Ok(payload)) => out.put_slice(&payload),
+        _ => rlp_encode_for_signing(tx, out),
+    }
+}
+
+/// Returns the length of the payload `tx` is signed over, see [`encode_for_signing`].
+pub(super) fn payload_len_for_signature<T: SeismicTx>(tx: &T) -> usize {
+    try_payload_len_for_signature(tx).unwrap_or_else(|_| tx.length() + 1)
+}
+
+/// Returns the hash `tx` is signed over, see [`encode_for_signing`].
+pub(super) fn signature_hash<T: SeismicTx>(tx: &T) -> B256 {
+    let mut buf = Vec::with_capacity(payload_len_for_signature(tx));
+    encode_for_signing(tx, &mut buf);
+    keccak256(buf)
+}
+
+/// Signs `tx` with `signature`, see [`encode_for_signing`].
+pub(super) fn into_signed<T: SeismicTx>(tx: T, signature: Signature) -> Signed<T> {
+    let tx_hash = match is_eip712(&tx).then(|| eip712_encode_for_signing(&tx)) {
+        Some(Ok(payload)) => eip712_tx_hash(&tx, &signature, payload),
+        _ => tx.tx_hash(&signature),
+    };
+    Signed::new_unchecked(tx, signature, tx_hash)
+}
+
+/// Returns the typed data and signature of `signed`, if it is signed over EIP-712 typed data.
+pub(super) fn encode_712<T: SeismicTx>(
+    signed: &Signed<T>,
+) -> Eip712Result<Option<TypedDataRequest>> {
+    if !is_eip712(signed.tx()) {
+        return Ok(None);
+    }
+    let data = eip712_to_type_data(signed.tx())?;
+    Ok(Some(TypedDataRequest { data, signature: *signed.signature() }))
+}
+
+/// Recovers the caller of `signed`, from its EIP-712 typed data if it is signed over them.
+#[cfg(feature = "k256")]
+pub(super) fn recover_caller<T>(
+    signed: &Signed<T>,
+) -> Result<Address, alloy_primitives::SignatureError>
+where
+    T: SeismicTx + SignableTransaction<Signature>,
+{
+    let tx = signed.tx();
+    if !is_eip712(tx) {
+        return signed.recover_signer();
+    }
+    let tx_hash = eip712_signature_hash(tx).map_err(|_| {
+        alloy_primitives::SignatureError::FromBytes(
+            "seismic transaction cannot be encoded as EIP-712 typed data",
+        )
+    })?;
+    signed.signature().recover_address_from_prehash(&tx_hash)
+}
+
+/// Returns the kind of the transaction of an EIP-712 `message` whose `to` field decoded to
+/// `to`.
+pub(super) fn eip712_decode_kind(
+    message: &serde_json::Value,
+    to: TxKind,
+    message_version: u8,
+) -> Eip712Result<TxKind> {
+    if message_version < TxSeismic::EIP712_EXPLICIT_CREATE_VERSION {
+        // earlier schemas encode contract creation as a call to the zero address
+        return Ok(if to == TxKind::Call(Address::ZERO) { TxKind::Create } else { to });
+    }
+    match message.get(EIP712_IS_CREATE).and_then(serde_json::Value::as_bool) {
+        Some(false) => Ok(to),
+        Some(true) if to == TxKind::Call(Address::ZERO) => Ok(TxKind::Create),
+        Some(true) => Err(Eip712Error::DecodeError(
+            "Contract creation with a non-zero `to` address".to_string(),
+        )),
+        None => Err(Eip712Error::DecodeError(format!("Missing `{EIP712_IS_CREATE}` field"))),
+    }
+}
+
 /// Basic encrypted transaction type
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
@@ -61,6 +323,9 @@ pub struct TxSeismic {
     pub encryption_pubkey: EncryptionPublicKey,
     /// The EIP712 version of the transaction when the user submitted it using signTypedDataV4.
     /// A value of 0 means the transaction was not signed using EIP712
+    ///
+    /// From [`Self::EIP712_EXPLICIT_CREATE_VERSION`] on, the typed data marks contract creation
+    /// with an `isCreate` field instead of a zero `to` address.
     #[cfg_attr(feature = "serde", serde(alias = "messageVersion", default))]
     pub message_version: u8,
     /// Input has two uses depending if transaction is Create or Call (if `to` field is None or
@@ -75,6 +340,13 @@ impl TxSeismic {
     /// numeric type for the transaction
     pub const TX_TYPE: u8 = 0x4A;
 
+    /// The first [`message_version`](Self::message_version) whose EIP-712 schema marks contract
+    /// creation with an explicit `isCreate` field.
+    ///
+    /// Earlier versions encode creation as a call to [`Address::ZERO`], so a call to the zero
+    /// address cannot be signed over their typed data.
+    pub const EIP712_EXPLICIT_CREATE_VERSION: u8 = 3;
+
     /// Get the transaction type
     #[doc(alias = "transaction_type")]
     pub(crate) const fn tx_type() -> TxType {
@@ -83,7 +355,7 @@ impl TxSeismic {
 
     /// Returns true if the transaction is signed using EIP712
     pub fn is_eip712(&self) -> bool {
-        self.message_version >= 2
+        is_eip712(self)
     }
 
     /// Calculates a heuristic for the in-memory size of the [`TxSeismic`] transaction.
@@ -104,85 +376,80 @@ impl TxSeismic {
         self.input.len() // input
     }
 
+    /// Returns true if the EIP-712 schema of the transaction marks contract creation explicitly.
+    pub const fn has_explicit_create(&self) -> bool {
+        self.message_version >= Self::EIP712_EXPLICIT_CREATE_VERSION
+    }
+
     /// Encodes a [`TxSeismic`] into a [`TypedData`].
-    pub fn eip712_to_type_data(&self) -> TypedData {
-        let typed_data_json = serde_json::json!({
-            "types": {
-                "EIP712Domain": [
-                  { "name": "name", "type": "string" },
-                  { "name": "version", "type": "string" },
-                  { "name": "chainId", "type": "uint256" },
-                  { "name": "verifyingContract", "type": "address" },
-                ],
-                "TxSeismic": [
-                  { "name": "chainId", "type": "uint64" },
-                  { "name": "nonce", "type": "uint64" },
-                  { "name": "gasPrice", "type": "uint128" },
-                  { "name": "gasLimit", "type": "uint64" },
-                  // if blank, we assume it's a create
-                  { "name": "to", "type": "address" },
-                  { "name": "value", "type": "uint256" },
-                  // compressed secp256k1 public key (33 bytes)
-                  { "name": "encryptionPubkey", "type": "bytes" },
-                  { "name": "messageVersion", "type": "uint8" },
-                  { "name": "input", "type": "bytes" },
-                ],
-            },
-            "primaryType": "TxSeismic",
-            "domain": {
-                "name": "Seismic Transaction",
-                "version": self.message_version.to_string(),
-                "chainId": self.chain_id,
-                // no verifying contract since this happens in RPC
-                "verifyingContract": "0x0000000000000000000000000000000000000000",
-            },
-            "message": {
-                "chainId": self.chain_id.to_string(),
-                "nonce": self.nonce.to_string(),
-                "gasPrice": self.gas_price.to_string(),
-                "gasLimit": self.gas_limit.to_string(),
-                "to": match self.to {
-                    TxKind::Create => Address::ZERO.to_string(),
-                    TxKind::Call(to) => to.to_string(),
-                },
-                "value": self.value.to_string(),
-                "input": self.input.to_string(),
-                "encryptionPubkey": self.encryption_pubkey.to_string(),
-                "messageVersion": self.message_version,
-            }
-        });
-        serde_json::from_value(typed_data_json)
-            .map_err(|e| format!("Failed to convert seismic transaction to typed data: {e}"))
-            .unwrap()
+    pub fn eip712_to_type_data(&self) -> Eip712Result<TypedData> {
+        eip712_to_type_data(self)
     }
 
     /// Decodes a [`TypedData`] into a [`TxSeismic`].
     pub fn eip712_decode(typed_data: &TypedData) -> Eip712Result<Self> {
-        // Extract the `message` field from TypedData (JSON format)
-        let message = serde_json::to_value(&typed_data.message)
-            .map_err(|_| Eip712Error::DecodeError("Failed to serialize message".to_string()))?;
-
         // Deserialize JSON `message` into `TxSeismic`
-        let mut tx: TxSeismic = serde_json::from_value(message)
+        let mut tx: Self = serde_json::from_value(typed_data.message.clone())
             .map_err(|_| Eip712Error::DecodeError("Failed to deserialize message".to_string()))?;
 
-        if tx.to == TxKind::Call(Address::ZERO) {
-            tx.to = TxKind::Create;
-        }
+        tx.to = eip712_decode_kind(&typed_data.message, tx.to, tx.message_version)?;
 
         Ok(tx)
     }
 
-    fn eip712_signature_hash(&self) -> B256 {
-        let typed_data = self.eip712_to_type_data();
+    /// Calculates the signing hash of the transaction's EIP-712 typed data.
+    pub fn eip712_signature_hash(&self) -> Eip712Result<B256> {
+        eip712_signature_hash(self)
+    }
+
+    /// Encodes the transaction for signing, failing if its EIP-712 typed data cannot be
+    /// encoded.
+    ///
+    /// Unlike [`SignableTransaction::encode_for_signing`], this never falls back to the RLP
+    /// encoding for EIP-712 message versions.
+    pub fn try_encode_for_signing(&self, out: &mut dyn BufMut) -> Eip712Result<()> {
+        try_encode_for_signing(self, out)
+    }
+
+    /// Returns the length of the payload the transaction is signed over, see
+    /// [`Self::try_encode_for_signing`].
+    pub fn try_payload_len_for_signature(&self) -> Eip712Result<usize> {
+        try_payload_len_for_signature(self)
+    }
+
+    /// Returns the hash the transaction is signed over, see [`Self::try_encode_for_signing`].
+    pub fn try_signature_hash(&self) -> Eip712Result<B256> {
+        try_signature_hash(self)
+    }
 
-        typed_data.eip712_signing_hash().expect("Failed to hash seismic transaction in eip712")
+    /// Signs the transaction with `signature`, see [`Self::try_encode_for_signing`].
+    pub fn try_into_signed(self, signature: Signature) -> Eip712Result<Signed<Self>> {
+        try_into_signed(self, signature)
     }
 }
 
-impl From<Signed<TxSeismic>> for TypedDataRequest {
-    fn from(tx: Signed<TxSeismic>) -> Self {
-        TypedDataRequest { data: tx.tx().eip712_to_type_data(), signature: *tx.signature() }
+impl TryFrom<Signed<TxSeismic>> for TypedDataRequest {
+    type Error = Eip712Error;
+
+    fn try_from(tx: Signed<TxSeismic>) -> Eip712Result<Self> {
+        Ok(Self { data: tx.tx().eip712_to_type_data()?, signature: *tx.signature() })
+    }
+}
+
+impl Encodable712 for Signed<TxSeismic> {
+    fn encode_712(&self) -> Eip712Result<Option<TypedDataRequest>> {
+        encode_712(self)
+    }
+}
+
+impl SeismicTx for TxSeismic {
+    const EIP712_PRIMARY_TYPE: &'static str = "TxSeismic";
+
+    fn eip712_fee_fields(&self) -> Vec<Eip712Field> {
+        vec![
+            ("gasPrice", "uint128", self.gas_price.to_string().into()),
+            ("gasLimit", "uint64", self.gas_limit.to_string().into()),
+        ]
     }
 }
 
@@ -353,45 +620,19 @@ impl SignableTransaction<Signature> for TxSeismic {
     }
 
     fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
-        if self.is_eip712() {
-            let data = self
-                .eip712_to_type_data()
-                .eip712_encode_for_signing()
-                .expect("Failed to encode seismic transaction for signing");
-            out.put_slice(data.as_slice());
-        } else {
-            out.put_u8(Self::tx_type() as u8);
-            self.encode(out)
-        }
+        encode_for_signing(self, out)
     }
 
     fn payload_len_for_signature(&self) -> usize {
-        if self.is_eip712() {
-            self.eip712_to_type_data().eip712_encode_for_signing_len()
-        } else {
-            self.length() + 1
-        }
+        payload_len_for_signature(self)
     }
 
     fn into_signed(self, signature: Signature) -> Signed<Self> {
-        if self.is_eip712() {
-            let mut bytes = vec![];
-            self.encode_for_signing(&mut bytes);
-            self.rlp_encode_signed(&signature, &mut bytes);
-            let tx_hash = keccak256(bytes.as_slice());
-            Signed::new_unchecked(self, signature, tx_hash)
-        } else {
-            let tx_hash = self.tx_hash(&signature);
-            Signed::new_unchecked(self, signature, tx_hash)
-        }
+        into_signed(self, signature)
     }
 
     fn signature_hash(&self) -> B256 {
-        if self.is_eip712() {
-            self.eip712_signature_hash()
-        } else {
-            keccak256(self.encoded_for_signing())
-        }
+        signature_hash(self)
     }
 }
 
@@ -403,12 +644,7 @@ impl Signed<TxSeismic> {
     pub fn recover_caller(
         &self,
     ) -> Result<alloy_primitives::Address, alloy_primitives::SignatureError> {
-        let tx = self.tx();
-        if !tx.is_eip712() {
-            return self.recover_signer();
-        }
-        let tx_hash: FixedBytes<32> = tx.eip712_signature_hash();
-        self.signature().recover_address_from_prehash(&tx_hash)
+        recover_caller(self)
     }
 }
 
@@ -638,7 +874,7 @@ mod tests {
             message_version: 2,
             input:  hex!("a22cb4650000000000000000000000005eee75727d804a2b13038928d36f8b188945a57a0000000000000000000000000000000000000000000000000000000000000000").into(),
         };
-        let typed_data = tx.eip712_to_type_data();
+        let typed_data = tx.eip712_to_type_data().unwrap();
         let decoded = TxSeismic::eip712_decode(&typed_data).unwrap();
         assert_eq!(decoded, tx);
 
@@ -656,9 +892,22 @@ mod tests {
         assert_eq!(signed.signature(), &sig);
         assert_ne!(*signed.hash(), signature_hash);
 
-        let typed_data_request: TypedDataRequest = signed.into();
-        assert_eq!(typed_data_request.data, tx.eip712_to_type_data());
+        // the fallible paths agree with the infallible ones
+        let mut payload = Vec::new();
+        tx.try_encode_for_signing(&mut payload).unwrap();
+        assert_eq!(payload.len(), tx.try_payload_len_for_signature().unwrap());
+        assert_eq!(tx.try_signature_hash().unwrap(), signature_hash);
+        assert_eq!(tx.clone().try_into_signed(sig).unwrap().hash(), signed.hash());
+
+        let encoded_712 = signed.encode_712().unwrap().unwrap();
+        let typed_data_request: TypedDataRequest = signed.try_into().unwrap();
+        assert_eq!(typed_data_request.data, typed_data);
         assert_eq!(typed_data_request.signature, sig);
+        assert_eq!(encoded_712.data, typed_data_request.data);
+
+        // transactions signed over their RLP encoding have no typed data form
+        let rlp_tx = TxSeismic { message_version: 0, ..tx };
+        assert!(rlp_tx.into_signed(sig).encode_712().unwrap().is_none());
     }
 
     #[test]
@@ -683,9 +932,94 @@ mod tests {
             message_version: u8::max_value(),
             input: Bytes::default(),
         };
-        let typed_data = tx.eip712_to_type_data();
+        let typed_data = tx.eip712_to_type_data().unwrap();
         println!("typed_data: {:?}", typed_data);
         let decoded = TxSeismic::eip712_decode(&typed_data).unwrap();
         assert_eq!(decoded, tx);
     }
+
+    fn seismic_tx(to: TxKind, message_version: u8) -> TxSeismic {
+        TxSeismic {
+            chain_id: 4u64,
+            nonce: 2,
+            gas_price: 1000000000,
+            gas_limit: 100000,
+            to,
+            value: U256::from(1000000000000000u64),
+            encryption_pubkey: hex!(
+                "028e76821eb4d77fd30223ca971c49738eb5b5b71eabe93f96b348fdce788ae5a0"
+            )
+            .into(),
+            message_version,
+            input: Bytes::default(),
+        }
+    }
+
+    #[test]
+    fn test_eip712_explicit_create() {
+        let version = TxSeismic::EIP712_EXPLICIT_CREATE_VERSION;
+
+        // a call to the zero address is distinct from a contract creation
+        let call = seismic_tx(TxKind::Call(Address::ZERO), version);
+        let create = seismic_tx(TxKind::Create, version);
+        let call_data = call.eip712_to_type_data().unwrap();
+        let create_data = create.eip712_to_type_data().unwrap();
+        assert_eq!(call_data.message["isCreate"], false);
+        assert_eq!(create_data.message["isCreate"], true);
+        assert_eq!(TxSeismic::eip712_decode(&call_data).unwrap(), call);
+        assert_eq!(TxSeismic::eip712_decode(&create_data).unwrap(), create);
+        assert_ne!(call.signature_hash(), create.signature_hash());
+
+        // earlier versions cannot tell them apart
+        let legacy_call = seismic_tx(TxKind::Call(Address::ZERO), 2);
+        let legacy_data = legacy_call.eip712_to_type_data().unwrap();
+        assert!(legacy_data.message.get("isCreate").is_none());
+        assert_eq!(TxSeismic::eip712_decode(&legacy_data).unwrap().to, TxKind::Create);
+    }
+
+    #[test]
+    fn test_eip712_decode_malformed() {
+        let mut typed_data = seismic_tx(TxKind::Create, TxSeismic::EIP712_EXPLICIT_CREATE_VERSION)
+            .eip712_to_type_data()
+            .unwrap();
+
+        // creation with a recipient
+        typed_data.message["to"] = Address::repeat_byte(1).to_string().into();
+        assert!(TxSeismic::eip712_decode(&typed_data).is_err());
+
+        // missing creation flag
+        typed_data.message.as_object_mut().unwrap().remove("isCreate");
+        assert!(TxSeismic::eip712_decode(&typed_data).is_err());
+
+        // malformed field
+        typed_data.message["nonce"] = "not a number".into();
+        assert!(TxSeismic::eip712_decode(&typed_data).is_err());
+    }
+
+    #[test]
+    fn test_arbitrary_roundtrip() {
+        use arbitrary::Arbitrary;
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x4a);
+        let mut bytes = [0u8; 1024];
+        for _ in 0..256 {
+            rng.fill(bytes.as_mut_slice());
+            let mut tx = TxSeismic::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap();
+            let sig = Signature::test_signature();
+
+            // RLP
+            let mut buf = vec![];
+            tx.rlp_encode_signed(&sig, &mut buf);
+            let decoded = TxSeismic::rlp_decode_signed(&mut &buf[..]).unwrap();
+            assert_eq!(decoded.tx(), &tx);
+
+            // EIP-712, with an explicit contract creation flag
+            tx.message_version = tx.message_version.max(TxSeismic::EIP712_EXPLICIT_CREATE_VERSION);
+            let typed_data = tx.eip712_to_type_data().unwrap();
+            assert_eq!(TxSeismic::eip712_decode(&typed_data).unwrap(), tx);
+            assert_eq!(tx.eip712_signature_hash().unwrap(), tx.signature_hash());
+            assert_eq!(tx.payload_len_for_signature(), tx.encoded_for_signing().len());
+        }
+    }
 }
@@ -0,0 +1,649 @@
+use crate::{transaction::RlpEcdsaTx, SignableTransaction, Signed, Transaction, TxType, Typed2718};
+use alloc::vec::Vec;
+use alloy_dyn_abi::TypedData;
+use alloy_eips::{
+    eip2930::AccessList,
+    eip712::{Eip712Error, Eip712Result, Encodable712, TypedDataRequest},
+    eip7702::SignedAuthorization,
+};
+use alloy_primitives::{
+    Bytes, ChainId, FixedBytes, PrimitiveSignature as Signature, TxKind, B256, U256,
+};
+use alloy_rlp::{BufMut, Decodable, Encodable};
+use core::mem;
+
+use super::{
+    seismic::{self, eip712_decode_kind, Eip712Field},
+    EncryptionPublicKey,
+};
+
+/// Encrypted transaction type with a priority fee
+/// ([EIP-1559](https://eips.ethereum.org/EIPS/eip-1559)) and an access list.
+///
+/// The dynamic-fee counterpart of [`TxSeismic`](super::TxSeismic).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[doc(alias = "SeismicEip1559Transaction", alias = "TransactionSeismicEip1559")]
+pub struct TxSeismicEip1559 {
+    /// EIP-155: Simple replay attack protection
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub chain_id: ChainId,
+    /// A scalar value equal to the number of transactions sent by the sender; formally Tn.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub nonce: u64,
+    /// A scalar value equal to the maximum
+    /// amount of gas that should be used in executing
+    /// this transaction. This is paid up-front, before any
+    /// computation is done and may not be increased
+    /// later; formally Tg.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "alloy_serde::quantity", rename = "gas", alias = "gasLimit")
+    )]
+    pub gas_limit: u64,
+    /// The maximum fee per gas the sender is willing to pay, base fee included.
+    ///
+    /// This is also known as `GasFeeCap`
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub max_fee_per_gas: u128,
+    /// Max Priority fee that transaction is paying
+    ///
+    /// This is also known as `GasTipCap`
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub max_priority_fee_per_gas: u128,
+    /// The 160-bit address of the message call’s recipient or, for a contract creation
+    /// transaction, ∅, used here to denote the only member of B0 ; formally Tt.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub to: TxKind,
+    /// A scalar value equal to the number of Wei to
+    /// be transferred to the message call’s recipient or,
+    /// in the case of contract creation, as an endowment
+    /// to the newly created account; formally Tv.
+    pub value: U256,
+    /// The accessList specifies a list of addresses and storage keys;
+    /// these addresses and storage keys are added into the `accessed_addresses`
+    /// and `accessed_storage_keys` global sets (introduced in EIP-2929).
+    /// A gas cost is charged, though at a discount relative to the cost of
+    /// accessing outside the list.
+    pub access_list: AccessList,
+    /// The public key we will decrypt to
+    #[cfg_attr(feature = "serde", serde(alias = "encryptionPubkey"))]
+    pub encryption_pubkey: EncryptionPublicKey,
+    /// The EIP712 version of the transaction when the user submitted it using signTypedDataV4.
+    /// A value of 0 means the transaction was not signed using EIP712
+    #[cfg_attr(feature = "serde", serde(alias = "messageVersion", default))]
+    pub message_version: u8,
+    /// Input has two uses depending if transaction is Create or Call (if `to` field is None or
+    /// Some). pub init: An unlimited size byte array specifying the
+    /// EVM-code for the account initialisation procedure CREATE,
+    /// data: An unlimited size byte array specifying the
+    /// input data of the message call, formally Td.
+    pub input: Bytes,
+}
+
+impl TxSeismicEip1559 {
+    /// numeric type for the transaction
+    ///
+    /// Follows [`TxSeismic::TX_TYPE`](super::TxSeismic::TX_TYPE). This type is not assigned by
+    /// the node yet, which does not decode dynamic-fee seismic transactions: they can be built and
+    /// signed, but sending one is rejected until the node supports it.
+    pub const TX_TYPE: u8 = 0x4B;
+
+    /// Get the transaction type
+    #[doc(alias = "transaction_type")]
+    pub(crate) const fn tx_type() -> TxType {
+        TxType::SeismicEip1559
+    }
+
+    /// Returns true if the transaction is signed using EIP712
+    pub const fn is_eip712(&self) -> bool {
+        self.message_version >= 2
+    }
+
+    /// Returns true if the EIP-712 schema of the transaction marks contract creation explicitly,
+    /// see [`TxSeismic::EIP712_EXPLICIT_CREATE_VERSION`](super::TxSeismic).
+    pub const fn has_explicit_create(&self) -> bool {
+        self.message_version >= super::TxSeismic::EIP712_EXPLICIT_CREATE_VERSION
+    }
+
+    /// Calculates a heuristic for the in-memory size of the [`TxSeismicEip1559`] transaction.
+    #[inline]
+    pub fn size(&self) -> usize {
+        mem::size_of::<ChainId>() + // chain_id
+        mem::size_of::<u64>() + // nonce
+        mem::size_of::<u64>() + // gas_limit
+        mem::size_of::<u128>() + // max_fee_per_gas
+        mem::size_of::<u128>() + // max_priority_fee_per_gas
+        self.to.size() + // to
+        mem::size_of::<U256>() + // value
+        self.access_list.size() + // access_list
+        self.encryption_pubkey.len() + // encryption public key
+        mem::size_of::<u8>() + // message_version
+        self.input.len() // input
+    }
+
+    /// Encodes a [`TxSeismicEip1559`] into a [`TypedData`].
+    pub fn eip712_to_type_data(&self) -> Eip712Result<TypedData> {
+        seismic::eip712_to_type_data(self)
+    }
+
+    /// Decodes a [`TypedData`] into a [`TxSeismicEip1559`].
+    pub fn eip712_decode(typed_data: &TypedData) -> Eip712Result<Self> {
+        let mut tx: Self = serde_json::from_value(typed_data.message.clone())
+            .map_err(|_| Eip712Error::DecodeError("Failed to deserialize message".to_string()))?;
+
+        tx.to = eip712_decode_kind(&typed_data.message, tx.to, tx.message_version)?;
+
+        Ok(tx)
+    }
+
+    /// Calculates the signing hash of the transaction's EIP-712 typed data.
+    pub fn eip712_signature_hash(&self) -> Eip712Result<B256> {
+        seismic::eip712_signature_hash(self)
+    }
+
+    /// Encodes the transaction for signing, see
+    /// [`TxSeismic::try_encode_for_signing`](super::TxSeismic::try_encode_for_signing).
+    pub fn try_encode_for_signing(&self, out: &mut dyn BufMut) -> Eip712Result<()> {
+        seismic::try_encode_for_signing(self, out)
+    }
+
+    /// Returns the length of the payload the transaction is signed over, see
+    /// [`TxSeismic::try_payload_len_for_signature`](super::TxSeismic::try_payload_len_for_signature).
+    pub fn try_payload_len_for_signature(&self) -> Eip712Result<usize> {
+        seismic::try_payload_len_for_signature(self)
+    }
+
+    /// Returns the hash the transaction is signed over, see
+    /// [`TxSeismic::try_signature_hash`](super::TxSeismic::try_signature_hash).
+    pub fn try_signature_hash(&self) -> Eip712Result<B256> {
+        seismic::try_signature_hash(self)
+    }
+
+    /// Signs the transaction with `signature`, see
+    /// [`TxSeismic::try_into_signed`](super::TxSeismic::try_into_signed).
+    pub fn try_into_signed(self, signature: Signature) -> Eip712Result<Signed<Self>> {
+        seismic::try_into_signed(self, signature)
+    }
+}
+
+impl TryFrom<Signed<TxSeismicEip1559>> for TypedDataRequest {
+    type Error = Eip712Error;
+
+    fn try_from(tx: Signed<TxSeismicEip1559>) -> Eip712Result<Self> {
+        Ok(Self { data: tx.tx().eip712_to_type_data()?, signature: *tx.signature() })
+    }
+}
+
+impl Encodable712 for Signed<TxSeismicEip1559> {
+    fn encode_712(&self) -> Eip712Result<Option<TypedDataRequest>> {
+        seismic::encode_712(self)
+    }
+}
+
+impl seismic::SeismicTx for TxSeismicEip1559 {
+    const EIP712_PRIMARY_TYPE: &'static str = "TxSeismicEip1559";
+
+    fn eip712_fee_fields(&self) -> Vec<Eip712Field> {
+        vec![
+            ("gasLimit", "uint64", self.gas_limit.to_string().into()),
+            ("maxFeePerGas", "uint128", self.max_fee_per_gas.to_string().into()),
+            ("maxPriorityFeePerGas", "uint128", self.max_priority_fee_per_gas.to_string().into()),
+        ]
+    }
+
+    fn eip712_extra_fields(&self) -> (Vec<Eip712Field>, serde_json::Value) {
+        let fields = vec![("accessList", "AccessListItem[]", serde_json::json!(self.access_list))];
+        let types = serde_json::json!({
+            "AccessListItem": [
+              { "name": "address", "type": "address" },
+              { "name": "storageKeys", "type": "bytes32[]" },
+            ],
+        });
+        (fields, types)
+    }
+}
+
+impl RlpEcdsaTx for TxSeismicEip1559 {
+    const DEFAULT_TX_TYPE: u8 = { Self::tx_type() as u8 };
+
+    /// Outputs the length of the transaction's fields, without a RLP header.
+    fn rlp_encoded_fields_length(&self) -> usize {
+        self.chain_id.length()
+            + self.nonce.length()
+            + self.max_priority_fee_per_gas.length()
+            + self.max_fee_per_gas.length()
+            + self.gas_limit.length()
+            + self.to.length()
+            + self.value.length()
+            + self.access_list.length()
+            + self.encryption_pubkey.length()
+            + self.message_version.length()
+            + self.input.length()
+    }
+
+    /// Encodes only the transaction's fields into the desired buffer, without
+    /// a RLP header.
+    fn rlp_encode_fields(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.chain_id.encode(out);
+        self.nonce.encode(out);
+        self.max_priority_fee_per_gas.encode(out);
+        self.max_fee_per_gas.encode(out);
+        self.gas_limit.encode(out);
+        self.to.encode(out);
+        self.value.encode(out);
+        self.access_list.encode(out);
+        self.encryption_pubkey.encode(out);
+        self.message_version.encode(out);
+        self.input.encode(out);
+    }
+
+    /// Decodes the inner [TxSeismicEip1559] fields from RLP bytes.
+    ///
+    /// NOTE: This assumes a RLP header has already been decoded, and _just_
+    /// decodes the following RLP fields in the following order:
+    ///
+    /// - `chain_id`
+    /// - `nonce`
+    /// - `max_priority_fee_per_gas`
+    /// - `max_fee_per_gas`
+    /// - `gas_limit`
+    /// - `to`
+    /// - `value`
+    /// - `access_list`
+    /// - `encryption_pubkey`
+    /// - `message_version`
+    /// - `data` (`input`)
+    fn rlp_decode_fields(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self {
+            chain_id: Decodable::decode(buf)?,
+            nonce: Decodable::decode(buf)?,
+            max_priority_fee_per_gas: Decodable::decode(buf)?,
+            max_fee_per_gas: Decodable::decode(buf)?,
+            gas_limit: Decodable::decode(buf)?,
+            to: Decodable::decode(buf)?,
+            value: Decodable::decode(buf)?,
+            access_list: Decodable::decode(buf)?,
+            encryption_pubkey: Decodable::decode(buf)?,
+            message_version: Decodable::decode(buf)?,
+            input: Decodable::decode(buf)?,
+        })
+    }
+}
+
+impl Transaction for TxSeismicEip1559 {
+    #[inline]
+    fn chain_id(&self) -> Option<ChainId> {
+        Some(self.chain_id)
+    }
+
+    #[inline]
+    fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    #[inline]
+    fn gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    #[inline]
+    fn gas_price(&self) -> Option<u128> {
+        None
+    }
+
+    #[inline]
+    fn max_fee_per_gas(&self) -> u128 {
+        self.max_fee_per_gas
+    }
+
+    #[inline]
+    fn max_priority_fee_per_gas(&self) -> Option<u128> {
+        Some(self.max_priority_fee_per_gas)
+    }
+
+    #[inline]
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        None
+    }
+
+    #[inline]
+    fn priority_fee_or_price(&self) -> u128 {
+        self.max_priority_fee_per_gas
+    }
+
+    fn effective_gas_price(&self, base_fee: Option<u64>) -> u128 {
+        base_fee.map_or(self.max_fee_per_gas, |base_fee| {
+            // if the tip is greater than the max priority fee per gas, set it to the max
+            // priority fee per gas + base fee
+            let tip = self.max_fee_per_gas.saturating_sub(base_fee as u128);
+            if tip > self.max_priority_fee_per_gas {
+                self.max_priority_fee_per_gas + base_fee as u128
+            } else {
+                // otherwise return the max fee per gas
+                self.max_fee_per_gas
+            }
+        })
+    }
+
+    #[inline]
+    fn is_dynamic_fee(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn kind(&self) -> TxKind {
+        self.to
+    }
+
+    #[inline]
+    fn is_create(&self) -> bool {
+        self.to.is_create()
+    }
+
+    #[inline]
+    fn value(&self) -> U256 {
+        self.value
+    }
+
+    #[inline]
+    fn input(&self) -> &Bytes {
+        &self.input
+    }
+
+    #[inline]
+    fn access_list(&self) -> Option<&AccessList> {
+        Some(&self.access_list)
+    }
+
+    #[inline]
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        None
+    }
+
+    #[inline]
+    fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
+        None
+    }
+
+    #[inline]
+    fn encryption_pubkey(&self) -> Option<&FixedBytes<33>> {
+        Some(&self.encryption_pubkey)
+    }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        Some(self.message_version)
+    }
+}
+
+impl Typed2718 for TxSeismicEip1559 {
+    fn ty(&self) -> u8 {
+        TxType::SeismicEip1559 as u8
+    }
+}
+
+impl SignableTransaction<Signature> for TxSeismicEip1559 {
+    fn set_chain_id(&mut self, chain_id: ChainId) {
+        self.chain_id = chain_id;
+    }
+
+    fn encode_for_signing(&self, out: &mut dyn alloy_rlp::BufMut) {
+        seismic::encode_for_signing(self, out)
+    }
+
+    fn payload_len_for_signature(&self) -> usize {
+        seismic::payload_len_for_signature(self)
+    }
+
+    fn into_signed(self, signature: Signature) -> Signed<Self> {
+        seismic::into_signed(self, signature)
+    }
+
+    fn signature_hash(&self) -> B256 {
+        seismic::signature_hash(self)
+    }
+}
+
+#[cfg(feature = "k256")]
+impl Signed<TxSeismicEip1559> {
+    /// If this was a signed call, recover the caller's address, see
+    /// [`Signed::<TxSeismic>::recover_caller`](super::TxSeismic).
+    pub fn recover_caller(
+        &self,
+    ) -> Result<alloy_primitives::Address, alloy_primitives::SignatureError> {
+        seismic::recover_caller(self)
+    }
+}
+
+impl Encodable for TxSeismicEip1559 {
+    fn encode(&self, out: &mut dyn BufMut) {
+        self.rlp_encode(out);
+    }
+
+    fn length(&self) -> usize {
+        self.rlp_encoded_length()
+    }
+}
+
+impl Decodable for TxSeismicEip1559 {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Self::rlp_decode(buf)
+    }
+}
+
+/// Bincode-compatible [`TxSeismicEip1559`] serde implementation.
+#[cfg(all(feature = "serde", feature = "serde-bincode-compat"))]
+pub(super) mod serde_bincode_compat {
+    use alloc::borrow::Cow;
+    use alloy_eips::eip2930::AccessList;
+    use alloy_primitives::{Bytes, ChainId, TxKind, U256};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+
+    /// Bincode-compatible [`super::TxSeismicEip1559`] serde implementation.
+    ///
+    /// Intended to use with the [`serde_with::serde_as`] macro in the following way:
+    /// ```rust
+    /// use alloy_consensus::{serde_bincode_compat, TxSeismicEip1559};
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Data {
+    ///     #[serde_as(as = "serde_bincode_compat::transaction::TxSeismicEip1559")]
+    ///     transaction: TxSeismicEip1559,
+    /// }
+    /// ```
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TxSeismicEip1559<'a> {
+        chain_id: ChainId,
+        nonce: u64,
+        gas_limit: u64,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+        #[serde(default)]
+        to: TxKind,
+        value: U256,
+        access_list: Cow<'a, AccessList>,
+        encryption_pubkey: Cow<'a, crate::transaction::EncryptionPublicKey>,
+        message_version: u8,
+        input: Cow<'a, Bytes>,
+    }
+
+    impl<'a> From<&'a super::TxSeismicEip1559> for TxSeismicEip1559<'a> {
+        fn from(value: &'a super::TxSeismicEip1559) -> Self {
+            Self {
+                chain_id: value.chain_id,
+                nonce: value.nonce,
+                gas_limit: value.gas_limit,
+                max_fee_per_gas: value.max_fee_per_gas,
+                max_priority_fee_per_gas: value.max_priority_fee_per_gas,
+                to: value.to,
+                value: value.value,
+                access_list: Cow::Borrowed(&value.access_list),
+                encryption_pubkey: Cow::Borrowed(&value.encryption_pubkey),
+                message_version: value.message_version,
+                input: Cow::Borrowed(&value.input),
+            }
+        }
+    }
+
+    impl<'a> From<TxSeismicEip1559<'a>> for super::TxSeismicEip1559 {
+        fn from(value: TxSeismicEip1559<'a>) -> Self {
+            Self {
+                chain_id: value.chain_id,
+                nonce: value.nonce,
+                gas_limit: value.gas_limit,
+                max_fee_per_gas: value.max_fee_per_gas,
+                max_priority_fee_per_gas: value.max_priority_fee_per_gas,
+                to: value.to,
+                value: value.value,
+                access_list: value.access_list.into_owned(),
+                encryption_pubkey: value.encryption_pubkey.into_owned(),
+                message_version: value.message_version,
+                input: value.input.into_owned(),
+            }
+        }
+    }
+
+    impl SerializeAs<super::TxSeismicEip1559> for TxSeismicEip1559<'_> {
+        fn serialize_as<S>(
+            source: &super::TxSeismicEip1559,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            TxSeismicEip1559::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de> DeserializeAs<'de, super::TxSeismicEip1559> for TxSeismicEip1559<'de> {
+        fn deserialize_as<D>(deserializer: D) -> Result<super::TxSeismicEip1559, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            TxSeismicEip1559::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use arbitrary::Arbitrary;
+        use rand::Rng;
+        use serde::{Deserialize, Serialize};
+        use serde_with::serde_as;
+
+        use super::super::{serde_bincode_compat, TxSeismicEip1559};
+
+        #[test]
+        fn test_tx_seismic_eip1559_bincode_roundtrip() {
+            #[serde_as]
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Data {
+                #[serde_as(as = "serde_bincode_compat::TxSeismicEip1559")]
+                transaction: TxSeismicEip1559,
+            }
+
+            let mut bytes = [0u8; 1024];
+            rand::thread_rng().fill(bytes.as_mut_slice());
+            let data = Data {
+                transaction: TxSeismicEip1559::arbitrary(&mut arbitrary::Unstructured::new(&bytes))
+                    .unwrap(),
+            };
+
+            let encoded = bincode::serialize(&data).unwrap();
+            let decoded: Data = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_eips::eip2930::AccessListItem;
+    use alloy_primitives::{address, b256, hex, Address};
+
+    use super::*;
+
+    fn tx(message_version: u8) -> TxSeismicEip1559 {
+        TxSeismicEip1559 {
+            chain_id: 4u64,
+            nonce: 2,
+            gas_limit: 100000,
+            max_fee_per_gas: 2000000000,
+            max_priority_fee_per_gas: 1000000000,
+            to: address!("d3e8763675e4c425df46cc3b5c0f6cbdac396046").into(),
+            value: U256::from(1000000000000000u64),
+            access_list: AccessList(vec![AccessListItem {
+                address: address!("5eee75727d804a2b13038928d36f8b188945a57a"),
+                storage_keys: vec![B256::with_last_byte(1)],
+            }]),
+            encryption_pubkey: hex!("028e76821eb4d77fd30223ca971c49738eb5b5b71eabe93f96b348fdce788ae5a0").into(),
+            message_version,
+            input: hex!("a22cb4650000000000000000000000005eee75727d804a2b13038928d36f8b188945a57a0000000000000000000000000000000000000000000000000000000000000000").into(),
+        }
+    }
+
+    fn signature() -> Signature {
+        Signature::from_scalars_and_parity(
+            b256!("840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca90058565"),
+            b256!("25e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1"),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_encode_decode_seismic_eip1559() {
+        let tx = tx(0);
+        let sig = signature();
+
+        let mut buf = vec![];
+        tx.rlp_encode_signed(&sig, &mut buf);
+        let decoded = TxSeismicEip1559::rlp_decode_signed(&mut &buf[..]).unwrap();
+        assert_eq!(decoded, tx.clone().into_signed(sig));
+        assert_eq!(decoded.tx(), &tx);
+
+        // the fee fields are dynamic
+        assert!(tx.is_dynamic_fee());
+        assert_eq!(tx.gas_price(), None);
+        assert_eq!(tx.effective_gas_price(Some(1500000000)), 2000000000);
+        assert_eq!(tx.effective_gas_price(Some(500000000)), 1500000000);
+    }
+
+    #[test]
+    fn test_eip712_encode_decode_seismic_eip1559() {
+        let tx = tx(2);
+        let typed_data = tx.eip712_to_type_data().unwrap();
+        let decoded = TxSeismicEip1559::eip712_decode(&typed_data).unwrap();
+        assert_eq!(decoded, tx);
+
+        let sig = signature();
+        let signed = tx.clone().into_signed(sig);
+        assert_ne!(*signed.hash(), tx.signature_hash());
+        let typed_data_request = signed.encode_712().unwrap().unwrap();
+        assert_eq!(typed_data_request.data, typed_data);
+        assert_eq!(typed_data_request.signature, sig);
+
+        // transactions signed over their RLP encoding have no typed data form
+        assert!(TxSeismicEip1559 { message_version: 0, ..tx }
+            .into_signed(sig)
+            .encode_712()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_eip712_explicit_create_seismic_eip1559() {
+        let version = super::super::TxSeismic::EIP712_EXPLICIT_CREATE_VERSION;
+        for to in [TxKind::Create, TxKind::Call(Address::ZERO)] {
+            let tx = TxSeismicEip1559 { to, ..tx(version) };
+            let typed_data = tx.eip712_to_type_data().unwrap();
+            assert_eq!(TxSeismicEip1559::eip712_decode(&typed_data).unwrap(), tx);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     Transaction, TxEip1559, TxEip2930, TxEip7702, TxEnvelope, TxLegacy, TxType, Typed2718,
 };
 
-use super::TxSeismic;
+use super::{TxSeismic, TxSeismicEip1559};
 
 /// The TypedTransaction enum represents all Ethereum transaction request types.
 ///
@@ -43,8 +43,11 @@ pub enum TypedTransaction {
     #[cfg_attr(feature = "serde", serde(rename = "0x04", alias = "0x4"))]
     Eip7702(TxEip7702),
     /// Seismic transaction
-    #[cfg_attr(feature = "serde", serde(rename = "0x4A", alias = "0x4A"))]
+    #[cfg_attr(feature = "serde", serde(rename = "0x4a", alias = "0x4A"))]
     Seismic(TxSeismic),
+    /// Dynamic-fee seismic transaction
+    #[cfg_attr(feature = "serde", serde(rename = "0x4b", alias = "0x4B"))]
+    SeismicEip1559(TxSeismicEip1559),
 }
 
 impl From<TxLegacy> for TypedTransaction {
@@ -95,11 +98,18 @@ impl From<TxSeismic> for TypedTransaction {
     }
 }
 
+impl From<TxSeismicEip1559> for TypedTransaction {
+    fn from(tx: TxSeismicEip1559) -> Self {
+        Self::SeismicEip1559(tx)
+    }
+}
+
 impl From<TxEnvelope> for TypedTransaction {
     fn from(envelope: TxEnvelope) -> Self {
         match envelope {
             TxEnvelope::Legacy(tx) => Self::Legacy(tx.strip_signature()),
             TxEnvelope::Seismic(tx) => Self::Seismic(tx.strip_signature()),
+            TxEnvelope::SeismicEip1559(tx) => Self::SeismicEip1559(tx.strip_signature()),
             TxEnvelope::Eip2930(tx) => Self::Eip2930(tx.strip_signature()),
             TxEnvelope::Eip1559(tx) => Self::Eip1559(tx.strip_signature()),
             TxEnvelope::Eip4844(tx) => Self::Eip4844(tx.strip_signature()),
@@ -115,6 +125,7 @@ impl TypedTransaction {
         match self {
             Self::Legacy(_) => TxType::Legacy,
             Self::Seismic(_) => TxType::Seismic,
+            Self::SeismicEip1559(_) => TxType::SeismicEip1559,
             Self::Eip2930(_) => TxType::Eip2930,
             Self::Eip1559(_) => TxType::Eip1559,
             Self::Eip4844(_) => TxType::Eip4844,
@@ -161,6 +172,14 @@ impl TypedTransaction {
             _ => None,
         }
     }
+
+    /// Return the inner dynamic-fee seismic transaction if it exists.
+    pub const fn seismic_eip1559(&self) -> Option<&TxSeismicEip1559> {
+        match self {
+            Self::SeismicEip1559(tx) => Some(tx),
+            _ => None,
+        }
+    }
 }
 
 impl Transaction for TypedTransaction {
@@ -169,6 +188,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.chain_id(),
             Self::Seismic(tx) => tx.chain_id(),
+            Self::SeismicEip1559(tx) => tx.chain_id(),
             Self::Eip2930(tx) => tx.chain_id(),
             Self::Eip1559(tx) => tx.chain_id(),
             Self::Eip4844(tx) => tx.chain_id(),
@@ -181,6 +201,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.nonce(),
             Self::Seismic(tx) => tx.nonce(),
+            Self::SeismicEip1559(tx) => tx.nonce(),
             Self::Eip2930(tx) => tx.nonce(),
             Self::Eip1559(tx) => tx.nonce(),
             Self::Eip4844(tx) => tx.nonce(),
@@ -193,6 +214,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.gas_limit(),
             Self::Seismic(tx) => tx.gas_limit(),
+            Self::SeismicEip1559(tx) => tx.gas_limit(),
             Self::Eip2930(tx) => tx.gas_limit(),
             Self::Eip1559(tx) => tx.gas_limit(),
             Self::Eip4844(tx) => tx.gas_limit(),
@@ -205,6 +227,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.gas_price(),
             Self::Seismic(tx) => tx.gas_price(),
+            Self::SeismicEip1559(tx) => tx.gas_price(),
             Self::Eip2930(tx) => tx.gas_price(),
             Self::Eip1559(tx) => tx.gas_price(),
             Self::Eip4844(tx) => tx.gas_price(),
@@ -217,6 +240,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.max_fee_per_gas(),
             Self::Seismic(tx) => tx.max_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.max_fee_per_gas(),
             Self::Eip2930(tx) => tx.max_fee_per_gas(),
             Self::Eip1559(tx) => tx.max_fee_per_gas(),
             Self::Eip4844(tx) => tx.max_fee_per_gas(),
@@ -229,6 +253,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.max_priority_fee_per_gas(),
             Self::Seismic(tx) => tx.max_priority_fee_per_gas(),
+            Self::SeismicEip1559(tx) => tx.max_priority_fee_per_gas(),
             Self::Eip2930(tx) => tx.max_priority_fee_per_gas(),
             Self::Eip1559(tx) => tx.max_priority_fee_per_gas(),
             Self::Eip4844(tx) => tx.max_priority_fee_per_gas(),
@@ -241,6 +266,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.max_fee_per_blob_gas(),
             Self::Seismic(tx) => tx.max_fee_per_blob_gas(),
+            Self::SeismicEip1559(tx) => tx.max_fee_per_blob_gas(),
             Self::Eip2930(tx) => tx.max_fee_per_blob_gas(),
             Self::Eip1559(tx) => tx.max_fee_per_blob_gas(),
             Self::Eip4844(tx) => tx.max_fee_per_blob_gas(),
@@ -253,6 +279,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.priority_fee_or_price(),
             Self::Seismic(tx) => tx.priority_fee_or_price(),
+            Self::SeismicEip1559(tx) => tx.priority_fee_or_price(),
             Self::Eip2930(tx) => tx.priority_fee_or_price(),
             Self::Eip1559(tx) => tx.priority_fee_or_price(),
             Self::Eip4844(tx) => tx.priority_fee_or_price(),
@@ -264,6 +291,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.effective_gas_price(base_fee),
             Self::Seismic(tx) => tx.effective_gas_price(base_fee),
+            Self::SeismicEip1559(tx) => tx.effective_gas_price(base_fee),
             Self::Eip2930(tx) => tx.effective_gas_price(base_fee),
             Self::Eip1559(tx) => tx.effective_gas_price(base_fee),
             Self::Eip4844(tx) => tx.effective_gas_price(base_fee),
@@ -276,6 +304,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.is_dynamic_fee(),
             Self::Seismic(tx) => tx.is_dynamic_fee(),
+            Self::SeismicEip1559(tx) => tx.is_dynamic_fee(),
             Self::Eip2930(tx) => tx.is_dynamic_fee(),
             Self::Eip1559(tx) => tx.is_dynamic_fee(),
             Self::Eip4844(tx) => tx.is_dynamic_fee(),
@@ -288,6 +317,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.kind(),
             Self::Seismic(tx) => tx.kind(),
+            Self::SeismicEip1559(tx) => tx.kind(),
             Self::Eip2930(tx) => tx.kind(),
             Self::Eip1559(tx) => tx.kind(),
             Self::Eip4844(tx) => tx.kind(),
@@ -300,6 +330,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.is_create(),
             Self::Seismic(tx) => tx.is_create(),
+            Self::SeismicEip1559(tx) => tx.is_create(),
             Self::Eip2930(tx) => tx.is_create(),
             Self::Eip1559(tx) => tx.is_create(),
             Self::Eip4844(tx) => tx.is_create(),
@@ -312,6 +343,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.value(),
             Self::Seismic(tx) => tx.value(),
+            Self::SeismicEip1559(tx) => tx.value(),
             Self::Eip2930(tx) => tx.value(),
             Self::Eip1559(tx) => tx.value(),
             Self::Eip4844(tx) => tx.value(),
@@ -324,6 +356,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.input(),
             Self::Seismic(tx) => tx.input(),
+            Self::SeismicEip1559(tx) => tx.input(),
             Self::Eip2930(tx) => tx.input(),
             Self::Eip1559(tx) => tx.input(),
             Self::Eip4844(tx) => tx.input(),
@@ -336,6 +369,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.access_list(),
             Self::Seismic(tx) => tx.access_list(),
+            Self::SeismicEip1559(tx) => tx.access_list(),
             Self::Eip2930(tx) => tx.access_list(),
             Self::Eip1559(tx) => tx.access_list(),
             Self::Eip4844(tx) => tx.access_list(),
@@ -348,6 +382,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.blob_versioned_hashes(),
             Self::Seismic(tx) => tx.blob_versioned_hashes(),
+            Self::SeismicEip1559(tx) => tx.blob_versioned_hashes(),
             Self::Eip2930(tx) => tx.blob_versioned_hashes(),
             Self::Eip1559(tx) => tx.blob_versioned_hashes(),
             Self::Eip4844(tx) => tx.blob_versioned_hashes(),
@@ -360,6 +395,7 @@ impl Transaction for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.authorization_list(),
             Self::Seismic(tx) => tx.authorization_list(),
+            Self::SeismicEip1559(tx) => tx.authorization_list(),
             Self::Eip2930(tx) => tx.authorization_list(),
             Self::Eip1559(tx) => tx.authorization_list(),
             Self::Eip4844(tx) => tx.authorization_list(),
@@ -371,6 +407,7 @@ impl Transaction for TypedTransaction {
     fn encryption_pubkey(&self) -> Option<&crate::transaction::EncryptionPublicKey> {
         match self {
             Self::Seismic(tx) => tx.encryption_pubkey(),
+            Self::SeismicEip1559(tx) => tx.encryption_pubkey(),
             _ => None,
         }
     }
@@ -379,6 +416,7 @@ impl Transaction for TypedTransaction {
     fn message_version(&self) -> Option<u8> {
         match self {
             Self::Seismic(tx) => tx.message_version(),
+            Self::SeismicEip1559(tx) => tx.message_version(),
             _ => None,
         }
     }
@@ -389,6 +427,7 @@ impl Typed2718 for TypedTransaction {
         match self {
             Self::Legacy(tx) => tx.ty(),
             Self::Seismic(tx) => tx.ty(),
+            Self::SeismicEip1559(tx) => tx.ty(),
             Self::Eip2930(tx) => tx.ty(),
             Self::Eip1559(tx) => tx.ty(),
             Self::Eip4844(tx) => tx.ty(),
@@ -425,8 +464,8 @@ mod serde_from {
     //! We serialize via [`TaggedTypedTransaction`] and deserialize via
     //! [`MaybeTaggedTypedTransaction`].
     use crate::{
-        transaction::TxSeismic, TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702, TxLegacy,
-        TypedTransaction,
+        transaction::{TxSeismic, TxSeismicEip1559},
+        TxEip1559, TxEip2930, TxEip4844Variant, TxEip7702, TxLegacy, TypedTransaction,
     };
 
     #[derive(Debug, serde::Deserialize)]
@@ -459,9 +498,12 @@ mod serde_from {
         /// EIP-7702 transaction
         #[serde(rename = "0x04", alias = "0x4")]
         Eip7702(TxEip7702),
-        /// EIP-7702 transaction
-        #[serde(rename = "0x4A", alias = "0x4A")]
+        /// Seismic transaction
+        #[serde(rename = "0x4a", alias = "0x4A")]
         Seismic(TxSeismic),
+        /// Dynamic-fee seismic transaction
+        #[serde(rename = "0x4b", alias = "0x4B")]
+        SeismicEip1559(TxSeismicEip1559),
     }
 
     impl From<MaybeTaggedTypedTransaction> for TypedTransaction {
@@ -478,6 +520,7 @@ mod serde_from {
             match value {
                 TaggedTypedTransaction::Legacy(signed) => Self::Legacy(signed),
                 TaggedTypedTransaction::Seismic(signed) => Self::Seismic(signed),
+                TaggedTypedTransaction::SeismicEip1559(signed) => Self::SeismicEip1559(signed),
                 TaggedTypedTransaction::Eip2930(signed) => Self::Eip2930(signed),
                 TaggedTypedTransaction::Eip1559(signed) => Self::Eip1559(signed),
                 TaggedTypedTransaction::Eip4844(signed) => Self::Eip4844(signed),
@@ -491,6 +534,7 @@ mod serde_from {
             match value {
                 TypedTransaction::Legacy(signed) => Self::Legacy(signed),
                 TypedTransaction::Seismic(signed) => Self::Seismic(signed),
+                TypedTransaction::SeismicEip1559(signed) => Self::SeismicEip1559(signed),
                 TypedTransaction::Eip2930(signed) => Self::Eip2930(signed),
                 TypedTransaction::Eip1559(signed) => Self::Eip1559(signed),
                 TypedTransaction::Eip4844(signed) => Self::Eip4844(signed),
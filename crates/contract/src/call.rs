@@ -1,10 +1,11 @@
 use crate::{CallDecoder, Error, EthCall, Result};
+use alloy_consensus::TxSeismic;
 use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
 use alloy_json_abi::Function;
 use alloy_network::{Ethereum, Network, TransactionBuilder, TransactionBuilder4844};
 use alloy_network_primitives::ReceiptResponse;
 use alloy_primitives::{Address, Bytes, ChainId, TxKind, U256};
-use alloy_provider::{PendingTransactionBuilder, Provider};
+use alloy_provider::{PendingTransactionBuilder, Provider, SeismicCallOptions, SendableTx};
 use alloy_rpc_types_eth::{state::StateOverride, AccessList, BlobTransactionSidecar, BlockId};
 use alloy_sol_types::SolCall;
 use alloy_transport::Transport;
@@ -12,6 +13,7 @@ use std::{
     future::{Future, IntoFuture},
     marker::PhantomData,
     pin::Pin,
+    sync::Arc,
 };
 
 /// [`CallBuilder`] using a [`SolCall`] type as the call decoder.
@@ -125,6 +127,7 @@ pub struct CallBuilder<T, P, D, N: Network = Ethereum> {
     request: N::TransactionRequest,
     block: BlockId,
     state: Option<StateOverride>,
+    mode: CallMode,
     /// The provider.
     // NOTE: This is public due to usage in `sol!`, please avoid changing it.
     pub provider: P,
@@ -132,6 +135,18 @@ pub struct CallBuilder<T, P, D, N: Network = Ethereum> {
     transport: PhantomData<T>,
 }
 
+/// How a [`CallBuilder`] executes calls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum CallMode {
+    /// Plain `eth_call`.
+    #[default]
+    Plain,
+    /// Unsigned seismic call, see [`Provider::seismic_call`].
+    Seismic,
+    /// Signed seismic call, see [`Provider::seismic_signed_call`].
+    SignedRead,
+}
+
 impl<T, P, D, N: Network> CallBuilder<T, P, D, N> {
     /// Converts the call builder to the inner transaction request
     pub fn into_transaction_request(self) -> N::TransactionRequest {
@@ -168,6 +183,7 @@ impl<T: Transport + Clone, P: Provider<T, N>, N: Network> DynCallBuilder<T, P, N
             request: self.request,
             block: self.block,
             state: self.state,
+            mode: self.mode,
             provider: self.provider,
             decoder: (),
             transport: PhantomData,
@@ -194,6 +210,7 @@ impl<T: Transport + Clone, P: Provider<T, N>, C: SolCall, N: Network> SolCallBui
             request: self.request,
             block: self.block,
             state: self.state,
+            mode: self.mode,
             provider: self.provider,
             decoder: (),
             transport: PhantomData,
@@ -259,6 +276,7 @@ impl<T: Transport + Clone, P: Provider<T, N>, N: Network> RawCallBuilder<T, P, N
             request: self.request,
             block: self.block,
             state: self.state,
+            mode: self.mode,
             provider: self.provider,
             decoder: PhantomData::<C>,
             transport: PhantomData,
@@ -294,6 +312,7 @@ impl<T: Transport + Clone, P: Provider<T, N>, D: CallDecoder, N: Network> CallBu
             provider,
             block: BlockId::default(),
             state: None,
+            mode: CallMode::default(),
             transport: PhantomData,
         }
     }
@@ -305,6 +324,7 @@ impl<T: Transport + Clone, P: Provider<T, N>, D: CallDecoder, N: Network> CallBu
             provider,
             block: BlockId::default(),
             state: None,
+            mode: CallMode::default(),
             transport: PhantomData,
         }
     }
@@ -342,6 +362,32 @@ impl<T: Transport + Clone, P: Provider<T, N>, D: CallDecoder, N: Network> CallBu
         self
     }
 
+    /// Executes the call as a seismic transaction, for contracts with
+    /// shielded state.
+    ///
+    /// Calls go through [`Provider::seismic_call`], which encrypts the input
+    /// and decrypts the output before it is decoded. Transactions sent with
+    /// [`send`](Self::send) are encrypted by the provider as well.
+    ///
+    /// The provider must be built with a `SeismicLayer`, otherwise the
+    /// calldata is sent in plain text.
+    pub fn seismic(mut self) -> Self {
+        self.request.set_transaction_type(TxSeismic::TX_TYPE);
+        self.mode = CallMode::Seismic;
+        self
+    }
+
+    /// Like [`seismic`](Self::seismic), but calls are signed, through
+    /// [`Provider::seismic_signed_call`].
+    ///
+    /// Required for view functions that authenticate `msg.sender`. The call
+    /// fails if the provider has no wallet to sign it.
+    pub fn signed_read(mut self) -> Self {
+        self.request.set_transaction_type(TxSeismic::TX_TYPE);
+        self.mode = CallMode::SignedRead;
+        self
+    }
+
     /// Uses a Legacy transaction instead of an EIP-1559 one to execute the call
     pub fn legacy(self) -> Self {
         todo!()
@@ -445,6 +491,9 @@ impl<T: Transport + Clone, P: Provider<T, N>, D: CallDecoder, N: Network> CallBu
     ///
     /// Returns the decoded the output by using the provided decoder.
     /// If this is not desired, use [`call_raw`](Self::call_raw) to get the raw output data.
+    ///
+    /// In the [`seismic`](Self::seismic) and [`signed_read`](Self::signed_read) modes the call
+    /// goes through the encrypting provider and the decrypted output is decoded.
     #[doc(alias = "eth_call")]
     #[doc(alias = "call_with_overrides")]
     pub fn call(&self) -> EthCall<'_, '_, D, T, N> {
@@ -458,12 +507,36 @@ impl<T: Transport + Clone, P: Provider<T, N>, D: CallDecoder, N: Network> CallBu
     ///
     /// See [`call`](Self::call) for more information.
     pub fn call_raw(&self) -> EthCall<'_, '_, (), T, N> {
-        let call = self.provider.call(&self.request).block(self.block);
-        let call = match &self.state {
-            Some(state) => call.overrides(state),
-            None => call,
-        };
-        call.into()
+        let provider = &self.provider;
+        let request = &self.request;
+        match self.mode {
+            CallMode::Plain => {
+                let call = provider.call(request).block(self.block);
+                let call = match &self.state {
+                    Some(state) => call.overrides(state),
+                    None => call,
+                };
+                call.into()
+            }
+            CallMode::Seismic | CallMode::SignedRead => {
+                let signed = self.mode == CallMode::SignedRead;
+                let mut options = SeismicCallOptions::default().with_block(self.block);
+                if let Some(state) = &self.state {
+                    options = options.with_overrides(state.clone());
+                }
+                EthCall::new_seismic(
+                    Arc::new(move |options| {
+                        let tx = SendableTx::Builder(request.clone());
+                        if signed {
+                            provider.seismic_signed_call_with(tx, options)
+                        } else {
+                            provider.seismic_call_with(tx, options)
+                        }
+                    }),
+                    options,
+                )
+            }
+        }
     }
 
     /// Decodes the output of a contract function using the provided decoder.
@@ -515,6 +588,7 @@ impl<T: Transport, P: Clone, D, N: Network> CallBuilder<T, &P, D, N> {
             request: self.request,
             block: self.block,
             state: self.state,
+            mode: self.mode,
             provider: self.provider.clone(),
             decoder: self.decoder,
             transport: PhantomData,
@@ -559,6 +633,7 @@ impl<T, P, D: CallDecoder, N: Network> std::fmt::Debug for CallBuilder<T, P, D,
             .field("request", &self.request)
             .field("block", &self.block)
             .field("state", &self.state)
+            .field("mode", &self.mode)
             .field("decoder", &self.decoder.as_debug_field())
             .finish()
     }
@@ -568,12 +643,15 @@ impl<T, P, D: CallDecoder, N: Network> std::fmt::Debug for CallBuilder<T, P, D,
 mod tests {
     use super::*;
     use alloy_consensus::Transaction;
+    use alloy_network::EthereumWallet;
     use alloy_primitives::{address, b256, bytes, hex, utils::parse_units, B256};
     use alloy_provider::{
-        layers::AnvilProvider, Provider, ProviderBuilder, RootProvider, WalletProvider,
+        layers::AnvilProvider, MockSeismicNode, MockSeismicRequestKind, Provider, ProviderBuilder,
+        RootProvider, SeismicError, SeismicLayer, WalletProvider,
     };
     use alloy_rpc_types_eth::AccessListItem;
-    use alloy_sol_types::sol;
+    use alloy_signer_local::PrivateKeySigner;
+    use alloy_sol_types::{sol, SolValue};
 
     #[test]
     fn empty_constructor() {
@@ -792,4 +870,68 @@ mod tests {
             "max_priority_fee_per_gas of the transaction should be set to the right value"
         )
     }
+
+    #[tokio::test]
+    async fn seismic_call_modes() {
+        let node = MockSeismicNode::new().with_handler(|_| Ok(true.abi_encode().into()));
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+        let contract = MyContract::new(Address::ZERO, &provider);
+
+        // seismic calls are encrypted, and their decrypted output decoded
+        let MyContract::myStateReturn { myState: state } =
+            contract.myState().seismic().call().await.unwrap();
+        assert!(state);
+        let request = node.seismic_requests().pop().unwrap();
+        assert_eq!(request.kind, MockSeismicRequestKind::Call);
+        assert!(request.encryption_pubkey.is_some());
+        assert_eq!(request.input, Bytes::from(MyContract::myStateCall {}.abi_encode()));
+
+        // without a wallet the signed read is never sent
+        let err = contract.myState().signed_read().call().await.err().unwrap();
+        assert!(err.to_string().contains("signed call"), "{err}");
+        assert_eq!(node.request_count("eth_call"), 1);
+
+        // the block and state overrides of seismic calls are forwarded to the node
+        let last_call_params = || {
+            let request = node.requests().into_iter().rev().find(|r| r.method() == "eth_call");
+            let params = request.unwrap().params().unwrap().get().to_owned();
+            serde_json::from_str::<Vec<serde_json::Value>>(&params).unwrap()
+        };
+        contract.myState().seismic().block(BlockId::number(1)).call().await.unwrap();
+        assert_eq!(last_call_params()[1], "0x1");
+        contract.myState().seismic().call().block(BlockId::number(2)).await.unwrap();
+        assert_eq!(last_call_params()[1], "0x2");
+        let state = StateOverride::default();
+        contract.myState().seismic().state(state.clone()).call().await.unwrap();
+        assert_eq!(last_call_params()[2], serde_json::to_value(&state).unwrap());
+        contract.myState().seismic().call().overrides(&state).await.unwrap();
+        assert_eq!(last_call_params().len(), 3);
+        assert_eq!(node.request_count("eth_call"), 5);
+
+        // signed reads are signed by the wallet of the provider
+        let signer = PrivateKeySigner::random();
+        let from = signer.address();
+        let provider =
+            ProviderBuilder::new().seismic(EthereumWallet::from(signer)).on_client(node.client());
+        let contract = MyContract::new(Address::ZERO, &provider);
+        let MyContract::myStateReturn { myState: state } =
+            contract.myState().from(from).signed_read().call().await.unwrap();
+        assert!(state);
+        let request = node.seismic_requests().pop().unwrap();
+        assert_eq!(request.kind, MockSeismicRequestKind::SignedCall);
+        assert_eq!(request.from, Some(from));
+    }
+
+    #[tokio::test]
+    async fn seismic_call_error() {
+        // the node serves a TEE public key which is not a valid point
+        let node =
+            MockSeismicNode::new().with_response("seismic_getTeePublicKey", Bytes::from([5; 33]));
+        let provider = ProviderBuilder::new().seismic_unsigned().on_client(node.client());
+        let contract = MyContract::new(Address::ZERO, &provider);
+
+        let err = contract.myState().seismic().nonce(0).call().await.err().unwrap();
+        assert!(matches!(err.as_seismic_error(), Some(SeismicError::InvalidTeePubkey(_))), "{err}");
+        assert_eq!(node.request_count("eth_call"), 0);
+    }
 }
@@ -1,6 +1,6 @@
 use alloy_dyn_abi::Error as AbiError;
 use alloy_primitives::Selector;
-use alloy_provider::PendingTransactionError;
+use alloy_provider::{PendingTransactionError, SeismicError};
 use alloy_transport::TransportError;
 use thiserror::Error;
 
@@ -39,3 +39,17 @@ impl From<alloy_sol_types::Error> for Error {
         Self::AbiError(e.into())
     }
 }
+
+impl Error {
+    /// Returns the [`SeismicError`] if the seismic provider failed to encrypt
+    /// the call or to decrypt its output.
+    pub fn as_seismic_error(&self) -> Option<&SeismicError> {
+        match self {
+            Self::TransportError(err)
+            | Self::PendingTransactionError(PendingTransactionError::TransportError(err)) => {
+                SeismicError::from_transport_err(err)
+            }
+            _ => None,
+        }
+    }
+}
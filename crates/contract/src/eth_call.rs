@@ -1,12 +1,18 @@
-use std::{future::IntoFuture, marker::PhantomData};
+use std::{
+    future::{Future, IntoFuture},
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+};
 
 use alloy_dyn_abi::{DynSolValue, FunctionExt};
 use alloy_json_abi::Function;
 use alloy_network::Network;
 use alloy_primitives::Bytes;
+use alloy_provider::SeismicCallOptions;
 use alloy_rpc_types_eth::{state::StateOverride, BlockId};
 use alloy_sol_types::SolCall;
-use alloy_transport::Transport;
+use alloy_transport::{Transport, TransportResult};
 
 use crate::{Error, Result};
 
@@ -21,7 +27,54 @@ mod private {
     impl Sealed for () {}
 }
 
+/// Future of a call made through [`Provider::seismic_call`].
+///
+/// [`Provider::seismic_call`]: alloy_provider::Provider::seismic_call
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type SeismicCallFut<'req> =
+    Pin<Box<dyn Future<Output = TransportResult<Bytes>> + Send + 'req>>;
+
+/// Future of a call made through [`Provider::seismic_call`].
+///
+/// [`Provider::seismic_call`]: alloy_provider::Provider::seismic_call
+#[cfg(target_arch = "wasm32")]
+pub(crate) type SeismicCallFut<'req> = Pin<Box<dyn Future<Output = TransportResult<Bytes>> + 'req>>;
+
+/// Starts a seismic call with the given block and state overrides, invoked
+/// once the [`EthCall`] is awaited.
+pub(crate) type SeismicCaller<'req> =
+    Arc<dyn Fn(SeismicCallOptions) -> SeismicCallFut<'req> + Send + Sync + 'req>;
+
+/// The call wrapped by an [`EthCall`].
+#[derive(Clone)]
+enum Inner<'req, T, N>
+where
+    T: Transport + Clone,
+    N: Network,
+{
+    /// A plain `eth_call`.
+    Eth(alloy_provider::EthCall<'req, T, N, Bytes>),
+    /// A call whose input is encrypted and output decrypted by the provider.
+    Seismic(SeismicCaller<'req>, SeismicCallOptions),
+}
+
+impl<T, N> std::fmt::Debug for Inner<'_, T, N>
+where
+    T: Transport + Clone,
+    N: Network,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eth(call) => f.debug_tuple("Eth").field(call).finish(),
+            Self::Seismic(_, options) => f.debug_tuple("Seismic").field(options).finish(),
+        }
+    }
+}
+
 /// An [`alloy_provider::EthCall`] with an abi decoder.
+///
+/// Calls built by a [`CallBuilder`](crate::CallBuilder) in one of its seismic
+/// modes go through the encrypting provider instead.
 #[must_use = "EthCall must be awaited to execute the call"]
 #[derive(Clone, Debug)]
 pub struct EthCall<'req, 'coder, D, T, N>
@@ -30,7 +83,7 @@ where
     N: Network,
     D: CallDecoder,
 {
-    inner: alloy_provider::EthCall<'req, T, N, Bytes>,
+    inner: Inner<'req, T, N>,
 
     decoder: &'coder D,
 }
@@ -46,7 +99,7 @@ where
         inner: alloy_provider::EthCall<'req, T, N, Bytes>,
         decoder: &'coder D,
     ) -> Self {
-        Self { inner, decoder }
+        Self { inner: Inner::Eth(inner), decoder }
     }
 }
 
@@ -59,6 +112,11 @@ where
     pub const fn new_raw(inner: alloy_provider::EthCall<'req, T, N, Bytes>) -> Self {
         Self::new(inner, &RAW_CODER)
     }
+
+    /// Create a new [`EthCall`] executed by `caller` with `options`.
+    pub(crate) fn new_seismic(caller: SeismicCaller<'req>, options: SeismicCallOptions) -> Self {
+        Self { inner: Inner::Seismic(caller, options), decoder: &RAW_CODER }
+    }
 }
 
 impl<'req, D, T, N> EthCall<'req, '_, D, T, N>
@@ -80,13 +138,21 @@ where
 
     /// Set the state overrides for this call.
     pub fn overrides(mut self, overrides: &'req StateOverride) -> Self {
-        self.inner = self.inner.overrides(overrides);
+        self.inner = match self.inner {
+            Inner::Eth(call) => Inner::Eth(call.overrides(overrides)),
+            Inner::Seismic(caller, options) => {
+                Inner::Seismic(caller, options.with_overrides(overrides.clone()))
+            }
+        };
         self
     }
 
     /// Set the block to use for this call.
     pub fn block(mut self, block: BlockId) -> Self {
-        self.inner = self.inner.block(block);
+        self.inner = match self.inner {
+            Inner::Eth(call) => Inner::Eth(call.block(block)),
+            Inner::Seismic(caller, options) => Inner::Seismic(caller, options.with_block(block)),
+        };
         self
     }
 }
@@ -98,7 +164,7 @@ where
     N: Network,
 {
     fn from(inner: alloy_provider::EthCall<'req, T, N, Bytes>) -> Self {
-        Self { inner, decoder: &RAW_CODER }
+        Self::new_raw(inner)
     }
 }
 
@@ -113,7 +179,34 @@ where
     type IntoFuture = EthCallFut<'req, 'coder, D, T, N>;
 
     fn into_future(self) -> Self::IntoFuture {
-        EthCallFut { inner: self.inner.into_future(), decoder: self.decoder }
+        let inner = match self.inner {
+            Inner::Eth(call) => FutInner::Eth(call.into_future()),
+            Inner::Seismic(caller, options) => FutInner::Seismic(caller(options)),
+        };
+        EthCallFut { inner, decoder: self.decoder }
+    }
+}
+
+/// The future wrapped by an [`EthCallFut`].
+enum FutInner<'req, T, N>
+where
+    T: Transport + Clone,
+    N: Network,
+{
+    Eth(<alloy_provider::EthCall<'req, T, N, Bytes> as IntoFuture>::IntoFuture),
+    Seismic(SeismicCallFut<'req>),
+}
+
+impl<T, N> std::fmt::Debug for FutInner<'_, T, N>
+where
+    T: Transport + Clone + std::fmt::Debug,
+    N: Network,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eth(fut) => f.debug_tuple("Eth").field(fut).finish(),
+            Self::Seismic(_) => f.debug_tuple("Seismic").finish(),
+        }
     }
 }
 
@@ -128,7 +221,7 @@ where
     N: Network,
     D: CallDecoder,
 {
-    inner: <alloy_provider::EthCall<'req, T, N, Bytes> as IntoFuture>::IntoFuture,
+    inner: FutInner<'req, T, N>,
     decoder: &'coder D,
 }
 
@@ -145,8 +238,11 @@ where
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
         let this = self.get_mut();
-        let pin = std::pin::pin!(&mut this.inner);
-        match pin.poll(cx) {
+        let poll = match &mut this.inner {
+            FutInner::Eth(fut) => std::pin::pin!(fut).poll(cx),
+            FutInner::Seismic(fut) => fut.as_mut().poll(cx),
+        };
+        match poll {
             std::task::Poll::Ready(Ok(data)) => {
                 std::task::Poll::Ready(this.decoder.abi_decode_output(data, true))
             }
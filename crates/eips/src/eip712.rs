@@ -13,18 +13,33 @@ pub struct TypedDataRequest {
     pub signature: PrimitiveSignature,
 }
 
-/// [EIP-712] decoding errors.
+/// [EIP-712] encoding and decoding errors.
 /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
 #[derive(Clone, Debug)]
 #[non_exhaustive] // NB: non-exhaustive allows us to add a Custom variant later
 pub enum Eip712Error {
     /// Error while decoding the typed data.
     DecodeError(String),
+    /// Error while encoding to typed data, or hashing it.
+    EncodeError(String),
     /// Got an unexpected type flag while decoding.
     InvalidType,
 }
 
-/// Result type for [EIP-712] decoding.
+impl core::fmt::Display for Eip712Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DecodeError(err) => write!(f, "EIP-712 decoding error: {err}"),
+            Self::EncodeError(err) => write!(f, "EIP-712 encoding error: {err}"),
+            Self::InvalidType => f.write_str("Unexpected EIP-712 type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip712Error {}
+
+/// Result type for [EIP-712] encoding and decoding.
 pub type Eip712Result<T, E = Eip712Error> = core::result::Result<T, E>;
 
 /// Decoding trait for [EIP-712] typed data.
@@ -34,3 +49,16 @@ pub trait Decodable712: Sized {
     /// Decode the typed data from the buffer.
     fn decode_712(buf: &TypedDataRequest) -> Eip712Result<Self>;
 }
+
+/// Encoding trait for [EIP-712] typed data.
+///
+/// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+pub trait Encodable712 {
+    /// Encode as typed data with signature, if this was signed over [EIP-712]
+    /// typed data rather than its RLP encoding.
+    ///
+    /// Returns `Ok(None)` for transactions signed over their RLP encoding.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    fn encode_712(&self) -> Eip712Result<Option<TypedDataRequest>>;
+}
@@ -11,9 +11,12 @@
 
 extern crate alloc;
 
-use alloc::{collections::BTreeMap, string::String};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+};
 use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
-use alloy_serde::{storage::deserialize_storage_map, ttd::deserialize_json_ttd_opt, OtherFields};
+use alloy_serde::{storage::from_bytes_to_b256, ttd::deserialize_json_ttd_opt, OtherFields};
 use alloy_trie::{TrieAccount, EMPTY_ROOT_HASH, KECCAK_EMPTY};
 use core::str::FromStr;
 use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
@@ -192,31 +195,25 @@ impl Genesis {
 }
 
 /// An account in the state of the genesis block.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(from = "GenesisAccountSerde")]
 pub struct GenesisAccount {
     /// The nonce of the account at genesis.
-    #[serde(skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt", default)]
     pub nonce: Option<u64>,
     /// The balance of the account at genesis.
     pub balance: U256,
     /// The account's bytecode at genesis.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub code: Option<Bytes>,
     /// The account's storage at genesis.
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "deserialize_storage_map"
-    )]
     pub storage: Option<BTreeMap<B256, B256>>,
+    /// The slots of [`storage`](Self::storage) holding shielded values, only readable by the
+    /// contract itself, see [`with_private_storage`](Self::with_private_storage) and
+    /// [`is_private_slot`](Self::is_private_slot).
+    ///
+    /// These are serialized as [`FlaggedStorage`] entries of the storage map. Slots without a
+    /// value in the storage are ignored.
+    private_storage_slots: BTreeSet<B256>,
     /// The account's private key. Should only be used for testing.
-    #[serde(
-        rename = "secretKey",
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "deserialize_private_key"
-    )]
     pub private_key: Option<B256>,
 }
 
@@ -239,18 +236,218 @@ impl GenesisAccount {
         self
     }
 
-    /// Set the storage.
+    /// Set the storage, all of it public.
     pub fn with_storage(mut self, storage: Option<BTreeMap<B256, B256>>) -> Self {
         self.storage = storage;
+        self.private_storage_slots.clear();
+        self
+    }
+
+    /// Set the storage along with the privacy flag of each slot.
+    pub fn with_flagged_storage(mut self, storage: BTreeMap<B256, FlaggedStorage>) -> Self {
+        self.private_storage_slots =
+            storage.iter().filter(|(_, value)| value.is_private).map(|(slot, _)| *slot).collect();
+        self.storage = Some(storage.into_iter().map(|(slot, value)| (slot, value.value)).collect());
         self
     }
 
+    /// Adds shielded slots to the storage, overwriting existing values.
+    pub fn with_private_storage(mut self, storage: BTreeMap<B256, B256>) -> Self {
+        self.private_storage_slots.extend(storage.keys());
+        self.storage.get_or_insert_with(Default::default).extend(storage);
+        self
+    }
+
+    /// Returns whether the given storage slot holds a shielded value.
+    pub fn is_private_slot(&self, slot: &B256) -> bool {
+        self.private_storage_slots.contains(slot)
+    }
+
+    /// Returns the storage along with the privacy flag of each slot.
+    pub fn flagged_storage(&self) -> Option<BTreeMap<B256, FlaggedStorage>> {
+        self.storage.as_ref().map(|storage| {
+            storage
+                .iter()
+                .map(|(slot, value)| {
+                    (
+                        *slot,
+                        FlaggedStorage { value: *value, is_private: self.is_private_slot(slot) },
+                    )
+                })
+                .collect()
+        })
+    }
+
     /// Convert the genesis account into the [`TrieAccount`] format.
     pub fn into_trie_account(self) -> TrieAccount {
         self.into()
     }
 }
 
+/// The value of a storage slot along with its privacy flag, as used by the shielded storage of
+/// seismic (`suint`, `saddress`, ...).
+///
+/// In genesis files, shielded slots are written as `{"value": "0x..", "is_private": true}`, and
+/// other slots as their plain value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FlaggedStorage {
+    /// The value of the slot.
+    #[serde(deserialize_with = "deserialize_storage_value")]
+    pub value: B256,
+    /// Whether the value is shielded.
+    pub is_private: bool,
+}
+
+impl FlaggedStorage {
+    /// Creates a public storage value.
+    pub const fn public(value: B256) -> Self {
+        Self { value, is_private: false }
+    }
+
+    /// Creates a shielded storage value.
+    pub const fn private(value: B256) -> Self {
+        Self { value, is_private: true }
+    }
+}
+
+/// A storage value of a genesis file, either plain or flagged.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum GenesisStorageValue {
+    Plain(#[serde(deserialize_with = "deserialize_storage_value")] B256),
+    Flagged(FlaggedStorage),
+}
+
+impl From<GenesisStorageValue> for FlaggedStorage {
+    fn from(value: GenesisStorageValue) -> Self {
+        match value {
+            GenesisStorageValue::Plain(value) => Self::public(value),
+            GenesisStorageValue::Flagged(value) => value,
+        }
+    }
+}
+
+/// The serialized form of a [`GenesisAccount`], whose storage carries the privacy flags.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenesisAccountSerde {
+    #[serde(with = "alloy_serde::quantity::opt", default)]
+    nonce: Option<u64>,
+    balance: U256,
+    #[serde(default)]
+    code: Option<Bytes>,
+    #[serde(default, deserialize_with = "deserialize_flagged_storage_map")]
+    storage: Option<BTreeMap<B256, GenesisStorageValue>>,
+    #[serde(rename = "secretKey", default, deserialize_with = "deserialize_private_key")]
+    private_key: Option<B256>,
+}
+
+/// The serialized form of a borrowed [`GenesisAccount`].
+#[derive(Serialize)]
+struct GenesisAccountRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    nonce: Option<u64>,
+    balance: &'a U256,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<&'a Bytes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage: Option<FlaggedStorageRef<'a>>,
+    #[serde(rename = "secretKey", skip_serializing_if = "Option::is_none")]
+    private_key: Option<&'a B256>,
+}
+
+/// Serializes a storage map, flagging the values of its private slots.
+struct FlaggedStorageRef<'a> {
+    storage: &'a BTreeMap<B256, B256>,
+    private_storage_slots: &'a BTreeSet<B256>,
+}
+
+impl Serialize for FlaggedStorageRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.storage.iter().map(|(slot, value)| {
+            let value = if self.private_storage_slots.contains(slot) {
+                GenesisStorageValue::Flagged(FlaggedStorage::private(*value))
+            } else {
+                GenesisStorageValue::Plain(*value)
+            };
+            (slot, value)
+        }))
+    }
+}
+
+impl From<GenesisAccountSerde> for GenesisAccount {
+    fn from(account: GenesisAccountSerde) -> Self {
+        let mut private_storage_slots = BTreeSet::new();
+        let storage = account.storage.map(|storage| {
+            storage
+                .into_iter()
+                .map(|(slot, value)| {
+                    let value = FlaggedStorage::from(value);
+                    if value.is_private {
+                        private_storage_slots.insert(slot);
+                    }
+                    (slot, value.value)
+                })
+                .collect()
+        });
+        Self {
+            nonce: account.nonce,
+            balance: account.balance,
+            code: account.code,
+            storage,
+            private_storage_slots,
+            private_key: account.private_key,
+        }
+    }
+}
+
+impl Serialize for GenesisAccount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        GenesisAccountRef {
+            nonce: self.nonce,
+            balance: &self.balance,
+            code: self.code.as_ref(),
+            storage: self.storage.as_ref().map(|storage| FlaggedStorageRef {
+                storage,
+                private_storage_slots: &self.private_storage_slots,
+            }),
+            private_key: self.private_key.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a storage map whose values may be flagged, accepting keys shorter than 32 bytes
+/// like [`deserialize_storage_map`](alloy_serde::storage::deserialize_storage_map).
+fn deserialize_flagged_storage_map<'de, D>(
+    deserializer: D,
+) -> Result<Option<BTreeMap<B256, GenesisStorageValue>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<BTreeMap<Bytes, GenesisStorageValue>>::deserialize(deserializer)?
+        .map(|map| {
+            map.into_iter()
+                .map(|(slot, value)| Ok((from_bytes_to_b256::<D>(slot)?, value)))
+                .collect()
+        })
+        .transpose()
+}
+
+/// Deserializes a storage value, accepting values shorter than 32 bytes.
+fn deserialize_storage_value<'de, D>(deserializer: D) -> Result<B256, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    from_bytes_to_b256::<D>(Bytes::deserialize(deserializer)?)
+}
+
 impl From<GenesisAccount> for TrieAccount {
     fn from(account: GenesisAccount) -> Self {
         let storage_root = account
@@ -703,6 +900,7 @@ mod tests {
             balance: U256::from(1),
             code: Some(b"code".into()),
             storage: Some(BTreeMap::default()),
+            private_storage_slots: Default::default(),
             private_key: None,
         };
         let mut updated_account = BTreeMap::default();
@@ -1434,6 +1632,7 @@ mod tests {
     unwrap(),                     nonce: None,
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1444,6 +1643,7 @@ mod tests {
                         nonce: None,
                         code: Some(Bytes::from_str("0x12").unwrap()),
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1461,6 +1661,7 @@ mod tests {
     B256::from_str("0x0000000000000000000000000000000000000000000000000000000000000022").
     unwrap(),                         ),
                         ])),
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1471,6 +1672,7 @@ mod tests {
                         nonce: Some(0x32u64),
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1481,6 +1683,7 @@ mod tests {
                         nonce: None,
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1491,6 +1694,7 @@ mod tests {
                         nonce: None,
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1501,6 +1705,7 @@ mod tests {
                         nonce: None,
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1511,6 +1716,7 @@ mod tests {
                         nonce: None,
                         code: None,
                         storage: None,
+                        private_storage_slots: Default::default(),
                         private_key: None,
                     },
                 ),
@@ -1683,6 +1889,7 @@ mod tests {
             balance: U256::from(1000),
             code: Some(Bytes::from(vec![0x60, 0x61])),
             storage: Some(storage),
+            private_storage_slots: Default::default(),
             private_key: None,
         };
 
@@ -1711,6 +1918,7 @@ mod tests {
             balance: U256::from(300),
             code: None,
             storage: Some(storage),
+            private_storage_slots: Default::default(),
             private_key: None,
         };
 
@@ -1725,4 +1933,90 @@ mod tests {
         // No code provided, so code hash should be KECCAK_EMPTY
         assert_eq!(trie_account.code_hash, KECCAK_EMPTY);
     }
+
+    #[test]
+    fn parse_genesis_flagged_storage() {
+        let genesis = r#"{
+            "alloc": {
+                "0x0000000000000000000000000000000000000001": {
+                    "balance": "0x0",
+                    "code": "0x6001",
+                    "storage": {
+                        "0x00": "0x22",
+                        "0x01": { "value": "0x33", "is_private": true },
+                        "0x02": { "value": "0x44", "is_private": false }
+                    }
+                }
+            }
+        }"#;
+        let genesis: Genesis = serde_json::from_str(genesis).unwrap();
+        let account = &genesis.alloc[&Address::with_last_byte(1)];
+
+        let storage = account.storage.as_ref().unwrap();
+        assert_eq!(storage[&B256::with_last_byte(1)], B256::with_last_byte(0x33));
+        assert!(!account.is_private_slot(&B256::ZERO));
+        assert!(account.is_private_slot(&B256::with_last_byte(1)));
+        assert!(!account.is_private_slot(&B256::with_last_byte(2)));
+
+        // public slots are written as plain values
+        let serialized = serde_json::to_value(account).unwrap();
+        assert_eq!(
+            serialized["storage"][B256::ZERO.to_string()],
+            serde_json::json!(B256::with_last_byte(0x22))
+        );
+        assert_eq!(
+            serialized["storage"][B256::with_last_byte(1).to_string()],
+            serde_json::json!({ "value": B256::with_last_byte(0x33), "is_private": true })
+        );
+
+        let roundtrip: Genesis =
+            serde_json::from_str(&serde_json::to_string(&genesis).unwrap()).unwrap();
+        assert_eq!(roundtrip, genesis);
+    }
+
+    #[test]
+    fn genesis_account_private_storage_builders() {
+        let public = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let private = (B256::with_last_byte(3), B256::with_last_byte(4));
+
+        let account = GenesisAccount::default()
+            .with_storage(Some(BTreeMap::from([public])))
+            .with_private_storage(BTreeMap::from([private]));
+        assert_eq!(
+            account.flagged_storage().unwrap(),
+            BTreeMap::from([
+                (public.0, FlaggedStorage::public(public.1)),
+                (private.0, FlaggedStorage::private(private.1)),
+            ])
+        );
+
+        let flagged =
+            GenesisAccount::default().with_flagged_storage(account.flagged_storage().unwrap());
+        assert_eq!(flagged, account);
+
+        // an account without storage keeps serializing without it
+        let serialized = serde_json::to_value(GenesisAccount::default()).unwrap();
+        assert!(serialized.get("storage").is_none());
+
+        // replacing the storage drops the flags of the previous one
+        let replaced = account.with_storage(Some(BTreeMap::from([private])));
+        assert!(replaced.private_storage_slots.is_empty());
+        assert!(!replaced.is_private_slot(&private.0));
+    }
+
+    #[test]
+    fn genesis_account_skips_orphan_private_slots() {
+        let mut account = GenesisAccount::default()
+            .with_private_storage(BTreeMap::from([(B256::with_last_byte(1), B256::ZERO)]));
+
+        account.storage = None;
+        assert!(serde_json::to_value(&account).unwrap().get("storage").is_none());
+
+        let public = (B256::with_last_byte(2), B256::ZERO);
+        account.storage = Some(BTreeMap::from([public]));
+        assert_eq!(
+            serde_json::to_value(&account).unwrap()["storage"],
+            serde_json::json!({ public.0.to_string(): public.1 })
+        );
+    }
 }
@@ -121,6 +121,10 @@ impl TransactionBuilder<AnyNetwork> for WithOtherFields<TransactionRequest> {
         self.deref_mut().set_message_version(message_version);
     }
 
+    fn set_transaction_type(&mut self, transaction_type: u8) {
+        self.deref_mut().set_transaction_type(transaction_type);
+    }
+
     fn complete_type(&self, ty: <AnyNetwork as Network>::TxType) -> Result<(), Vec<&'static str>> {
         self.deref().complete_type(ty.try_into().map_err(|_| vec!["supported tx type"])?)
     }
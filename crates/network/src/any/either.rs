@@ -1,7 +1,11 @@
 use crate::{UnknownTxEnvelope, UnknownTypedTransaction};
-use alloy_consensus::{Transaction as TransactionTrait, TxEnvelope, Typed2718, TypedTransaction};
+use alloy_consensus::{
+    transaction::EncryptionPublicKey, Transaction as TransactionTrait, TxEnvelope, Typed2718,
+    TypedTransaction,
+};
 use alloy_eips::{
     eip2718::{Decodable2718, Encodable2718},
+    eip712::{Eip712Result, Encodable712, TypedDataRequest},
     eip7702::SignedAuthorization,
 };
 use alloy_primitives::{Bytes, ChainId, B256, U256};
@@ -189,6 +193,22 @@ impl TransactionTrait for AnyTypedTransaction {
             Self::Unknown(inner) => inner.authorization_list(),
         }
     }
+
+    #[inline]
+    fn encryption_pubkey(&self) -> Option<&EncryptionPublicKey> {
+        match self {
+            Self::Ethereum(inner) => inner.encryption_pubkey(),
+            Self::Unknown(inner) => inner.encryption_pubkey(),
+        }
+    }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        match self {
+            Self::Ethereum(inner) => inner.message_version(),
+            Self::Unknown(inner) => inner.message_version(),
+        }
+    }
 }
 
 impl Typed2718 for AnyTypedTransaction {
@@ -266,6 +286,15 @@ impl Decodable2718 for AnyTxEnvelope {
     }
 }
 
+impl Encodable712 for AnyTxEnvelope {
+    fn encode_712(&self) -> Eip712Result<Option<TypedDataRequest>> {
+        match self {
+            Self::Ethereum(tx) => tx.encode_712(),
+            Self::Unknown(_) => Ok(None),
+        }
+    }
+}
+
 impl TransactionTrait for AnyTxEnvelope {
     #[inline]
     fn chain_id(&self) -> Option<ChainId> {
@@ -397,4 +426,20 @@ impl TransactionTrait for AnyTxEnvelope {
             Self::Unknown(inner) => inner.authorization_list(),
         }
     }
+
+    #[inline]
+    fn encryption_pubkey(&self) -> Option<&EncryptionPublicKey> {
+        match self {
+            Self::Ethereum(inner) => inner.encryption_pubkey(),
+            Self::Unknown(inner) => inner.encryption_pubkey(),
+        }
+    }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        match self {
+            Self::Ethereum(inner) => inner.message_version(),
+            Self::Unknown(inner) => inner.message_version(),
+        }
+    }
 }
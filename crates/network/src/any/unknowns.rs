@@ -1,7 +1,7 @@
 use core::fmt;
 use std::sync::OnceLock;
 
-use alloy_consensus::{TxType, Typed2718};
+use alloy_consensus::{transaction::EncryptionPublicKey, TxType, Typed2718};
 use alloy_eips::{eip2718::Eip2718Error, eip7702::SignedAuthorization};
 use alloy_primitives::{Address, Bytes, ChainId, TxKind, B256, U128, U256, U64, U8};
 use alloy_rpc_types_eth::AccessList;
@@ -84,6 +84,7 @@ pub struct DeserMemo {
     pub access_list: OnceLock<AccessList>,
     pub blob_versioned_hashes: OnceLock<Vec<B256>>,
     pub authorization_list: OnceLock<Vec<SignedAuthorization>>,
+    pub encryption_pubkey: OnceLock<EncryptionPublicKey>,
 }
 
 /// A typed transaction of an unknown Network
@@ -253,6 +254,25 @@ impl alloy_consensus::Transaction for UnknownTypedTransaction {
             None
         }
     }
+
+    #[inline]
+    fn encryption_pubkey(&self) -> Option<&EncryptionPublicKey> {
+        if self.fields.contains_key("encryptionPubkey") {
+            Some(self.memo.encryption_pubkey.get_or_init(|| {
+                self.fields
+                    .get_deserialized("encryptionPubkey")
+                    .and_then(Result::ok)
+                    .unwrap_or_default()
+            }))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        self.fields.get_deserialized::<U8>("messageVersion").and_then(Result::ok).map(|v| v.to())
+    }
 }
 
 impl Typed2718 for UnknownTxEnvelope {
@@ -369,6 +389,16 @@ impl alloy_consensus::Transaction for UnknownTxEnvelope {
     fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
         self.inner.authorization_list()
     }
+
+    #[inline]
+    fn encryption_pubkey(&self) -> Option<&EncryptionPublicKey> {
+        self.inner.encryption_pubkey()
+    }
+
+    #[inline]
+    fn message_version(&self) -> Option<u8> {
+        self.inner.message_version()
+    }
 }
 
 #[cfg(test)]
@@ -427,4 +457,53 @@ mod tests {
 
         assert_eq!(tx, roundrip_tx);
     }
+
+    #[test]
+    fn test_serde_seismic() {
+        use alloy_consensus::{SignableTransaction, TxEnvelope, TxSeismic};
+        use alloy_primitives::{FixedBytes, PrimitiveSignature};
+        use alloy_rpc_types_eth::TransactionRequest;
+        use alloy_serde::WithOtherFields;
+
+        let encryption_pubkey = FixedBytes::<33>::repeat_byte(2);
+        let tx = TxSeismic {
+            chain_id: 5124,
+            nonce: 3,
+            gas_price: 1_000_000_000,
+            gas_limit: 100_000,
+            to: Address::with_last_byte(1).into(),
+            encryption_pubkey,
+            message_version: 2,
+            input: Bytes::from_static(&[1, 2, 3]),
+            ..Default::default()
+        };
+        let envelope: TxEnvelope = tx.into_signed(PrimitiveSignature::test_signature()).into();
+        let rpc_tx = alloy_rpc_types_eth::Transaction {
+            inner: envelope.clone(),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            from: Address::with_last_byte(2),
+        };
+        let mut json = serde_json::to_value(&rpc_tx).unwrap();
+        assert_eq!(json["type"], "0x4a");
+
+        let tx: AnyRpcTransaction = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(tx.inner.inner, AnyTxEnvelope::Ethereum(envelope));
+        assert_eq!(tx.encryption_pubkey(), Some(&encryption_pubkey));
+        assert_eq!(tx.message_version(), Some(2));
+
+        // the seismic fields survive the conversion to a request
+        let request = WithOtherFields::<TransactionRequest>::from(tx.inner.inner);
+        assert_eq!(request.encryption_pubkey, Some(encryption_pubkey));
+        assert_eq!(request.message_version, Some(2));
+
+        // and are read from the fields of unknown transaction types
+        json["type"] = "0x7f".into();
+        let tx: AnyRpcTransaction = serde_json::from_value(json).unwrap();
+        assert!(matches!(tx.inner.inner, AnyTxEnvelope::Unknown(_)));
+        assert_eq!(tx.encryption_pubkey(), Some(&encryption_pubkey));
+        assert_eq!(tx.message_version(), Some(2));
+    }
 }
@@ -118,10 +118,15 @@ impl TransactionBuilder<Ethereum> for TransactionRequest {
         self.message_version = Some(message_version);
     }
 
+    fn set_transaction_type(&mut self, transaction_type: u8) {
+        self.transaction_type = Some(transaction_type);
+    }
+
     fn complete_type(&self, ty: TxType) -> Result<(), Vec<&'static str>> {
         match ty {
             TxType::Legacy => self.complete_legacy(),
             TxType::Seismic => self.complete_seismic(),
+            TxType::SeismicEip1559 => self.complete_seismic_eip1559(),
             TxType::Eip2930 => self.complete_2930(),
             TxType::Eip1559 => self.complete_1559(),
             TxType::Eip4844 => self.complete_4844(),
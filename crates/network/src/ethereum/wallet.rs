@@ -1,6 +1,10 @@
 use crate::{AnyNetwork, AnyTxEnvelope, AnyTypedTransaction, Network, NetworkWallet, TxSigner};
 use alloy_consensus::{SignableTransaction, TxEnvelope, TypedTransaction};
+#[cfg(feature = "eip712")]
+use alloy_dyn_abi::TypedData;
 use alloy_primitives::{map::AddressHashMap, Address, PrimitiveSignature as Signature};
+#[cfg(feature = "eip712")]
+use alloy_signer::UnsupportedSignerOperation;
 use std::sync::Arc;
 
 use super::Ethereum;
@@ -80,18 +84,43 @@ impl EthereumWallet {
         self.signers.get(&address).cloned()
     }
 
+    /// Get the signer for the given address, or an error if there is none.
+    fn signer_for(
+        &self,
+        sender: Address,
+    ) -> alloy_signer::Result<Arc<dyn TxSigner<Signature> + Send + Sync + 'static>> {
+        self.signer_by_address(sender).ok_or_else(|| {
+            alloy_signer::Error::other(format!("Missing signing credential for {}", sender))
+        })
+    }
+
     #[doc(alias = "sign_tx_inner")]
     async fn sign_transaction_inner(
         &self,
         sender: Address,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> alloy_signer::Result<Signature> {
-        self.signer_by_address(sender)
-            .ok_or_else(|| {
-                alloy_signer::Error::other(format!("Missing signing credential for {}", sender))
-            })?
-            .sign_transaction(tx)
-            .await
+        self.signer_for(sender)?.sign_transaction(tx).await
+    }
+
+    /// Sign a transaction over its EIP-712 typed data.
+    ///
+    /// Signers that can't sign typed data sign the signature hash of the
+    /// transaction instead, which is the EIP-712 signing hash of `typed_data`.
+    #[cfg(feature = "eip712")]
+    async fn sign_typed_transaction_inner(
+        &self,
+        sender: Address,
+        tx: &mut dyn SignableTransaction<Signature>,
+        typed_data: &TypedData,
+    ) -> alloy_signer::Result<Signature> {
+        let signer = self.signer_for(sender)?;
+        match signer.sign_dynamic_typed_data(typed_data).await {
+            Err(err) if err.unsupported() == Some(UnsupportedSignerOperation::SignTypedData) => {
+                signer.sign_transaction(tx).await
+            }
+            res => res,
+        }
     }
 }
 
@@ -122,10 +151,26 @@ where
                 let sig = self.sign_transaction_inner(sender, &mut t).await?;
                 Ok(t.into_signed(sig).into())
             }
+            #[cfg(feature = "eip712")]
+            TypedTransaction::Seismic(mut t) if t.is_eip712() => {
+                let typed_data = t.eip712_to_type_data().map_err(alloy_signer::Error::other)?;
+                let sig = self.sign_typed_transaction_inner(sender, &mut t, &typed_data).await?;
+                Ok(t.try_into_signed(sig).map_err(alloy_signer::Error::other)?.into())
+            }
             TypedTransaction::Seismic(mut t) => {
                 let sig = self.sign_transaction_inner(sender, &mut t).await?;
                 Ok(t.into_signed(sig).into())
             }
+            #[cfg(feature = "eip712")]
+            TypedTransaction::SeismicEip1559(mut t) if t.is_eip712() => {
+                let typed_data = t.eip712_to_type_data().map_err(alloy_signer::Error::other)?;
+                let sig = self.sign_typed_transaction_inner(sender, &mut t, &typed_data).await?;
+                Ok(t.try_into_signed(sig).map_err(alloy_signer::Error::other)?.into())
+            }
+            TypedTransaction::SeismicEip1559(mut t) => {
+                let sig = self.sign_transaction_inner(sender, &mut t).await?;
+                Ok(t.into_signed(sig).into())
+            }
             TypedTransaction::Eip2930(mut t) => {
                 let sig = self.sign_transaction_inner(sender, &mut t).await?;
                 Ok(t.into_signed(sig).into())
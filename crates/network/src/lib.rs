@@ -21,6 +21,9 @@ pub use transaction::{
 mod ethereum;
 pub use ethereum::{Ethereum, EthereumWallet};
 
+mod seismic;
+pub use seismic::SeismicNetwork;
+
 mod any;
 pub use any::{
     AnyHeader, AnyNetwork, AnyReceiptEnvelope, AnyRpcBlock, AnyRpcHeader, AnyRpcTransaction,
@@ -0,0 +1,73 @@
+use crate::Network;
+use alloy_eips::eip712::{Eip712Result, Encodable712, TypedDataRequest};
+
+/// A [`Network`] whose transactions may be seismic transactions signed over
+/// [EIP-712] typed data.
+///
+/// Such transactions must be submitted in their typed data form rather than
+/// their 2718 encoding, so only the seismic provider paths require it. It is
+/// implemented for every network whose envelope is [`Encodable712`].
+///
+/// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+pub trait SeismicNetwork: Network {
+    /// Returns the typed data and signature of `tx`, if it is a seismic
+    /// transaction signed over [EIP-712] typed data.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    fn encode_712(tx: &Self::TxEnvelope) -> Eip712Result<Option<TypedDataRequest>>;
+}
+
+impl<N> SeismicNetwork for N
+where
+    N: Network,
+    N::TxEnvelope: Encodable712,
+{
+    fn encode_712(tx: &Self::TxEnvelope) -> Eip712Result<Option<TypedDataRequest>> {
+        tx.encode_712()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnyNetwork, AnyTxEnvelope, Ethereum};
+    use alloy_consensus::{SignableTransaction, TxEnvelope, TxLegacy, TxSeismic};
+    use alloy_primitives::{FixedBytes, PrimitiveSignature as Signature, U256};
+
+    fn seismic_envelope(message_version: u8) -> TxEnvelope {
+        let tx = TxSeismic {
+            chain_id: 1,
+            nonce: 2,
+            gas_price: 1,
+            gas_limit: 21_000,
+            to: alloy_primitives::Address::repeat_byte(1).into(),
+            value: U256::ZERO,
+            encryption_pubkey: FixedBytes::repeat_byte(2),
+            message_version,
+            input: Default::default(),
+        };
+        tx.into_signed(Signature::test_signature()).into()
+    }
+
+    #[test]
+    fn typed_data_of_eip712_seismic_tx() {
+        let envelope = seismic_envelope(2);
+        let expected = envelope.encode_712().unwrap().unwrap();
+
+        let typed_data = Ethereum::encode_712(&envelope).unwrap().unwrap();
+        assert_eq!(typed_data.data, expected.data);
+        assert_eq!(typed_data.signature, expected.signature);
+
+        let any = AnyTxEnvelope::Ethereum(envelope);
+        assert_eq!(AnyNetwork::encode_712(&any).unwrap().unwrap().data, expected.data);
+    }
+
+    #[test]
+    fn no_typed_data_for_rlp_signed_txs() {
+        assert!(Ethereum::encode_712(&seismic_envelope(0)).unwrap().is_none());
+
+        let legacy: TxEnvelope =
+            TxLegacy::default().into_signed(Signature::test_signature()).into();
+        assert!(Ethereum::encode_712(&legacy).unwrap().is_none());
+    }
+}
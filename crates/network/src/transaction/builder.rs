@@ -284,6 +284,22 @@ pub trait TransactionBuilder<N: Network>: Default + Sized + Send + Sync + 'stati
         self
     }
 
+    /// Set the EIP-2718 type of the transaction, e.g. [`TxSeismic::TX_TYPE`].
+    ///
+    /// The default implementation does nothing, for requests without an
+    /// explicit type whose type is inferred from the fields that are set.
+    ///
+    /// [`TxSeismic::TX_TYPE`]: alloy_consensus::TxSeismic::TX_TYPE
+    fn set_transaction_type(&mut self, transaction_type: u8) {
+        let _ = transaction_type;
+    }
+
+    /// Builder pattern for setting the EIP-2718 type of the transaction.
+    fn with_transaction_type(mut self, transaction_type: u8) -> Self {
+        self.set_transaction_type(transaction_type);
+        self
+    }
+
     /// Get the gas limit for the transaction.
     fn gas_limit(&self) -> Option<u64>;
 
@@ -1,6 +1,10 @@
 use crate::{Network, TransactionBuilder};
 use alloy_consensus::SignableTransaction;
+#[cfg(feature = "eip712")]
+use alloy_dyn_abi::TypedData;
 use alloy_primitives::Address;
+#[cfg(feature = "eip712")]
+use alloy_signer::UnsupportedSignerOperation;
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 use futures_utils_wasm::impl_future;
@@ -88,6 +92,24 @@ pub trait TxSigner<Signature> {
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> alloy_signer::Result<Signature>;
+
+    /// Asynchronously sign dynamic [EIP-712] typed data.
+    ///
+    /// Used for transactions which are signed over their typed data rather
+    /// than their RLP encoding, e.g. seismic transactions with a
+    /// `message_version` of 2 or higher. Signers that don't implement it
+    /// return [`UnsupportedSignerOperation::SignTypedData`], in which case the
+    /// transaction is signed with [`sign_transaction`](Self::sign_transaction)
+    /// instead.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    #[cfg(feature = "eip712")]
+    async fn sign_dynamic_typed_data(
+        &self,
+        _payload: &TypedData,
+    ) -> alloy_signer::Result<Signature> {
+        Err(alloy_signer::Error::UnsupportedOperation(UnsupportedSignerOperation::SignTypedData))
+    }
 }
 
 /// Synchronous transaction signer,  capable of signing any [`SignableTransaction`] for the given
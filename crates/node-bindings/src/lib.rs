@@ -16,6 +16,7 @@ pub use nodes::{
     anvil::{self, Anvil, AnvilInstance},
     geth::{self, Geth, GethInstance},
     reth::{self, Reth, RethInstance},
+    seismic_reth::{self, SeismicReth, SeismicRethInstance},
 };
 
 mod node;
@@ -62,6 +62,9 @@ pub enum NodeError {
     /// An error occurred while parsing a hex string.
     #[error(transparent)]
     FromHexError(#[from] hex::FromHexError),
+    /// The TEE public key could not be fetched from a seismic node.
+    #[error("could not fetch the TEE public key: {0}")]
+    TeePubkeyError(String),
     /// No keys available this node instance.
     #[error("no keys available in this node instance")]
     NoKeysAvailable,
@@ -3,3 +3,4 @@
 pub mod anvil;
 pub mod geth;
 pub mod reth;
+pub mod seismic_reth;
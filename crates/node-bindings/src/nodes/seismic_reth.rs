@@ -0,0 +1,528 @@
+//! Utilities for launching a seismic-reth dev-mode instance.
+
+use crate::{utils::unused_port, NodeError, Reth, RethInstance};
+use alloy_genesis::Genesis;
+use alloy_primitives::{hex, Address, FixedBytes};
+use k256::{ecdsa::SigningKey, SecretKey as K256SecretKey};
+use std::{
+    ffi::OsString,
+    fs::File,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    process::ChildStdout,
+};
+use tempfile::TempDir;
+use url::Url;
+
+/// The seismic-reth command
+const SEISMIC_RETH: &str = "seismic-reth";
+
+/// The default address of the enclave server.
+const DEFAULT_ENCLAVE_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// The private keys of the first 10 accounts prefunded in dev mode, derived from the mnemonic
+/// "test test test test test test test test test test test junk".
+pub const DEV_PRIVATE_KEYS: [&str; 10] = [
+    "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+    "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+    "5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+    "7c852118294e51e653712a81e05800f419141751be58f605c371e15141b007a6",
+    "47e179ec197488593b187f80a00eb0da91f1b9d0b13f8733639f19c30a34926a",
+    "8b3a350cf5c34c9194ca85829a2df0ec3153be0318b5e2d3348e872092edffba",
+    "92db14e403b83dfe3df233f83dfa3a0d7096f21ca9b0d6d6b8d88b2b4ec1564e",
+    "4bbbf85ce3377467afe5d46f804f221813b2bb87f24d81f60f1fcdbf7cbf4356",
+    "dbda1821b80551c9d65939329250298aa3472ba22feea921c0cf5d620ea67b97",
+    "2a871d0798f97d79848a013d4936a73bf4cc922c825d33c1cf7073dff6d409c6",
+];
+
+/// A seismic-reth instance. Will close the instance when dropped.
+///
+/// Construct this using [`SeismicReth`].
+#[derive(Debug)]
+pub struct SeismicRethInstance {
+    reth: RethInstance,
+    enclave: SocketAddr,
+    tee_pubkey: FixedBytes<33>,
+    private_keys: Vec<K256SecretKey>,
+    addresses: Vec<Address>,
+    /// Holds the chain spec written from a custom genesis until the instance is dropped.
+    _genesis_dir: Option<TempDir>,
+}
+
+impl SeismicRethInstance {
+    /// Returns the underlying reth instance.
+    pub const fn reth(&self) -> &RethInstance {
+        &self.reth
+    }
+
+    /// Returns the instance number of this instance.
+    pub const fn instance(&self) -> u16 {
+        self.reth.instance()
+    }
+
+    /// Returns the HTTP port of this instance.
+    pub const fn http_port(&self) -> u16 {
+        self.reth.http_port()
+    }
+
+    /// Returns the WS port of this instance.
+    pub const fn ws_port(&self) -> u16 {
+        self.reth.ws_port()
+    }
+
+    /// Returns the auth port of this instance.
+    pub const fn auth_port(&self) -> Option<u16> {
+        self.reth.auth_port()
+    }
+
+    /// Returns the HTTP endpoint of this instance.
+    #[doc(alias = "http_endpoint")]
+    pub fn endpoint(&self) -> String {
+        self.reth.endpoint()
+    }
+
+    /// Returns the Websocket endpoint of this instance.
+    pub fn ws_endpoint(&self) -> String {
+        self.reth.ws_endpoint()
+    }
+
+    /// Returns the IPC endpoint of this instance.
+    pub fn ipc_endpoint(&self) -> String {
+        self.reth.ipc_endpoint()
+    }
+
+    /// Returns the HTTP endpoint url of this instance.
+    #[doc(alias = "http_endpoint_url")]
+    pub fn endpoint_url(&self) -> Url {
+        self.reth.endpoint_url()
+    }
+
+    /// Returns the Websocket endpoint url of this instance.
+    pub fn ws_endpoint_url(&self) -> Url {
+        self.reth.ws_endpoint_url()
+    }
+
+    /// Returns the port of the enclave server used by this instance.
+    pub const fn enclave_port(&self) -> u16 {
+        self.enclave.port()
+    }
+
+    /// Returns the endpoint of the enclave server used by this instance.
+    pub fn enclave_endpoint(&self) -> String {
+        format!("http://{}", self.enclave)
+    }
+
+    /// Returns the TEE public key of this instance, the key inputs of seismic transactions are
+    /// encrypted to.
+    pub const fn tee_pubkey(&self) -> FixedBytes<33> {
+        self.tee_pubkey
+    }
+
+    /// Returns the private keys of the accounts whose keys are known from the genesis in use.
+    ///
+    /// These are the accounts prefunded in dev mode on the dev chain, or the accounts of a
+    /// [custom genesis](SeismicReth::genesis) which have a private key. There are none on any
+    /// other chain.
+    pub fn keys(&self) -> &[K256SecretKey] {
+        &self.private_keys
+    }
+
+    /// Returns the addresses of the accounts of [`keys`](Self::keys).
+    pub fn addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+
+    /// Returns the path to this instances' data directory.
+    pub const fn data_dir(&self) -> Option<&PathBuf> {
+        self.reth.data_dir()
+    }
+
+    /// Returns the genesis configuration used to configure this instance, if it was set with
+    /// [`SeismicReth::genesis`].
+    pub const fn genesis(&self) -> Option<&Genesis> {
+        self.reth.genesis()
+    }
+
+    /// Takes the stdout contained in the child process.
+    ///
+    /// This leaves a `None` in its place, so calling methods that require a stdout to be present
+    /// will fail if called after this.
+    pub fn stdout(&mut self) -> Result<ChildStdout, NodeError> {
+        self.reth.stdout()
+    }
+}
+
+/// Builder for launching `seismic-reth` in dev mode.
+///
+/// The node runs with a mock enclave server by default, on a random port so that several
+/// instances can run side by side.
+///
+/// # Panics
+///
+/// If `spawn` is called without `seismic-reth` being available in the user's $PATH
+///
+/// # Example
+///
+/// ```no_run
+/// use alloy_node_bindings::SeismicReth;
+///
+/// let seismic_reth = SeismicReth::new().block_time("1sec").spawn();
+///
+/// println!("TEE public key: {}", seismic_reth.tee_pubkey());
+///
+/// drop(seismic_reth); // this will kill the instance
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "This Builder struct does nothing unless it is `spawn`ed"]
+pub struct SeismicReth {
+    reth: Reth,
+    enclave_addr: IpAddr,
+    enclave_port: Option<u16>,
+    mock_enclave: bool,
+    custom_chain: bool,
+    genesis: Option<Genesis>,
+}
+
+impl Default for SeismicReth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeismicReth {
+    /// Creates a seismic-reth builder running in dev mode, with discovery disabled and a mock
+    /// enclave server.
+    ///
+    /// As for [`Reth::new`], the instance number is set to a random number between 1 and 200.
+    pub fn new() -> Self {
+        Self {
+            reth: Reth::new().path(SEISMIC_RETH).dev().disable_discovery(),
+            enclave_addr: DEFAULT_ENCLAVE_ADDR,
+            enclave_port: None,
+            mock_enclave: true,
+            custom_chain: false,
+            genesis: None,
+        }
+    }
+
+    /// Creates a seismic-reth builder which will execute `seismic-reth` at the given path.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self::new().path(path)
+    }
+
+    /// Sets the `path` to the `seismic-reth` executable
+    ///
+    /// By default, it's expected that `seismic-reth` is in `$PATH`, see also
+    /// [`std::process::Command::new()`]
+    pub fn path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.reth = self.reth.path(path);
+        self
+    }
+
+    /// Sets the HTTP port for the instance.
+    /// Note: this resets the instance number to 0 to allow for custom ports.
+    pub fn http_port(mut self, http_port: u16) -> Self {
+        self.reth = self.reth.http_port(http_port);
+        self
+    }
+
+    /// Sets the WS port for the instance.
+    /// Note: this resets the instance number to 0 to allow for custom ports.
+    pub fn ws_port(mut self, ws_port: u16) -> Self {
+        self.reth = self.reth.ws_port(ws_port);
+        self
+    }
+
+    /// Sets the auth port for the instance.
+    /// Note: this resets the instance number to 0 to allow for custom ports.
+    pub fn auth_port(mut self, auth_port: u16) -> Self {
+        self.reth = self.reth.auth_port(auth_port);
+        self
+    }
+
+    /// Sets the block time of the dev chain, see [`Reth::block_time`]. By default, a block is
+    /// mined for every transaction.
+    pub fn block_time(mut self, block_time: &str) -> Self {
+        self.reth = self.reth.block_time(block_time);
+        self
+    }
+
+    /// Sets the chain or the path to the chain spec of the instance.
+    ///
+    /// The keys of the accounts of such a chain are unknown, so the instance has no
+    /// [`keys`](SeismicRethInstance::keys). Use [`genesis`](Self::genesis) instead to run a
+    /// custom chain with known keys.
+    pub fn chain_or_path(mut self, chain_or_path: &str) -> Self {
+        self.reth = self.reth.chain_or_path(chain_or_path);
+        self.custom_chain = true;
+        self
+    }
+
+    /// Sets the genesis of the instance, overriding any [`chain_or_path`](Self::chain_or_path).
+    ///
+    /// The accounts of the genesis which have a private key are the
+    /// [`keys`](SeismicRethInstance::keys) of the instance.
+    pub fn genesis(mut self, genesis: Genesis) -> Self {
+        self.genesis = Some(genesis);
+        self
+    }
+
+    /// Enable IPC for the instance.
+    pub fn enable_ipc(mut self) -> Self {
+        self.reth = self.reth.enable_ipc();
+        self
+    }
+
+    /// Sets the IPC path for the socket.
+    pub fn ipc_path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.reth = self.reth.ipc_path(path);
+        self
+    }
+
+    /// Sets the instance number. Set to 0 to use the default ports.
+    pub fn instance(mut self, instance: u16) -> Self {
+        self.reth = self.reth.instance(instance);
+        self
+    }
+
+    /// Sets the data directory for seismic-reth.
+    pub fn data_dir<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.reth = self.reth.data_dir(path);
+        self
+    }
+
+    /// Sets the address of the enclave server. Defaults to localhost.
+    pub const fn enclave_addr(mut self, addr: IpAddr) -> Self {
+        self.enclave_addr = addr;
+        self
+    }
+
+    /// Sets the port of the enclave server. Defaults to an unused port.
+    pub const fn enclave_port(mut self, port: u16) -> Self {
+        self.enclave_port = Some(port);
+        self
+    }
+
+    /// Connects to an enclave server running at the configured address instead of starting a
+    /// mock one with the node.
+    pub const fn external_enclave(mut self) -> Self {
+        self.mock_enclave = false;
+        self
+    }
+
+    /// Adds an argument to pass to `seismic-reth`.
+    ///
+    /// Pass any arg that is not supported by the builder.
+    pub fn arg<T: Into<OsString>>(mut self, arg: T) -> Self {
+        self.reth = self.reth.arg(arg);
+        self
+    }
+
+    /// Adds multiple arguments to pass to `seismic-reth`.
+    ///
+    /// Pass any args that is not supported by the builder.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        self.reth = self.reth.args(args);
+        self
+    }
+
+    /// Consumes the builder and spawns `seismic-reth`.
+    ///
+    /// # Panics
+    ///
+    /// If spawning the instance fails at any point.
+    #[track_caller]
+    pub fn spawn(self) -> SeismicRethInstance {
+        self.try_spawn().unwrap()
+    }
+
+    /// Consumes the builder and spawns `seismic-reth`. If spawning fails, returns an error.
+    pub fn try_spawn(self) -> Result<SeismicRethInstance, NodeError> {
+        let enclave =
+            SocketAddr::new(self.enclave_addr, self.enclave_port.unwrap_or_else(unused_port));
+
+        let mut reth = self
+            .reth
+            .arg("--enclave.endpoint-addr")
+            .arg(enclave.ip().to_string())
+            .arg("--enclave.endpoint-port")
+            .arg(enclave.port().to_string());
+        if self.mock_enclave {
+            reth = reth.arg("--enclave.mock-server");
+        }
+
+        let (private_keys, addresses) = match &self.genesis {
+            Some(genesis) => genesis_accounts(genesis)?,
+            None if self.custom_chain => Default::default(),
+            None => dev_accounts()?,
+        };
+        let genesis_dir = match self.genesis {
+            Some(genesis) => {
+                let dir = write_genesis(&genesis)?;
+                reth = reth.chain_or_path(&dir.path().join("genesis.json").to_string_lossy());
+                reth = reth.genesis(genesis);
+                Some(dir)
+            }
+            None => None,
+        };
+        let reth = reth.try_spawn()?;
+
+        let tee_pubkey = fetch_tee_pubkey(reth.endpoint_url())?;
+
+        Ok(SeismicRethInstance {
+            reth,
+            enclave,
+            tee_pubkey,
+            private_keys,
+            addresses,
+            _genesis_dir: genesis_dir,
+        })
+    }
+}
+
+/// Returns the keys and addresses of the accounts prefunded in dev mode.
+fn dev_accounts() -> Result<(Vec<K256SecretKey>, Vec<Address>), NodeError> {
+    DEV_PRIVATE_KEYS
+        .iter()
+        .map(|key| {
+            let key = hex::decode(key)?;
+            let key = K256SecretKey::from_bytes((&key[..]).into())
+                .map_err(|_| NodeError::DeserializePrivateKeyError)?;
+            let address = Address::from_public_key(SigningKey::from(&key).verifying_key());
+            Ok((key, address))
+        })
+        .collect()
+}
+
+/// Returns the keys and addresses of the accounts of `genesis` which have a private key.
+fn genesis_accounts(genesis: &Genesis) -> Result<(Vec<K256SecretKey>, Vec<Address>), NodeError> {
+    genesis
+        .alloc
+        .iter()
+        .filter_map(|(address, account)| Some((*address, account.private_key?)))
+        .map(|(address, key)| {
+            let key = K256SecretKey::from_bytes(key.as_slice().into())
+                .map_err(|_| NodeError::DeserializePrivateKeyError)?;
+            Ok((key, address))
+        })
+        .collect()
+}
+
+/// Writes `genesis` to a `genesis.json` file in a new temporary directory.
+fn write_genesis(genesis: &Genesis) -> Result<TempDir, NodeError> {
+    let dir = tempfile::tempdir().map_err(NodeError::CreateDirError)?;
+    let file = File::create(dir.path().join("genesis.json"))
+        .map_err(|_| NodeError::GenesisError("could not create genesis file".to_string()))?;
+    serde_json::to_writer_pretty(file, genesis)
+        .map_err(|_| NodeError::GenesisError("could not write genesis to file".to_string()))?;
+    Ok(dir)
+}
+
+/// Requests the TEE public key of the node at `endpoint` with `seismic_getTeePublicKey`.
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_tee_pubkey(endpoint: Url) -> Result<FixedBytes<33>, NodeError> {
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "seismic_getTeePublicKey",
+        "params": [],
+    });
+    // the blocking client panics when used on an async runtime, which callers are often in
+    let response = std::thread::spawn(move || -> reqwest::Result<serde_json::Value> {
+        reqwest::blocking::Client::builder()
+            .timeout(crate::NODE_STARTUP_TIMEOUT)
+            .build()?
+            .post(endpoint)
+            .json(&request)
+            .send()?
+            .error_for_status()?
+            .json()
+    })
+    .join()
+    .map_err(|_| tee_pubkey_error("request thread panicked"))?;
+
+    let mut response = response.map_err(tee_pubkey_error)?;
+    if let Some(error) = response.get("error") {
+        return Err(tee_pubkey_error(error));
+    }
+    serde_json::from_value(response["result"].take()).map_err(tee_pubkey_error)
+}
+
+/// Fails, as there is no blocking HTTP client to request the TEE public key with on WASM.
+#[cfg(target_arch = "wasm32")]
+fn fetch_tee_pubkey(_endpoint: Url) -> Result<FixedBytes<33>, NodeError> {
+    Err(tee_pubkey_error("fetching the TEE public key is not supported on wasm32"))
+}
+
+fn tee_pubkey_error(e: impl std::fmt::Display) -> NodeError {
+    NodeError::TeePubkeyError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_genesis::GenesisAccount;
+    use alloy_primitives::{address, B256, U256};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    #[test]
+    fn dev_accounts_are_prefunded_accounts() {
+        let (keys, addresses) = dev_accounts().unwrap();
+        assert_eq!(keys.len(), DEV_PRIVATE_KEYS.len());
+        assert_eq!(addresses[0], address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"));
+        assert_eq!(addresses[9], address!("a0Ee7A142d267C1f36714E4a8F75612F20a79720"));
+    }
+
+    #[test]
+    fn genesis_accounts_have_private_keys() {
+        let key = B256::repeat_byte(1);
+        let funded = GenesisAccount::default().with_balance(U256::from(1));
+        let mut with_key = funded.clone();
+        with_key.private_key = Some(key);
+        let genesis = Genesis::default().extend_accounts([
+            (Address::repeat_byte(1), with_key),
+            (Address::repeat_byte(2), funded),
+        ]);
+
+        let (keys, addresses) = genesis_accounts(&genesis).unwrap();
+        assert_eq!(addresses, [Address::repeat_byte(1)]);
+        assert_eq!(&keys[0].to_bytes()[..], key.as_slice());
+        assert!(genesis_accounts(&Genesis::default()).unwrap().0.is_empty());
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fetches_tee_pubkey() {
+        let tee_pubkey = FixedBytes::<33>::repeat_byte(2);
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            assert!(line.starts_with("POST / HTTP/1.1"));
+
+            let body = format!(r#"{{"jsonrpc":"2.0","id":1,"result":"{tee_pubkey}"}}"#);
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+
+        let endpoint = format!("http://localhost:{port}").parse().unwrap();
+        assert_eq!(fetch_tee_pubkey(endpoint).unwrap(), tee_pubkey);
+        server.join().unwrap();
+    }
+}
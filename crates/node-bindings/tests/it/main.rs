@@ -3,3 +3,4 @@
 mod anvil;
 mod geth;
 mod reth;
+mod seismic_reth;
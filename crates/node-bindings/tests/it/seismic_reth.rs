@@ -0,0 +1,41 @@
+// These tests should use a different datadir for each `seismic-reth` instance spawned.
+
+use alloy_node_bindings::{utils::run_with_tempdir_sync, SeismicReth};
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn can_launch_seismic_reth() {
+    if !ci_info::is_ci() {
+        return;
+    }
+
+    run_with_tempdir_sync("seismic-reth-test-", |temp_dir_path| {
+        let seismic_reth = SeismicReth::new().data_dir(temp_dir_path).spawn();
+
+        assert!(!seismic_reth.tee_pubkey().is_zero());
+        assert_eq!(seismic_reth.keys().len(), seismic_reth.addresses().len());
+        assert!(seismic_reth
+            .enclave_endpoint()
+            .ends_with(&seismic_reth.enclave_port().to_string()));
+    });
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn can_launch_seismic_reth_instances() {
+    if !ci_info::is_ci() {
+        return;
+    }
+
+    // each instance runs its own mock enclave server
+    run_with_tempdir_sync("seismic-reth-test-", |temp_dir_path| {
+        let first = SeismicReth::new().instance(100).data_dir(temp_dir_path).spawn();
+
+        run_with_tempdir_sync("seismic-reth-test-", |temp_dir_path| {
+            let second = SeismicReth::new().instance(101).data_dir(temp_dir_path).spawn();
+
+            assert_ne!(first.http_port(), second.http_port());
+            assert_ne!(first.enclave_port(), second.enclave_port());
+        });
+    });
+}
@@ -1,7 +1,8 @@
 use crate::{
     fillers::{
         CachedNonceManager, ChainIdFiller, FillerControlFlow, GasFiller, JoinFill, NonceFiller,
-        NonceManager, RecommendedFillers, SimpleNonceManager, TxFiller, WalletFiller,
+        NonceManager, RecommendedFillers, ResyncingNonceManager, SimpleNonceManager, TxFiller,
+        WalletFiller,
     },
     provider::SendableTx,
     Provider, RootProvider,
@@ -174,6 +175,15 @@ impl<L, N: Network> ProviderBuilder<L, Identity, N> {
         self.with_nonce_management(CachedNonceManager::default())
     }
 
+    /// Add resyncing nonce management to the stack being built.
+    ///
+    /// See [`ResyncingNonceManager`]
+    pub fn with_resyncing_nonce_management(
+        self,
+    ) -> ProviderBuilder<L, JoinFill<Identity, NonceFiller<ResyncingNonceManager>>, N> {
+        self.with_nonce_management(ResyncingNonceManager::default())
+    }
+
     /// Add a chain ID filler to the stack being built. The filler will attempt
     /// to fetch the chain ID from the provider using
     /// [`Provider::get_chain_id`]. the first time a transaction is prepared,
@@ -6,6 +6,7 @@ use crate::{
     utils::Eip1559Estimation,
     Provider,
 };
+use alloy_consensus::TxSeismicEip1559;
 use alloy_eips::eip4844::BLOB_TX_MIN_BLOB_GASPRICE;
 use alloy_json_rpc::RpcError;
 use alloy_network::{Network, TransactionBuilder, TransactionBuilder4844};
@@ -36,6 +37,8 @@ pub enum GasFillable {
 /// The layer will populate gas fields based on the following logic:
 /// - if `gas_price` is set, it will process as a legacy tx and populate the `gas_limit` field if
 ///   unset.
+/// - if `encryption_pubkey` is set, it will process as a seismic tx and populate the `gas_limit`
+///   and `gas_price` fields if unset, unless the request is built as a dynamic-fee seismic tx.
 /// - if `access_list` is set, it will process as a 2930 tx and populate the `gas_limit` and
 ///   `gas_price` field if unset.
 /// - if `blob_sidecar` is set, it will process as a 4844 tx and populate the `gas_limit`,
@@ -118,6 +121,11 @@ impl GasFiller {
 
         Ok(GasFillable::Eip1559 { gas_limit, estimate })
     }
+
+    /// Returns true if `tx` is a seismic transaction priced with a legacy gas price.
+    fn is_legacy_seismic<N: Network>(tx: &N::TransactionRequest) -> bool {
+        tx.is_seismic() && tx.output_tx_type().into() != TxSeismicEip1559::TX_TYPE
+    }
 }
 
 impl<N: Network> TxFiller<N> for GasFiller {
@@ -150,7 +158,7 @@ impl<N: Network> TxFiller<N> for GasFiller {
         P: Provider<T, N>,
         T: Transport + Clone,
     {
-        if tx.gas_price().is_some() || tx.encryption_pubkey().is_some() {
+        if tx.gas_price().is_some() || Self::is_legacy_seismic::<N>(tx) {
             self.prepare_legacy(provider, tx).await
         } else {
             match self.prepare_1559(provider, tx).await {
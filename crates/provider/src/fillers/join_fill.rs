@@ -4,7 +4,7 @@ use crate::{
     Provider, ProviderLayer,
 };
 use alloy_network::Network;
-use alloy_transport::{Transport, TransportResult};
+use alloy_transport::{Transport, TransportError, TransportResult};
 use futures::try_join;
 
 /// A layer that can fill in a [`TransactionRequest`] with additional information by joining two
@@ -126,6 +126,19 @@ where
         };
         Ok(tx)
     }
+
+    async fn handle_send_error<P, T>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+        error: &TransportError,
+    ) where
+        P: Provider<T, N>,
+        T: Transport + Clone,
+    {
+        self.left.handle_send_error(provider, tx, error).await;
+        self.right.handle_send_error(provider, tx, error).await;
+    }
 }
 
 impl<L, R, P, T, N> ProviderLayer<P, T, N> for JoinFill<L, R>
@@ -14,7 +14,12 @@ mod wallet;
 pub use wallet::WalletFiller;
 
 mod nonce;
-pub use nonce::{CachedNonceManager, NonceFiller, NonceManager, SimpleNonceManager};
+#[cfg(not(target_arch = "wasm32"))]
+pub use nonce::FileNonceStore;
+pub use nonce::{
+    CachedNonceManager, NonceError, NonceFiller, NonceGap, NonceManager, NonceStore,
+    ResyncingNonceManager, SimpleNonceManager,
+};
 
 mod gas;
 pub use gas::{BlobGasFiller, GasFillable, GasFiller};
@@ -25,11 +30,11 @@ use tracing::error;
 
 use crate::{
     provider::SendableTx, Identity, PendingTransactionBuilder, Provider, ProviderLayer,
-    RootProvider,
+    RootProvider, SeismicCallOptions,
 };
 use alloy_json_rpc::RpcError;
 use alloy_network::{AnyNetwork, Ethereum, Network};
-use alloy_transport::{Transport, TransportResult};
+use alloy_transport::{Transport, TransportError, TransportResult};
 use async_trait::async_trait;
 use futures_utils_wasm::impl_future;
 use std::marker::PhantomData;
@@ -205,6 +210,24 @@ pub trait TxFiller<N: Network = Ethereum>: Clone + Send + Sync + std::fmt::Debug
             self.fill(fillable, tx).await
         }
     }
+
+    /// Handles the failure to send a transaction filled by this filler, e.g. to roll back any
+    /// state kept for it.
+    ///
+    /// `tx` is the transaction request as it was last filled, before being signed if it was.
+    /// The default implementation does nothing.
+    fn handle_send_error<P, T>(
+        &self,
+        _provider: &P,
+        _tx: &N::TransactionRequest,
+        _error: &TransportError,
+    ) -> impl_future!(<Output = ()>)
+    where
+        P: Provider<T, N>,
+        T: Transport + Clone,
+    {
+        async {}
+    }
 }
 
 /// A [`Provider`] that applies one or more [`TxFiller`]s.
@@ -251,8 +274,19 @@ where
         self.filler.join_with(other).layer(self.inner)
     }
 
-    async fn fill_inner(&self, mut tx: SendableTx<N>) -> TransportResult<SendableTx<N>> {
+    async fn fill_inner(&self, tx: SendableTx<N>) -> TransportResult<SendableTx<N>> {
+        self.fill_inner_with_request(tx, &mut None).await
+    }
+
+    /// Fills the transaction, setting `request` to the transaction request as it was last filled,
+    /// before being signed if it was, even if filling fails.
+    async fn fill_inner_with_request(
+        &self,
+        mut tx: SendableTx<N>,
+        request: &mut Option<N::TransactionRequest>,
+    ) -> TransportResult<SendableTx<N>> {
         let mut count = 0;
+        *request = tx.as_builder().cloned();
 
         while self.filler.continue_filling(&tx) {
             self.filler.fill_sync(&mut tx);
@@ -270,10 +304,35 @@ where
                 );
                 panic!("{}, {:?}, {:?}", ERROR, &tx, &self.filler);
             }
+
+            if let Some(builder) = tx.as_builder() {
+                *request = Some(builder.clone());
+            }
         }
         Ok(tx)
     }
 
+    /// Fills and sends the transaction, see
+    /// [`send_transaction_internal`](Provider::send_transaction_internal).
+    async fn fill_and_send(
+        &self,
+        tx: SendableTx<N>,
+        request: &mut Option<N::TransactionRequest>,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        let tx = self.fill_inner_with_request(tx, request).await?;
+
+        if let Some(builder) = tx.as_builder() {
+            if let FillerControlFlow::Missing(missing) = self.filler.status(builder) {
+                // TODO: improve this.
+                // blocked by #431
+                let message = format!("missing properties: {:?}", missing);
+                return Err(RpcError::local_usage_str(&message));
+            }
+        }
+        // Errors in tx building happen further down the stack.
+        self.inner.send_transaction_internal(tx).await
+    }
+
     /// Fills the transaction request, using the configured fillers
     pub async fn fill(&self, tx: N::TransactionRequest) -> TransportResult<SendableTx<N>> {
         self.fill_inner(SendableTx::Builder(tx)).await
@@ -293,7 +352,11 @@ where
         self.inner.root()
     }
 
-    async fn seismic_call(&self, mut tx: SendableTx<N>) -> TransportResult<Bytes> {
+    async fn seismic_call_with(
+        &self,
+        mut tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
         tx = self.fill_inner(tx).await?;
 
         if let Some(builder) = tx.as_builder() {
@@ -306,27 +369,37 @@ where
         }
 
         // Errors in tx building happen further down the stack.
-        self.inner.seismic_call(tx).await
+        self.inner.seismic_call_with(tx, options).await
     }
 
-    async fn send_transaction_internal(
+    async fn seismic_signed_call_with(
         &self,
         mut tx: SendableTx<N>,
-    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
         tx = self.fill_inner(tx).await?;
 
         if let Some(builder) = tx.as_builder() {
             if let FillerControlFlow::Missing(missing) = self.filler.status(builder) {
-                // TODO: improve this.
-                // blocked by #431
                 let message = format!("missing properties: {:?}", missing);
                 return Err(RpcError::local_usage_str(&message));
             }
         }
-        println!("fillProvider send_transaction_internal: tx: {:?}", tx);
 
-        // Errors in tx building happen further down the stack.
-        self.inner.send_transaction_internal(tx).await
+        self.inner.seismic_signed_call_with(tx, options).await
+    }
+
+    async fn send_transaction_internal(
+        &self,
+        tx: SendableTx<N>,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        // Nonces and other state reserved by the fillers are rolled back if filling fails too.
+        let mut request = None;
+        let result = self.fill_and_send(tx, &mut request).await;
+        if let (Err(error), Some(request)) = (&result, &request) {
+            self.filler.handle_send_error(&self.inner, request, error).await;
+        }
+        result
     }
 }
 
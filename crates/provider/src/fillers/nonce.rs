@@ -3,13 +3,14 @@ use crate::{
     provider::SendableTx,
     Provider,
 };
+use alloy_json_rpc::RpcError;
 use alloy_network::{Network, TransactionBuilder};
 use alloy_primitives::Address;
-use alloy_transport::{Transport, TransportResult};
+use alloy_transport::{Transport, TransportError, TransportResult};
 use async_trait::async_trait;
 use dashmap::DashMap;
 use futures::lock::Mutex;
-use std::sync::Arc;
+use std::{fmt, io, sync::Arc};
 
 /// A trait that determines the behavior of filling nonces.
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -21,6 +22,28 @@ pub trait NonceManager: Clone + Send + Sync + std::fmt::Debug {
         P: Provider<T, N>,
         N: Network,
         T: Transport + Clone;
+
+    /// Releases a nonce returned by [`get_next_nonce`](Self::get_next_nonce) that was not used,
+    /// e.g. because sending the transaction failed before it was broadcast.
+    ///
+    /// The default implementation does nothing.
+    async fn release_nonce(&self, _address: Address, _nonce: u64) {}
+
+    /// Handles the failure to send a transaction with the given nonce.
+    ///
+    /// The default implementation does nothing.
+    async fn handle_send_error<P, T, N>(
+        &self,
+        _provider: &P,
+        _address: Address,
+        _nonce: u64,
+        _error: &TransportError,
+    ) where
+        P: Provider<T, N>,
+        N: Network,
+        T: Transport + Clone,
+    {
+    }
 }
 
 /// This [`NonceManager`] implementation will fetch the transaction count for any new account it
@@ -90,6 +113,397 @@ impl NonceManager for CachedNonceManager {
     }
 }
 
+/// A nonce related error returned by a node when sending a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum NonceError {
+    /// The nonce was already used by an included transaction.
+    #[error("nonce too low")]
+    TooLow,
+    /// The nonce is ahead of the next nonce of the account.
+    #[error("nonce too high")]
+    TooHigh,
+    /// The transaction is already in the mempool.
+    #[error("transaction already known")]
+    AlreadyKnown,
+    /// A transaction with the same nonce is already in the mempool, and paying too little to be
+    /// replaced.
+    #[error("replacement transaction underpriced")]
+    ReplacementUnderpriced,
+}
+
+impl NonceError {
+    /// Parses the error returned by a node when sending a transaction, if it is related to the
+    /// nonce.
+    pub fn from_transport_error(error: &TransportError) -> Option<Self> {
+        error.as_error_resp().and_then(|payload| Self::from_message(&payload.message))
+    }
+
+    /// Parses the message of an error returned by a node when sending a transaction, if it is
+    /// related to the nonce.
+    ///
+    /// This recognizes the messages of the most common clients, such as geth, reth, erigon,
+    /// nethermind and besu.
+    pub fn from_message(message: &str) -> Option<Self> {
+        let message = message.to_lowercase().replace('_', " ");
+        let contains_any = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+        if contains_any(&["nonce too low", "nonce is too low", "oldnonce"]) {
+            Some(Self::TooLow)
+        } else if contains_any(&["nonce too high", "nonce is too high", "noncegap", "nonce gap"]) {
+            Some(Self::TooHigh)
+        } else if contains_any(&["already known", "known transaction", "already imported"]) {
+            Some(Self::AlreadyKnown)
+        } else if contains_any(&["replacement transaction underpriced", "replacement underpriced"])
+        {
+            Some(Self::ReplacementUnderpriced)
+        } else {
+            None
+        }
+    }
+}
+
+/// A gap in the nonces of an account, left by transactions that were reserved a nonce but never
+/// reached the node, or were dropped from its mempool.
+///
+/// Transactions with nonces after the gap cannot be included until it is filled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonceGap {
+    /// The account.
+    pub address: Address,
+    /// The transaction count of the account in the latest block.
+    pub latest: u64,
+    /// The transaction count of the account including pending transactions, the first nonce of
+    /// the gap.
+    pub pending: u64,
+    /// The next nonce that would have been used, the end of the gap (exclusive).
+    pub next: u64,
+}
+
+impl fmt::Display for NonceGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nonces {}..{} of {} are missing", self.pending, self.next, self.address)
+    }
+}
+
+/// Storage for the next nonces of accounts, shared by several [`ResyncingNonceManager`]s, possibly
+/// in several processes.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait NonceStore: fmt::Debug + Send + Sync {
+    /// Atomically updates the next nonce of the account, by calling `f` with the stored value and
+    /// storing the returned value. `None` means that no nonce is stored.
+    async fn update(
+        &self,
+        address: Address,
+        f: &mut (dyn FnMut(Option<u64>) -> Option<u64> + Send),
+    ) -> io::Result<()>;
+}
+
+/// A [`NonceStore`] keeping the next nonces in a JSON file, shared by the processes using the
+/// same file.
+///
+/// Updates are serialized across processes by an exclusive lock on a file next to the nonces file,
+/// with the `.lock` extension appended. The lock is held by the operating system on behalf of the
+/// updating process, so it is released even if that process dies during an update.
+///
+/// Waiting for the lock does not block the current thread, but reading and writing the nonces file
+/// does.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct FileNonceStore {
+    path: std::path::PathBuf,
+    lock_path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileNonceStore {
+    /// How long to wait for the lock before failing.
+    const LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+    /// How long to wait before trying to take the lock again.
+    const LOCK_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+    /// Creates a store keeping the nonces in the file at the given path.
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        let path = path.into();
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+        Self { path, lock_path: lock_path.into() }
+    }
+
+    /// Returns the path of the nonces file.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Waits for the exclusive lock on the lock file, and returns the locked file, which releases
+    /// the lock once dropped.
+    async fn lock(&self) -> io::Result<std::fs::File> {
+        use fs2::FileExt;
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&self.lock_path)?;
+        let start = std::time::Instant::now();
+        loop {
+            match file.try_lock_exclusive() {
+                Ok(()) => return Ok(file),
+                Err(err) if err.kind() == fs2::lock_contended_error().kind() => {
+                    if start.elapsed() > Self::LOCK_TIMEOUT {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("timed out waiting for {}", self.lock_path.display()),
+                        ));
+                    }
+                    tokio::time::sleep(Self::LOCK_RETRY_INTERVAL).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn read(&self) -> io::Result<std::collections::BTreeMap<Address, u64>> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) if bytes.is_empty() => Ok(Default::default()),
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Default::default()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl NonceStore for FileNonceStore {
+    async fn update(
+        &self,
+        address: Address,
+        f: &mut (dyn FnMut(Option<u64>) -> Option<u64> + Send),
+    ) -> io::Result<()> {
+        let _lock = self.lock().await?;
+        let mut nonces = self.read()?;
+        match f(nonces.get(&address).copied()) {
+            Some(nonce) => nonces.insert(address, nonce),
+            None => nonces.remove(&address),
+        };
+
+        // Write to a temporary file first, so that the nonces file is never left half written.
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let bytes = serde_json::to_vec(&nonces)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        let mut tmp = std::fs::File::create(&tmp_path)?;
+        std::io::Write::write_all(&mut tmp, &bytes)?;
+        // Make sure the nonces are on disk before they replace the previous file, so that a crash
+        // cannot leave it empty.
+        tmp.sync_all()?;
+        drop(tmp);
+        std::fs::rename(&tmp_path, &self.path)
+    }
+}
+
+/// Resyncing nonce manager
+///
+/// This [`NonceManager`] implementation stores the next nonce of each account locally like
+/// [`CachedNonceManager`], but resyncs it from the node instead of staying out of sync when
+/// sending a transaction fails:
+/// - when the node rejects the transaction because of its nonce (see [`NonceError`]), the next
+///   nonce is resynced from the transaction count of the account, reporting any [`NonceGap`],
+/// - when the node rejects the transaction for another reason, or it never reached the node, its
+///   nonce is [released](NonceManager::release_nonce),
+/// - when it is unknown whether the transaction reached the node, the next nonce is fetched again
+///   for the next transaction.
+///
+/// The nonces can also be shared with other managers, possibly in other processes, through a
+/// [`NonceStore`], so that they don't reserve the same nonces.
+#[derive(Clone, Debug, Default)]
+pub struct ResyncingNonceManager {
+    /// The next nonce of each account, `None` if it must be fetched.
+    nonces: Arc<DashMap<Address, Arc<Mutex<Option<u64>>>>>,
+    store: Option<Arc<dyn NonceStore>>,
+}
+
+impl ResyncingNonceManager {
+    /// Creates a nonce manager sharing the nonces through the given store.
+    pub fn with_store(store: impl NonceStore + 'static) -> Self {
+        Self { nonces: Default::default(), store: Some(Arc::new(store)) }
+    }
+
+    /// Returns the next nonce of the account, locking it.
+    fn next_nonce(&self, address: Address) -> Arc<Mutex<Option<u64>>> {
+        // Locks dashmap internally for a short duration to clone the `Arc`.
+        // We also don't want to hold the dashmap lock through an await point.
+        let rm = self.nonces.entry(address).or_default();
+        Arc::clone(rm.value())
+    }
+
+    /// Updates the next nonce of the account in the store, if any.
+    async fn update_store(
+        &self,
+        address: Address,
+        mut f: impl FnMut(Option<u64>) -> Option<u64> + Send,
+    ) -> TransportResult<()> {
+        let Some(store) = &self.store else { return Ok(()) };
+        store.update(address, &mut f).await.map_err(RpcError::local_usage)
+    }
+
+    /// Resyncs the next nonce of the account from its transaction count, including pending
+    /// transactions.
+    ///
+    /// Returns the [`NonceGap`] between the transactions known to the node and the next nonce
+    /// that would have been used, if any. Resyncing fills the gap, by reusing its nonces for the
+    /// next transactions, if the nonces up to its end were all reserved by this manager. Nonces
+    /// reserved by the managers sharing the [`NonceStore`] may still be used by them, so the gap is
+    /// then only filled by an explicit call to [`fill_gap`](Self::fill_gap).
+    pub async fn resync<P, T, N>(
+        &self,
+        provider: &P,
+        address: Address,
+    ) -> TransportResult<Option<NonceGap>>
+    where
+        P: Provider<T, N>,
+        N: Network,
+        T: Transport + Clone,
+    {
+        let next_nonce = self.next_nonce(address);
+        let mut next_nonce = next_nonce.lock().await;
+
+        let latest = provider.get_transaction_count(address).latest().await?;
+        let pending = provider.get_transaction_count(address).pending().await?;
+        let owned = *next_nonce;
+        let mut next = owned;
+        let mut resynced = pending;
+        self.update_store(address, |stored| {
+            next = next.max(stored);
+            resynced = match stored {
+                Some(stored) if Some(stored) != owned => stored.max(pending),
+                _ => pending,
+            };
+            Some(resynced)
+        })
+        .await?;
+        *next_nonce = Some(resynced);
+
+        let gap = next.filter(|&next| next > pending).map(|next| NonceGap {
+            address,
+            latest,
+            pending,
+            next,
+        });
+        if let Some(gap) = gap {
+            warn!(%gap, "nonce gap detected");
+        }
+        Ok(gap)
+    }
+
+    /// Fills a [`NonceGap`] returned by [`resync`](Self::resync), by reusing its nonces for the
+    /// next transactions, including the nonces reserved by the managers sharing the
+    /// [`NonceStore`].
+    ///
+    /// Returns `false` without filling the gap if nonces were reserved after it was reported.
+    pub async fn fill_gap(&self, gap: NonceGap) -> TransportResult<bool> {
+        let next_nonce = self.next_nonce(gap.address);
+        let mut next_nonce = next_nonce.lock().await;
+        if next_nonce.is_some_and(|next| next != gap.next) {
+            return Ok(false);
+        }
+
+        let mut filled = true;
+        self.update_store(gap.address, |stored| match stored {
+            Some(stored) if stored != gap.next => {
+                filled = false;
+                Some(stored)
+            }
+            _ => Some(gap.pending),
+        })
+        .await?;
+        if filled {
+            *next_nonce = Some(gap.pending);
+        }
+        Ok(filled)
+    }
+
+    /// Forgets the next nonce of the account, so that it is fetched for the next transaction.
+    pub async fn invalidate(&self, address: Address) {
+        *self.next_nonce(address).lock().await = None;
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl NonceManager for ResyncingNonceManager {
+    async fn get_next_nonce<P, T, N>(&self, provider: &P, address: Address) -> TransportResult<u64>
+    where
+        P: Provider<T, N>,
+        N: Network,
+        T: Transport + Clone,
+    {
+        let next_nonce = self.next_nonce(address);
+        let mut next_nonce = next_nonce.lock().await;
+
+        let mut nonce = match *next_nonce {
+            Some(nonce) => nonce,
+            None => provider.get_transaction_count(address).pending().await?,
+        };
+        // Skip the nonces reserved by the managers sharing the store.
+        self.update_store(address, |stored| {
+            nonce = nonce.max(stored.unwrap_or_default());
+            Some(nonce + 1)
+        })
+        .await?;
+        *next_nonce = Some(nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Releases the nonce if it is the last one reserved for the account, as later nonces would
+    /// otherwise be left after a gap.
+    async fn release_nonce(&self, address: Address, nonce: u64) {
+        let next_nonce = self.next_nonce(address);
+        let mut next_nonce = next_nonce.lock().await;
+        if *next_nonce != Some(nonce + 1) {
+            return;
+        }
+        let released = self
+            .update_store(address, |stored| match stored {
+                Some(stored) if stored == nonce + 1 => Some(nonce),
+                stored => stored,
+            })
+            .await;
+        match released {
+            Ok(()) => *next_nonce = Some(nonce),
+            Err(err) => warn!(%address, nonce, %err, "failed to release nonce"),
+        }
+    }
+
+    async fn handle_send_error<P, T, N>(
+        &self,
+        provider: &P,
+        address: Address,
+        nonce: u64,
+        error: &TransportError,
+    ) where
+        P: Provider<T, N>,
+        N: Network,
+        T: Transport + Clone,
+    {
+        if let Some(nonce_error) = NonceError::from_transport_error(error) {
+            debug!(%address, nonce, %nonce_error, "resyncing nonce");
+            if let Err(err) = self.resync(provider, address).await {
+                warn!(%address, %err, "failed to resync nonce");
+                self.invalidate(address).await;
+            }
+        } else if error.is_error_resp() || error.is_local_usage_error() || error.is_ser_error() {
+            // The transaction was rejected by the node, or never sent.
+            self.release_nonce(address, nonce).await;
+        } else {
+            // The transaction may or may not have reached the node.
+            self.invalidate(address).await;
+        }
+    }
+}
+
 /// A [`TxFiller`] that fills nonces on transactions. The behavior of filling nonces is determined
 /// by the [`NonceManager`].
 ///
@@ -166,6 +580,20 @@ impl<M: NonceManager, N: Network> TxFiller<N> for NonceFiller<M> {
         }
         Ok(tx)
     }
+
+    async fn handle_send_error<P, T>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+        error: &TransportError,
+    ) where
+        P: Provider<T, N>,
+        T: Transport + Clone,
+    {
+        if let (Some(from), Some(nonce)) = (tx.from(), tx.nonce()) {
+            self.nonce_manager.handle_send_error(provider, from, nonce, error).await;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +663,175 @@ mod tests {
         assert_eq!(*filler.nonce_manager.nonces.get(&address).unwrap().value().lock().await, 4);
     }
 
+    #[test]
+    fn nonce_errors() {
+        assert_eq!(
+            NonceError::from_message("nonce too low: next nonce 5, tx nonce 3"),
+            Some(NonceError::TooLow)
+        );
+        assert_eq!(NonceError::from_message("OldNonce"), Some(NonceError::TooLow));
+        assert_eq!(NonceError::from_message("NONCE_TOO_HIGH"), Some(NonceError::TooHigh));
+        assert_eq!(NonceError::from_message("already known"), Some(NonceError::AlreadyKnown));
+        assert_eq!(
+            NonceError::from_message("replacement transaction underpriced"),
+            Some(NonceError::ReplacementUnderpriced)
+        );
+        assert_eq!(NonceError::from_message("insufficient funds for gas * price + value"), None);
+    }
+
+    #[tokio::test]
+    async fn resync_reports_gap() {
+        let manager = ResyncingNonceManager::default();
+        let provider = ProviderBuilder::new().on_anvil();
+        let address = Address::ZERO;
+        for i in 0..3 {
+            assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), i);
+        }
+
+        // none of the nonces were used
+        let gap = manager.resync(&provider, address).await.unwrap();
+        assert_eq!(gap, Some(NonceGap { address, latest: 0, pending: 0, next: 3 }));
+        assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), 0);
+        assert_eq!(manager.resync(&provider, address).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn release_nonce() {
+        let manager = ResyncingNonceManager::default();
+        let provider = ProviderBuilder::new().on_anvil();
+        let address = Address::ZERO;
+        assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), 0);
+        assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), 1);
+
+        // only the last nonce can be released
+        manager.release_nonce(address, 0).await;
+        assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), 2);
+        manager.release_nonce(address, 2).await;
+        assert_eq!(manager.get_next_nonce(&provider, address).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn shared_file_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+        let first = ResyncingNonceManager::with_store(FileNonceStore::new(&path));
+        let second = ResyncingNonceManager::with_store(FileNonceStore::new(&path));
+        let provider = ProviderBuilder::new().on_anvil();
+        let address = Address::ZERO;
+
+        assert_eq!(first.get_next_nonce(&provider, address).await.unwrap(), 0);
+        assert_eq!(second.get_next_nonce(&provider, address).await.unwrap(), 1);
+        assert_eq!(first.get_next_nonce(&provider, address).await.unwrap(), 2);
+
+        let mut stored = None;
+        FileNonceStore::new(&path)
+            .update(address, &mut |nonce| {
+                stored = nonce;
+                nonce
+            })
+            .await
+            .unwrap();
+        assert_eq!(stored, Some(3));
+
+        // the lock file is kept, but the lock is released after each update
+        let lock = std::fs::File::open(dir.path().join("nonces.json.lock")).unwrap();
+        fs2::FileExt::try_lock_exclusive(&lock).unwrap();
+    }
+
+    #[tokio::test]
+    async fn file_store_waits_for_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileNonceStore::new(dir.path().join("nonces.json"));
+        let lock = store.lock().await.unwrap();
+
+        // another holder of the lock makes the update wait, without blocking the runtime
+        let mut set = |_| Some(1);
+        let mut update = Box::pin(store.update(Address::ZERO, &mut set));
+        assert!(tokio::time::timeout(std::time::Duration::from_millis(50), &mut update)
+            .await
+            .is_err());
+
+        // the lock is released along with the file of its holder, even if the holder dies
+        drop(lock);
+        update.await.unwrap();
+        assert_eq!(store.read().unwrap().get(&Address::ZERO), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn resync_keeps_shared_reservations() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+        let first = ResyncingNonceManager::with_store(FileNonceStore::new(&path));
+        let second = ResyncingNonceManager::with_store(FileNonceStore::new(&path));
+        let provider = ProviderBuilder::new().on_anvil();
+        let address = Address::ZERO;
+
+        assert_eq!(first.get_next_nonce(&provider, address).await.unwrap(), 0);
+        assert_eq!(first.get_next_nonce(&provider, address).await.unwrap(), 1);
+        assert_eq!(second.get_next_nonce(&provider, address).await.unwrap(), 2);
+
+        // the nonce reserved by the other manager is not given out again
+        let gap = first.resync(&provider, address).await.unwrap().unwrap();
+        assert_eq!(gap, NonceGap { address, latest: 0, pending: 0, next: 3 });
+        assert_eq!(first.get_next_nonce(&provider, address).await.unwrap(), 3);
+
+        // unless the gap is filled explicitly, which is refused once nonces are reserved after it
+        assert!(!first.fill_gap(gap).await.unwrap());
+        let gap = second.resync(&provider, address).await.unwrap().unwrap();
+        assert_eq!(gap.next, 4);
+        assert!(second.fill_gap(gap).await.unwrap());
+        assert_eq!(second.get_next_nonce(&provider, address).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn resyncs_after_nonce_too_low() {
+        let provider =
+            ProviderBuilder::new().with_resyncing_nonce_management().on_anvil_with_wallet();
+
+        let from = provider.default_signer_address();
+        let tx = TransactionRequest {
+            from: Some(from),
+            value: Some(U256::from(100)),
+            to: Some(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045").into()),
+            gas_price: Some(20e9 as u128),
+            gas: Some(21000),
+            ..Default::default()
+        };
+
+        provider.send_transaction(tx.clone()).await.unwrap().watch().await.unwrap();
+        // use the next nonce behind the back of the nonce manager
+        provider.send_transaction(tx.clone().nonce(1)).await.unwrap().watch().await.unwrap();
+
+        let err = provider.send_transaction(tx.clone()).await.unwrap_err();
+        assert_eq!(NonceError::from_transport_error(&err), Some(NonceError::TooLow));
+
+        let tx_hash = provider.send_transaction(tx).await.unwrap().watch().await.unwrap();
+        let mined_tx = provider.get_transaction_by_hash(tx_hash).await.unwrap().unwrap();
+        assert_eq!(mined_tx.nonce(), 2);
+    }
+
+    #[tokio::test]
+    async fn releases_nonce_if_filling_fails() {
+        let manager = ResyncingNonceManager::default();
+        let provider =
+            ProviderBuilder::new().with_nonce_management(manager.clone()).on_anvil_with_wallet();
+
+        // the wallet holds no credential for the sender, signing fails after the nonce is filled
+        let from = Address::ZERO;
+        let tx = TransactionRequest {
+            from: Some(from),
+            value: Some(U256::from(100)),
+            to: Some(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045").into()),
+            gas_price: Some(20e9 as u128),
+            gas: Some(21000),
+            chain_id: Some(31337),
+            ..Default::default()
+        };
+        provider.send_transaction(tx).await.unwrap_err();
+
+        assert_eq!(manager.get_next_nonce(&provider, from).await.unwrap(), 0);
+    }
+
     #[tokio::test]
     async fn no_nonce_if_sender_unset() {
         let provider = ProviderBuilder::new().with_cached_nonce_management().on_anvil();
@@ -2,18 +2,22 @@
 
 use crate::{Provider, RootProvider};
 use alloy_consensus::BlockHeader;
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
 use alloy_json_rpc::RpcError;
-use alloy_network::{BlockResponse, Network};
+use alloy_network::{
+    BlockResponse, Network, NetworkWallet, TransactionBuilder, TransactionResponse,
+};
 use alloy_primitives::{
     map::{B256HashMap, B256HashSet},
     TxHash, B256,
 };
-use alloy_transport::{utils::Spawnable, Transport, TransportError};
+use alloy_transport::{utils::Spawnable, BoxFuture, Transport, TransportError, TransportResult};
 use futures::{stream::StreamExt, FutureExt, Stream};
 use std::{
     collections::{BTreeMap, VecDeque},
     fmt,
     future::Future,
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
@@ -86,6 +90,7 @@ pub enum PendingTransactionError {
 pub struct PendingTransactionBuilder<T, N: Network> {
     config: PendingTransactionConfig,
     provider: RootProvider<T, N>,
+    escalation: Option<Escalation<N>>,
 }
 
 impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
@@ -99,7 +104,7 @@ impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
         provider: RootProvider<T, N>,
         config: PendingTransactionConfig,
     ) -> Self {
-        Self { config, provider }
+        Self { config, provider, escalation: None }
     }
 
     /// Returns the inner configuration.
@@ -176,6 +181,26 @@ impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
         self
     }
 
+    /// Returns the fee escalation policy, if any.
+    pub fn escalation_policy(&self) -> Option<&EscalationPolicy> {
+        self.escalation.as_ref().map(|escalation| &escalation.policy)
+    }
+
+    /// Replaces the transaction with copies paying higher fees, signed by the given wallet, while
+    /// it is not included, according to the given [`EscalationPolicy`].
+    ///
+    /// The replacements are watched along with the transaction, and the [`PendingTransaction`]
+    /// resolves to the hash of whichever of them is included. The wallet must hold a credential
+    /// for the sender of the transaction.
+    #[doc(alias = "with_fee_bumping")]
+    pub fn with_escalation<W>(mut self, policy: EscalationPolicy, wallet: W) -> Self
+    where
+        W: NetworkWallet<N> + Clone + 'static,
+    {
+        self.escalation = Some(Escalation::new(policy, wallet));
+        self
+    }
+
     /// Registers the watching configuration with the provider.
     ///
     /// This does not wait for the transaction to be confirmed, but returns a [`PendingTransaction`]
@@ -187,7 +212,10 @@ impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
     ///   confirmed.
     #[doc(alias = "build")]
     pub async fn register(self) -> Result<PendingTransaction, PendingTransactionError> {
-        self.provider.watch_pending_transaction(self.config).await
+        match self.escalation {
+            Some(escalation) => escalation.register(self.provider, self.config).await,
+            None => self.provider.watch_pending_transaction(self.config).await,
+        }
     }
 
     /// Waits for the transaction to confirm with the given number of confirmations.
@@ -213,12 +241,13 @@ impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
     ///   confirmed.
     /// - [`watch`](Self::watch) for watching the transaction without fetching the receipt.
     pub async fn get_receipt(self) -> Result<N::ReceiptResponse, PendingTransactionError> {
-        let hash = self.config.tx_hash;
-        let mut pending_tx = self.provider.watch_pending_transaction(self.config).await?;
+        let mut hash = self.config.tx_hash;
+        let provider = self.provider.clone();
+        let mut pending_tx = self.register().await?;
 
         // FIXME: this is a hotfix to prevent a race condition where the heartbeat would miss the
         // block the tx was mined in
-        let mut interval = tokio::time::interval(self.provider.client().poll_interval());
+        let mut interval = tokio::time::interval(provider.client().poll_interval());
 
         loop {
             let mut confirmed = false;
@@ -226,13 +255,14 @@ impl<T: Transport + Clone, N: Network> PendingTransactionBuilder<T, N> {
             select! {
                 _ = interval.tick() => {},
                 res = &mut pending_tx => {
-                    let _ = res?;
+                    // the confirmed transaction may be a replacement
+                    hash = res?;
                     confirmed = true;
                 }
             }
 
             // try to fetch the receipt
-            let receipt = self.provider.get_transaction_receipt(hash).await?;
+            let receipt = provider.get_transaction_receipt(hash).await?;
             if let Some(receipt) = receipt {
                 return Ok(receipt);
             }
@@ -332,6 +362,245 @@ impl PendingTransactionConfig {
     }
 }
 
+/// Policy for replacing a pending transaction with a copy paying higher fees when it is not
+/// included in time.
+///
+/// Once the transaction has gone [`blocks`](Self::blocks) blocks without being included, it is
+/// re-signed with the same nonce and bumped fees, and rebroadcast. The same goes for each
+/// replacement, up to [`max_replacements`](Self::max_replacements) times.
+///
+/// Nodes only accept a replacement that pays sufficiently more than the transaction it replaces,
+/// so the fees are bumped by at least:
+/// - 10% of the gas price of legacy, EIP-2930 and [`TxSeismic`] transactions,
+/// - 10% of the max priority fee of EIP-1559 and [`TxSeismicEip1559`] transactions,
+/// - 12.5% of their max fee, which keeps it above the base fee after a full block.
+///
+/// [`TxSeismic`]: alloy_consensus::TxSeismic
+/// [`TxSeismicEip1559`]: alloy_consensus::TxSeismicEip1559
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[doc(alias = "FeeBumpPolicy")]
+pub struct EscalationPolicy {
+    /// Number of blocks to wait for inclusion before replacing the transaction.
+    blocks: u64,
+
+    /// Maximum number of replacements.
+    max_replacements: u32,
+
+    /// Percentage to bump the fees by, if higher than the minimum bumps.
+    fee_bump_percent: u64,
+}
+
+impl EscalationPolicy {
+    /// Default maximum number of replacements.
+    pub const DEFAULT_MAX_REPLACEMENTS: u32 = 5;
+
+    /// Minimum bump of the gas price and max priority fee, in basis points.
+    const MIN_PRICE_BUMP_BPS: u64 = 1_000;
+
+    /// Minimum bump of the max fee, in basis points.
+    const MIN_MAX_FEE_BUMP_BPS: u64 = 1_250;
+
+    /// Creates a policy replacing the transaction after the given number of blocks without
+    /// inclusion, with the minimum fee bumps.
+    pub const fn new(blocks: u64) -> Self {
+        Self { blocks, max_replacements: Self::DEFAULT_MAX_REPLACEMENTS, fee_bump_percent: 0 }
+    }
+
+    /// Returns the number of blocks to wait for inclusion before replacing the transaction.
+    pub const fn blocks(&self) -> u64 {
+        self.blocks
+    }
+
+    /// Sets the number of blocks to wait for inclusion before replacing the transaction.
+    pub const fn with_blocks(mut self, blocks: u64) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// Returns the maximum number of replacements.
+    pub const fn max_replacements(&self) -> u32 {
+        self.max_replacements
+    }
+
+    /// Sets the maximum number of replacements.
+    pub const fn with_max_replacements(mut self, max_replacements: u32) -> Self {
+        self.max_replacements = max_replacements;
+        self
+    }
+
+    /// Returns the percentage to bump the fees by.
+    pub const fn fee_bump_percent(&self) -> u64 {
+        self.fee_bump_percent
+    }
+
+    /// Sets the percentage to bump the fees by. Bumps below the minimum accepted by nodes are
+    /// raised to that minimum.
+    pub const fn with_fee_bump_percent(mut self, percent: u64) -> Self {
+        self.fee_bump_percent = percent;
+        self
+    }
+
+    /// Bumps the fees of the given transaction request for it to replace the transaction it was
+    /// built from.
+    pub fn bump_fees<N: Network>(&self, request: &mut N::TransactionRequest) {
+        let bump_bps = self.fee_bump_percent.saturating_mul(100);
+        let price_bump_bps = bump_bps.max(Self::MIN_PRICE_BUMP_BPS);
+        if let Some(gas_price) = request.gas_price() {
+            request.set_gas_price(bump_fee(gas_price, price_bump_bps));
+        }
+        if let Some(max_priority_fee_per_gas) = request.max_priority_fee_per_gas() {
+            request
+                .set_max_priority_fee_per_gas(bump_fee(max_priority_fee_per_gas, price_bump_bps));
+        }
+        if let Some(max_fee_per_gas) = request.max_fee_per_gas() {
+            request.set_max_fee_per_gas(bump_fee(
+                max_fee_per_gas,
+                bump_bps.max(Self::MIN_MAX_FEE_BUMP_BPS),
+            ));
+        }
+    }
+}
+
+/// Returns the fee increased by the given basis points, rounding up, and by at least 1 wei.
+fn bump_fee(fee: u128, bps: u64) -> u128 {
+    let bumped = fee.saturating_mul(10_000 + bps as u128).div_ceil(10_000);
+    bumped.max(fee.saturating_add(1))
+}
+
+/// Replaces a pending transaction according to an [`EscalationPolicy`].
+struct Escalation<N: Network> {
+    policy: EscalationPolicy,
+    /// Signs the replacements.
+    #[allow(clippy::type_complexity)]
+    sign: Arc<
+        dyn Fn(N::TransactionRequest) -> BoxFuture<'static, TransportResult<N::TxEnvelope>>
+            + Send
+            + Sync,
+    >,
+}
+
+impl<N: Network> fmt::Debug for Escalation<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Escalation").field("policy", &self.policy).finish_non_exhaustive()
+    }
+}
+
+impl<N: Network> Escalation<N> {
+    fn new<W>(policy: EscalationPolicy, wallet: W) -> Self
+    where
+        W: NetworkWallet<N> + Clone + 'static,
+    {
+        let sign = move |request: N::TransactionRequest| {
+            let wallet = wallet.clone();
+            let fut: BoxFuture<'static, TransportResult<N::TxEnvelope>> = Box::pin(async move {
+                wallet.sign_request(request).await.map_err(RpcError::local_usage)
+            });
+            fut
+        };
+        Self { policy, sign: Arc::new(sign) }
+    }
+
+    /// Watches the transaction, and starts replacing it if it is still pending.
+    async fn register<T: Transport + Clone>(
+        self,
+        provider: RootProvider<T, N>,
+        config: PendingTransactionConfig,
+    ) -> Result<PendingTransaction, PendingTransactionError> {
+        let tx = provider.get_transaction_by_hash(config.tx_hash).await?;
+        let mut pending_tx = provider.watch_pending_transaction(config).await?;
+
+        // Only transactions that are not included yet can be replaced.
+        let tx = match tx {
+            Some(tx) if tx.block_number().is_none() => tx,
+            Some(_) => {
+                debug!(tx=%pending_tx.tx_hash, "transaction already included, not escalating");
+                return Ok(pending_tx);
+            }
+            None => {
+                warn!(tx=%pending_tx.tx_hash, "transaction not found, not escalating");
+                return Ok(pending_tx);
+            }
+        };
+        let envelope = N::TxEnvelope::decode_2718(&mut tx.as_ref().encoded_2718().as_slice())
+            .map_err(RpcError::local_usage)?;
+        let mut request: N::TransactionRequest = envelope.into();
+        request.set_from(tx.from());
+
+        let (stop, stopped) = oneshot::channel();
+        pending_tx._stop_escalation = Some(stop);
+        self.escalate(provider, pending_tx.tx_hash, request, stopped).spawn_task();
+        Ok(pending_tx)
+    }
+
+    /// Replaces the transaction each time it goes the configured number of blocks without being
+    /// included, until one of the replacements is included, the policy is exhausted or
+    /// `stopped` resolves.
+    async fn escalate<T: Transport + Clone>(
+        self,
+        provider: RootProvider<T, N>,
+        tx_hash: TxHash,
+        mut request: N::TransactionRequest,
+        mut stopped: oneshot::Receiver<()>,
+    ) {
+        let (Some(from), Some(nonce)) = (request.from(), request.nonce()) else {
+            return;
+        };
+        let heart = provider.get_heart().clone();
+        let mut latest = heart.latest().clone();
+
+        for _ in 0..self.policy.max_replacements {
+            // Wait for the configured number of blocks.
+            let mut blocks = 0;
+            while blocks < self.policy.blocks.max(1) {
+                select! {
+                    _ = &mut stopped => return,
+                    changed = latest.changed() => {
+                        if changed.is_err() {
+                            return;
+                        }
+                        blocks += 1;
+                    }
+                }
+            }
+
+            // A transaction with the nonce was included, either this one or one of its
+            // replacements, or the nonce was used by another transaction.
+            match provider.get_transaction_count(from).await {
+                Ok(count) if count > nonce => return,
+                Ok(_) => {}
+                Err(err) => {
+                    warn!(tx=%tx_hash, %err, "failed to fetch nonce, stopping escalation");
+                    return;
+                }
+            }
+
+            self.policy.bump_fees::<N>(&mut request);
+            let replacement = match self.replace(&provider, request.clone()).await {
+                Ok(replacement) => replacement,
+                Err(err) => {
+                    warn!(tx=%tx_hash, %err, "failed to replace transaction, stopping escalation");
+                    return;
+                }
+            };
+            debug!(tx=%tx_hash, %replacement, "replaced transaction");
+            if heart.replace_tx(tx_hash, replacement).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Signs and broadcasts the replacement, returning its hash.
+    async fn replace<T: Transport + Clone>(
+        &self,
+        provider: &RootProvider<T, N>,
+        request: N::TransactionRequest,
+    ) -> TransportResult<TxHash> {
+        let envelope = (self.sign)(request).await?;
+        let pending = provider.send_tx_envelope(envelope).await?;
+        Ok(*pending.tx_hash())
+    }
+}
+
 /// Errors which may occur in heartbeat when watching a transaction.
 #[derive(Debug, thiserror::Error)]
 pub enum WatchTxError {
@@ -346,22 +615,41 @@ struct TxWatcher {
     /// The block at which the transaction was received. To be filled once known.
     /// Invariant: any confirmed transaction in `Heart` has this value set.
     received_at_block: Option<u64>,
-    tx: oneshot::Sender<Result<(), WatchTxError>>,
+    /// Hashes of the transactions replacing the watched one.
+    replacements: Vec<TxHash>,
+    /// The hash of the replacement that was received instead of the watched transaction, if any.
+    received_replacement: Option<TxHash>,
+    tx: oneshot::Sender<Result<TxHash, WatchTxError>>,
 }
 
 impl TxWatcher {
     /// Notify the waiter.
     fn notify(self, result: Result<(), WatchTxError>) {
         debug!(tx=%self.config.tx_hash, "notifying");
-        let _ = self.tx.send(result);
+        let tx_hash = self.received_replacement.unwrap_or(self.config.tx_hash);
+        let _ = self.tx.send(result.map(|()| tx_hash));
     }
 }
 
+/// An instruction to the heartbeat task.
+enum WatchIx {
+    /// Watch for a transaction.
+    Watch(TxWatcher),
+    /// Watch for a replacement of a transaction being watched.
+    Replace {
+        /// The hash of the watched transaction.
+        tx_hash: TxHash,
+        /// The hash of the replacement.
+        replacement: TxHash,
+    },
+}
+
 /// Represents a transaction that is yet to be confirmed a specified number of times.
 ///
 /// This struct is a future created by [`PendingTransactionBuilder`] that resolves to the
 /// transaction hash once the underlying transaction has been confirmed the specified number of
-/// times in the network.
+/// times in the network. If the transaction was replaced according to an [`EscalationPolicy`], it
+/// resolves to the hash of the replacement that was confirmed instead.
 #[doc(alias = "PendingTx", alias = "TxPending")]
 pub struct PendingTransaction {
     /// The transaction hash.
@@ -369,7 +657,9 @@ pub struct PendingTransaction {
     pub(crate) tx_hash: TxHash,
     /// The receiver for the notification.
     // TODO: send a receipt?
-    pub(crate) rx: oneshot::Receiver<Result<(), WatchTxError>>,
+    pub(crate) rx: oneshot::Receiver<Result<TxHash, WatchTxError>>,
+    /// Stops replacing the transaction once dropped.
+    pub(crate) _stop_escalation: Option<oneshot::Sender<()>>,
 }
 
 impl fmt::Debug for PendingTransaction {
@@ -382,11 +672,13 @@ impl PendingTransaction {
     /// Creates a ready pending transaction.
     pub fn ready(tx_hash: TxHash) -> Self {
         let (tx, rx) = oneshot::channel();
-        tx.send(Ok(())).ok(); // Make sure that the receiver is notified already.
-        Self { tx_hash, rx }
+        tx.send(Ok(tx_hash)).ok(); // Make sure that the receiver is notified already.
+        Self { tx_hash, rx, _stop_escalation: None }
     }
 
     /// Returns this transaction's hash.
+    ///
+    /// This is the hash of the transaction that was sent, not of its replacements.
     #[doc(alias = "transaction_hash")]
     pub const fn tx_hash(&self) -> &TxHash {
         &self.tx_hash
@@ -400,17 +692,14 @@ impl Future for PendingTransaction {
         mut self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map(|res| {
-            res??;
-            Ok(self.tx_hash)
-        })
+        self.rx.poll_unpin(cx).map(|res| Ok(res??))
     }
 }
 
 /// A handle to the heartbeat task.
 #[derive(Clone, Debug)]
 pub(crate) struct HeartbeatHandle<N: Network> {
-    tx: mpsc::Sender<TxWatcher>,
+    tx: mpsc::Sender<WatchIx>,
     latest: watch::Receiver<Option<N::BlockResponse>>,
 }
 
@@ -424,14 +713,37 @@ impl<N: Network> HeartbeatHandle<N> {
     ) -> Result<PendingTransaction, PendingTransactionConfig> {
         let (tx, rx) = oneshot::channel();
         let tx_hash = config.tx_hash;
-        match self.tx.send(TxWatcher { config, received_at_block, tx }).await {
-            Ok(()) => Ok(PendingTransaction { tx_hash, rx }),
-            Err(e) => Err(e.0.config),
+        let watcher = TxWatcher {
+            config,
+            received_at_block,
+            replacements: Vec::new(),
+            received_replacement: None,
+            tx,
+        };
+        match self.tx.send(WatchIx::Watch(watcher)).await {
+            Ok(()) => Ok(PendingTransaction { tx_hash, rx, _stop_escalation: None }),
+            Err(e) => match e.0 {
+                WatchIx::Watch(watcher) => Err(watcher.config),
+                WatchIx::Replace { .. } => unreachable!("sent a watch instruction"),
+            },
         }
     }
 
+    /// Watch for the replacement of a transaction being watched, notifying its watcher when
+    /// either of them is confirmed.
+    #[doc(alias = "replace_transaction")]
+    pub(crate) async fn replace_tx(
+        &self,
+        tx_hash: TxHash,
+        replacement: TxHash,
+    ) -> Result<(), PendingTransactionError> {
+        self.tx
+            .send(WatchIx::Replace { tx_hash, replacement })
+            .await
+            .map_err(|_| PendingTransactionError::FailedToRegister)
+    }
+
     /// Returns a watcher that always sees the latest block.
-    #[allow(dead_code)]
     pub(crate) const fn latest(&self) -> &watch::Receiver<Option<N::BlockResponse>> {
         &self.latest
     }
@@ -449,6 +761,9 @@ pub(crate) struct Heartbeat<N, S> {
     /// Transactions to watch for.
     unconfirmed: B256HashMap<TxWatcher>,
 
+    /// Mapping of replacement transaction hash -> hash of the watched transaction it replaces.
+    replacements: B256HashMap<B256>,
+
     /// Ordered map of transactions waiting for confirmations.
     waiting_confs: BTreeMap<u64, Vec<TxWatcher>>,
 
@@ -465,6 +780,7 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
             stream: stream.fuse(),
             past_blocks: Default::default(),
             unconfirmed: Default::default(),
+            replacements: Default::default(),
             waiting_confs: Default::default(),
             reap_at: Default::default(),
             _network: Default::default(),
@@ -476,10 +792,18 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         let to_keep = self.waiting_confs.split_off(&(current_height + 1));
         let to_notify = std::mem::replace(&mut self.waiting_confs, to_keep);
         for watcher in to_notify.into_values().flatten() {
-            watcher.notify(Ok(()));
+            self.notify(watcher, Ok(()));
         }
     }
 
+    /// Notify the waiter, and stop watching for the replacements of its transaction.
+    fn notify(&mut self, watcher: TxWatcher, result: Result<(), WatchTxError>) {
+        for replacement in &watcher.replacements {
+            self.replacements.remove(replacement);
+        }
+        watcher.notify(result);
+    }
+
     /// Get the next time to reap a transaction. If no reaps, this is a very
     /// long time from now (i.e. will not be woken).
     fn next_reap(&self) -> Instant {
@@ -498,7 +822,7 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         for tx_hash in to_reap.values() {
             if let Some(watcher) = self.unconfirmed.remove(tx_hash) {
                 debug!(tx=%tx_hash, "reaped");
-                watcher.notify(Err(WatchTxError::Timeout));
+                self.notify(watcher, Err(WatchTxError::Timeout));
             }
         }
     }
@@ -508,11 +832,12 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
     /// that were received in blocks affected by the reorg (e.g. >= new_height).
     fn move_reorg_to_unconfirmed(&mut self, new_height: u64) {
         for waiters in self.waiting_confs.values_mut() {
-            *waiters = std::mem::take(waiters).into_iter().filter_map(|watcher| {
+            *waiters = std::mem::take(waiters).into_iter().filter_map(|mut watcher| {
                 if let Some(received_at_block) = watcher.received_at_block {
                     // All blocks after and _including_ the new height are reaped.
                     if received_at_block >= new_height {
                         let hash = watcher.config.tx_hash;
+                        watcher.received_replacement = None;
                         debug!(tx=%hash, %received_at_block, %new_height, "return to unconfirmed due to reorg");
                         self.unconfirmed.insert(hash, watcher);
                         return None;
@@ -562,6 +887,49 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         self.unconfirmed.insert(to_watch.config.tx_hash, to_watch);
     }
 
+    /// Handle a replace instruction by watching for the replacement along with the transaction it
+    /// replaces.
+    fn handle_replace_ix(&mut self, tx_hash: B256, replacement: B256) {
+        // The transaction may have been received or reaped already.
+        let Some(watcher) = self.unconfirmed.get_mut(&tx_hash) else { return };
+        debug!(tx=%tx_hash, %replacement, "watching replacement");
+        watcher.replacements.push(replacement);
+        self.replacements.insert(replacement, tx_hash);
+
+        // The replacement may be received already, check the lookbehind history.
+        let received_at = self.past_blocks.iter().rev().find(|(_, txs)| txs.contains(&replacement));
+        if let Some(&(block_height, _)) = received_at {
+            let current_height = self.past_blocks.back().map(|(h, _)| *h).unwrap();
+            let watcher = self.unconfirmed.remove(&tx_hash).unwrap();
+            self.handle_received(watcher, replacement, block_height);
+            self.check_confirmations(current_height);
+        }
+    }
+
+    /// Handle the watched transaction, or one of its replacements, being received in a block.
+    fn handle_received(&mut self, mut watcher: TxWatcher, tx_hash: B256, block_height: u64) {
+        if tx_hash != watcher.config.tx_hash {
+            watcher.received_replacement = Some(tx_hash);
+        }
+
+        // If `confirmations` is not more than 1 we can notify the watcher immediately.
+        let confirmations = watcher.config.required_confirmations;
+        if confirmations <= 1 {
+            self.notify(watcher, Ok(()));
+            return;
+        }
+        // Otherwise add it to the waiting list.
+
+        // Set the block at which the transaction was received.
+        if let Some(set_block) = watcher.received_at_block {
+            warn!(tx=%watcher.config.tx_hash, set_block=%set_block, new_block=%block_height, "received_at_block already set");
+            // We don't override the set value.
+        } else {
+            watcher.received_at_block = Some(block_height);
+        }
+        self.add_to_waiting_list(watcher, block_height);
+    }
+
     fn add_to_waiting_list(&mut self, watcher: TxWatcher, block_height: u64) {
         let confirmations = watcher.config.required_confirmations;
         debug!(tx=%watcher.config.tx_hash, %block_height, confirmations, "adding to waiting list");
@@ -601,29 +969,18 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         }
         self.past_blocks.push_back((block_height, block.transactions().hashes().collect()));
 
-        // Check if we are watching for any of the transactions in this block.
+        // Check if we are watching for any of the transactions in this block, or for the
+        // transactions they replace.
         let to_check: Vec<_> = block
             .transactions()
             .hashes()
-            .filter_map(|tx_hash| self.unconfirmed.remove(&tx_hash))
+            .filter_map(|tx_hash| {
+                let watched = self.replacements.get(&tx_hash).unwrap_or(&tx_hash);
+                self.unconfirmed.remove(watched).map(|watcher| (watcher, tx_hash))
+            })
             .collect();
-        for mut watcher in to_check {
-            // If `confirmations` is not more than 1 we can notify the watcher immediately.
-            let confirmations = watcher.config.required_confirmations;
-            if confirmations <= 1 {
-                watcher.notify(Ok(()));
-                continue;
-            }
-            // Otherwise add it to the waiting list.
-
-            // Set the block at which the transaction was received.
-            if let Some(set_block) = watcher.received_at_block {
-                warn!(tx=%watcher.config.tx_hash, set_block=%set_block, new_block=%block_height, "received_at_block already set");
-                // We don't override the set value.
-            } else {
-                watcher.received_at_block = Some(block_height);
-            }
-            self.add_to_waiting_list(watcher, block_height);
+        for (watcher, tx_hash) in to_check {
+            self.handle_received(watcher, tx_hash, block_height);
         }
 
         self.check_confirmations(block_height);
@@ -666,7 +1023,7 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
     async fn into_future(
         mut self,
         latest: watch::Sender<Option<N::BlockResponse>>,
-        mut ixns: mpsc::Receiver<TxWatcher>,
+        mut ixns: mpsc::Receiver<WatchIx>,
     ) {
         'shutdown: loop {
             {
@@ -678,9 +1035,12 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
                 select! {
                     biased;
 
-                    // Watch for new transactions.
+                    // Watch for new transactions and their replacements.
                     ix_opt = ixns.recv() => match ix_opt {
-                        Some(to_watch) => self.handle_watch_ix(to_watch),
+                        Some(WatchIx::Watch(to_watch)) => self.handle_watch_ix(to_watch),
+                        Some(WatchIx::Replace { tx_hash, replacement }) => {
+                            self.handle_replace_ix(tx_hash, replacement)
+                        }
                         None => break 'shutdown, // ix channel is closed
                     },
 
@@ -700,3 +1060,133 @@ impl<N: Network, S: Stream<Item = N::BlockResponse> + Unpin + 'static> Heartbeat
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::Ethereum;
+    use alloy_primitives::b256;
+    use alloy_rpc_types_eth::{Block, BlockTransactions, Header, TransactionRequest};
+
+    fn block(number: u64, hashes: Vec<TxHash>) -> Block {
+        Block {
+            header: Header {
+                inner: alloy_consensus::Header { number, ..Default::default() },
+                ..Default::default()
+            },
+            transactions: BlockTransactions::Hashes(hashes),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn bump_fees() {
+        let policy = EscalationPolicy::new(3);
+
+        let mut legacy = TransactionRequest { gas_price: Some(100), ..Default::default() };
+        policy.bump_fees::<Ethereum>(&mut legacy);
+        assert_eq!(legacy.gas_price, Some(110));
+
+        let mut dynamic_fee =
+            TransactionRequest::default().max_fee_per_gas(1_000).max_priority_fee_per_gas(10);
+        policy.bump_fees::<Ethereum>(&mut dynamic_fee);
+        assert_eq!(dynamic_fee.max_fee_per_gas, Some(1_125));
+        assert_eq!(dynamic_fee.max_priority_fee_per_gas, Some(11));
+
+        // bumps round up
+        let mut cheap =
+            TransactionRequest::default().max_fee_per_gas(1).max_priority_fee_per_gas(1);
+        policy.bump_fees::<Ethereum>(&mut cheap);
+        assert_eq!(cheap.max_fee_per_gas, Some(2));
+        assert_eq!(cheap.max_priority_fee_per_gas, Some(2));
+
+        let policy = policy.with_fee_bump_percent(20);
+        policy.bump_fees::<Ethereum>(&mut legacy);
+        assert_eq!(legacy.gas_price, Some(132));
+        policy.bump_fees::<Ethereum>(&mut dynamic_fee);
+        assert_eq!(dynamic_fee.max_fee_per_gas, Some(1_350));
+        assert_eq!(dynamic_fee.max_priority_fee_per_gas, Some(14));
+
+        // bumps below the minimum are raised to it
+        let mut legacy = TransactionRequest { gas_price: Some(100), ..Default::default() };
+        policy.with_fee_bump_percent(1).bump_fees::<Ethereum>(&mut legacy);
+        assert_eq!(legacy.gas_price, Some(110));
+    }
+
+    #[tokio::test]
+    async fn resolves_with_replacement() {
+        let tx_hash = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let replacement = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+
+        let (blocks, stream) = futures::channel::mpsc::unbounded();
+        let heart = Heartbeat::<Ethereum, _>::new(stream).spawn();
+        blocks.unbounded_send(block(1, vec![])).unwrap();
+
+        let pending_tx =
+            heart.watch_tx(PendingTransactionConfig::new(tx_hash), None).await.unwrap();
+        heart.replace_tx(tx_hash, replacement).await.unwrap();
+        blocks.unbounded_send(block(2, vec![replacement])).unwrap();
+
+        assert_eq!(pending_tx.await.unwrap(), replacement);
+    }
+
+    #[tokio::test]
+    async fn replacement_in_lookbehind() {
+        let tx_hash = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+        let replacement = b256!("0000000000000000000000000000000000000000000000000000000000000002");
+
+        let (blocks, stream) = futures::channel::mpsc::unbounded();
+        let heart = Heartbeat::<Ethereum, _>::new(stream).spawn();
+        let mut latest = heart.latest().clone();
+
+        let pending_tx =
+            heart.watch_tx(PendingTransactionConfig::new(tx_hash), None).await.unwrap();
+        blocks.unbounded_send(block(1, vec![replacement])).unwrap();
+        latest.changed().await.unwrap();
+        heart.replace_tx(tx_hash, replacement).await.unwrap();
+
+        assert_eq!(pending_tx.await.unwrap(), replacement);
+    }
+
+    #[cfg(feature = "anvil-api")]
+    #[tokio::test]
+    async fn escalates_stuck_transaction() {
+        use crate::{ext::AnvilApi, ProviderBuilder, WalletProvider};
+        use alloy_primitives::{Address, U256};
+
+        let provider = ProviderBuilder::new()
+            .on_anvil_with_wallet_and_config(|anvil| anvil.arg("--no-mining"));
+        let max_fee_per_gas = 2_000_000_000;
+        let tx = TransactionRequest::default()
+            .to(Address::ZERO)
+            .value(U256::from(1))
+            .max_fee_per_gas(max_fee_per_gas)
+            .max_priority_fee_per_gas(1);
+        let pending_tx = provider.send_transaction(tx).await.unwrap();
+        let tx_hash = *pending_tx.tx_hash();
+        let pending_tx = pending_tx
+            .with_escalation(EscalationPolicy::new(1), provider.wallet().clone())
+            .register()
+            .await
+            .unwrap();
+
+        // The base fee is kept out of reach of the transaction, but not of its replacements.
+        let mine = async {
+            loop {
+                let base_fee = U256::from(max_fee_per_gas + max_fee_per_gas / 10);
+                provider.anvil_set_next_block_base_fee_per_gas(base_fee).await.unwrap();
+                provider.anvil_mine(Some(U256::from(1)), None).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        };
+        let included = select! {
+            included = pending_tx => included.unwrap(),
+            () = mine => unreachable!(),
+        };
+
+        assert_ne!(included, tx_hash);
+        let receipt = provider.get_transaction_receipt(included).await.unwrap().unwrap();
+        assert!(receipt.status());
+        assert!(provider.get_transaction_receipt(tx_hash).await.unwrap().is_none());
+    }
+}
@@ -1,18 +1,41 @@
-use crate::{ParamsWithBlock, Provider, ProviderCall, ProviderLayer, RootProvider, RpcWithBlock};
+use crate::{
+    Caller, EthCall, EthCallParams, ParamsWithBlock, Provider, ProviderCall, ProviderLayer,
+    RootProvider, RpcWithBlock,
+};
 use alloy_eips::BlockId;
-use alloy_json_rpc::{RpcError, RpcObject, RpcParam};
-use alloy_network::Network;
+use alloy_json_rpc::{RpcError, RpcObject, RpcParam, RpcReturn};
+use alloy_network::{Network, ReceiptResponse, TransactionResponse};
 use alloy_primitives::{
-    keccak256, Address, BlockHash, Bytes, StorageKey, StorageValue, TxHash, B256, U256, U64,
+    keccak256, map::B256HashMap, Address, BlockHash, Bytes, StorageKey, StorageValue, TxHash, B256,
+    U256, U64,
 };
+use alloy_rpc_client::{RpcClientInner, WeakClient};
 use alloy_rpc_types_eth::{
-    BlockNumberOrTag, BlockTransactionsKind, EIP1186AccountProofResponse, Filter, Log,
+    BlockNumberOrTag, BlockTransactionsKind, EIP1186AccountProofResponse, Filter,
+    FilterBlockOption, Log,
 };
 use alloy_transport::{Transport, TransportErrorKind, TransportResult};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use schnellru::{ByLength, LruMap};
 use serde::{Deserialize, Serialize};
-use std::{io::BufReader, marker::PhantomData, path::PathBuf, sync::Arc};
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 /// A provider layer that caches RPC responses and serves them on subsequent requests.
 ///
@@ -21,6 +44,10 @@ use std::{io::BufReader, marker::PhantomData, path::PathBuf, sync::Arc};
 ///
 /// One can load the cache from the file system by calling `load_cache` and save the cache to the
 /// file system by calling `save_cache`.
+///
+/// Responses read at a block hash are cached forever. Responses read at a block number are cached
+/// forever only if the block is final, and otherwise expire after the cache TTL, so that a reorg
+/// cannot leave stale data in the cache. Responses read at a block tag are never cached.
 #[derive(Debug, Clone)]
 pub struct CacheLayer {
     /// In-memory LRU cache, mapping requests to responses.
@@ -34,6 +61,11 @@ impl CacheLayer {
         Self { cache: SharedCache::new(max_items) }
     }
 
+    /// Instantiate a new cache layer backed by the given shared cache.
+    pub const fn with_cache(cache: SharedCache) -> Self {
+        Self { cache }
+    }
+
     /// Returns the maximum number of items that can be stored in the cache, set at initialization.
     pub const fn max_items(&self) -> u32 {
         self.cache.max_items()
@@ -85,13 +117,19 @@ where
     pub const fn new(inner: P, cache: SharedCache) -> Self {
         Self { inner, cache, _pd: PhantomData }
     }
+
+    /// Returns the shared cache.
+    pub fn cache(&self) -> SharedCache {
+        self.cache.clone()
+    }
 }
 
 /// Uses underlying transport client to fetch data from the RPC.
 ///
 /// This is specific to RPC requests that require the `block_id` parameter.
 ///
-/// Fetches from the RPC and saves the response to the cache.
+/// Fetches from the RPC and saves the response to the cache, if it was read at a block that
+/// allows caching it.
 ///
 /// Returns a ProviderCall::BoxedFuture
 macro_rules! rpc_call_with_block {
@@ -108,9 +146,11 @@ macro_rules! rpc_call_with_block {
 
             let res = result.await?;
             // Insert into cache.
-            let json_str = serde_json::to_string(&res).map_err(TransportErrorKind::custom)?;
-            let hash = $req.params_hash()?;
-            let _ = cache.put(hash, json_str);
+            if let Some(cached_at) = $req.cached_at() {
+                let json_str = serde_json::to_string(&res).map_err(TransportErrorKind::custom)?;
+                let hash = $req.params_hash()?;
+                let _ = put_response(&cache, &client, hash, json_str, cached_at).await;
+            }
 
             Ok(res)
         }))
@@ -124,7 +164,7 @@ macro_rules! rpc_call_with_block {
 /// This helps overriding [`Provider`] methods that return `RpcWithBlock`.
 macro_rules! cache_rpc_call_with_block {
     ($cache:expr, $client:expr, $req:expr) => {{
-        if $req.has_block_tag() {
+        if $req.cached_at().is_none() {
             return rpc_call_with_block!($cache, $client, $req);
         }
 
@@ -153,11 +193,29 @@ where
         self.inner.root()
     }
 
+    fn call<'req>(&self, tx: &'req N::TransactionRequest) -> EthCall<'req, T, N, Bytes> {
+        let caller = CacheCaller { client: self.inner.weak_client(), cache: self.cache.clone() };
+        EthCall::new(caller, tx).block(BlockNumberOrTag::Pending.into())
+    }
+
+    fn get_balance(&self, address: Address) -> RpcWithBlock<T, Address, U256, U256> {
+        let client = self.inner.weak_client();
+        let cache = self.cache.clone();
+        RpcWithBlock::new_provider(move |block_id| {
+            let req = RequestType::new("eth_getBalance", address).with_block_id(block_id);
+            cache_rpc_call_with_block!(cache, client, req)
+        })
+    }
+
     async fn get_block_by_number(
         &self,
         number: BlockNumberOrTag,
         kind: BlockTransactionsKind,
     ) -> TransportResult<Option<N::BlockResponse>> {
+        let BlockNumberOrTag::Number(block_number) = number else {
+            return self.inner.get_block_by_number(number, kind).await;
+        };
+
         let full = match kind {
             BlockTransactionsKind::Full => true,
             BlockTransactionsKind::Hashes => false,
@@ -165,7 +223,14 @@ where
 
         let req = RequestType::new("eth_getBlockByNumber", (number, full));
 
-        cache_get_or_fetch(&self.cache, req, self.inner.get_block_by_number(number, kind)).await
+        cache_get_or_fetch(
+            &self.cache,
+            self.inner.client(),
+            req,
+            CachedAt::Block(block_number),
+            self.inner.get_block_by_number(number, kind),
+        )
+        .await
     }
 
     async fn get_block_by_hash(
@@ -180,47 +245,27 @@ where
 
         let req = RequestType::new("eth_getBlockByHash", (hash, full));
 
-        cache_get_or_fetch(&self.cache, req, self.inner.get_block_by_hash(hash, kind)).await
+        cache_get_or_fetch(
+            &self.cache,
+            self.inner.client(),
+            req,
+            CachedAt::Immutable,
+            self.inner.get_block_by_hash(hash, kind),
+        )
+        .await
     }
 
     fn get_block_receipts(
         &self,
         block: BlockId,
     ) -> ProviderCall<T, (BlockId,), Option<Vec<N::ReceiptResponse>>> {
-        let req = RequestType::new("eth_getBlockReceipts", (block,));
-
-        let redirect =
-            !matches!(block, BlockId::Hash(_) | BlockId::Number(BlockNumberOrTag::Number(_)));
-
-        if !redirect {
-            let params_hash = req.params_hash().ok();
-
-            if let Some(hash) = params_hash {
-                if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                    return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-                }
-            }
-        }
-
-        let client = self.inner.weak_client();
-        let cache = self.cache.clone();
-
-        ProviderCall::BoxedFuture(Box::pin(async move {
-            let client = client
-                .upgrade()
-                .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
+        let Some(cached_at) = CachedAt::from_block_id(block) else {
+            return self.inner.get_block_receipts(block);
+        };
 
-            if !redirect {
-                let hash = req.params_hash()?;
-                let _ = cache.put(hash, json_str);
-            }
+        let req = RequestType::new("eth_getBlockReceipts", (block,));
 
-            Ok(result)
-        }))
+        cached_provider_call(&self.cache, self.inner.weak_client(), req, move |_| Some(cached_at))
     }
 
     fn get_proof(
@@ -270,6 +315,20 @@ where
     }
 
     async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>> {
+        // Logs can only be cached if the filter is pinned to a block hash or ends at a block
+        // number.
+        let cached_at = match filter.block_option {
+            FilterBlockOption::AtBlockHash(_) => Some(CachedAt::Immutable),
+            FilterBlockOption::Range {
+                from_block: Some(BlockNumberOrTag::Number(_) | BlockNumberOrTag::Earliest),
+                to_block: Some(BlockNumberOrTag::Number(to_block)),
+            } => Some(CachedAt::Block(to_block)),
+            FilterBlockOption::Range { .. } => None,
+        };
+        let Some(cached_at) = cached_at else {
+            return self.inner.get_logs(filter).await;
+        };
+
         let req = RequestType::new("eth_getLogs", filter.clone());
 
         let params_hash = req.params_hash().ok();
@@ -285,7 +344,7 @@ where
         let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
 
         let hash = req.params_hash()?;
-        let _ = self.cache.put(hash, json_str);
+        let _ = put_response(&self.cache, self.inner.client(), hash, json_str, cached_at).await;
 
         Ok(result)
     }
@@ -296,27 +355,13 @@ where
     ) -> ProviderCall<T, (TxHash,), Option<N::TransactionResponse>> {
         let req = RequestType::new("eth_getTransactionByHash", (hash,));
 
-        let params_hash = req.params_hash().ok();
-
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
-        let client = self.inner.weak_client();
-        let cache = self.cache.clone();
-        ProviderCall::BoxedFuture(Box::pin(async move {
-            let client = client
-                .upgrade()
-                .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
-
-            Ok(result)
-        }))
+        // Pending transactions are not cached, as they have no block yet.
+        cached_provider_call(
+            &self.cache,
+            self.inner.weak_client(),
+            req,
+            |tx: &Option<N::TransactionResponse>| tx.as_ref()?.block_number().map(CachedAt::Block),
+        )
     }
 
     fn get_raw_transaction_by_hash(
@@ -325,29 +370,10 @@ where
     ) -> ProviderCall<T, (TxHash,), Option<Bytes>> {
         let req = RequestType::new("eth_getRawTransactionByHash", (hash,));
 
-        let params_hash = req.params_hash().ok();
-
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
-
-        let client = self.inner.weak_client();
-        let cache = self.cache.clone();
-        ProviderCall::BoxedFuture(Box::pin(async move {
-            let client = client
-                .upgrade()
-                .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
-
-            let result = client.request(req.method(), req.params()).await?;
-
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
-
-            Ok(result)
-        }))
+        // The encoding of a transaction is fixed by its hash.
+        cached_provider_call(&self.cache, self.inner.weak_client(), req, |tx: &Option<Bytes>| {
+            tx.as_ref().map(|_| CachedAt::Immutable)
+        })
     }
 
     fn get_transaction_receipt(
@@ -356,29 +382,71 @@ where
     ) -> ProviderCall<T, (TxHash,), Option<N::ReceiptResponse>> {
         let req = RequestType::new("eth_getTransactionReceipt", (hash,));
 
-        let params_hash = req.params_hash().ok();
+        cached_provider_call(
+            &self.cache,
+            self.inner.weak_client(),
+            req,
+            |receipt: &Option<N::ReceiptResponse>| {
+                receipt.as_ref()?.block_number().map(CachedAt::Block)
+            },
+        )
+    }
+}
 
-        if let Some(hash) = params_hash {
-            if let Ok(Some(cached)) = self.cache.get_deserialized(&hash) {
-                return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
-            }
-        }
+/// A [`Caller`] that serves `eth_call` requests made at a pinned block from the cache.
+///
+/// Gas estimates are never cached.
+struct CacheCaller<T> {
+    client: WeakClient<T>,
+    cache: SharedCache,
+}
 
-        let client = self.inner.weak_client();
-        let cache = self.cache.clone();
-        ProviderCall::BoxedFuture(Box::pin(async move {
-            let client = client
-                .upgrade()
-                .ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
+impl<T, N, Resp> Caller<T, N, Resp> for CacheCaller<T>
+where
+    T: Transport + Clone,
+    N: Network,
+    Resp: RpcReturn + Serialize,
+{
+    fn call(
+        &self,
+        params: EthCallParams<'_, N>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>> {
+        let Some(cached_at) = params.block().and_then(CachedAt::from_block_id) else {
+            return Caller::<T, N, Resp>::call(&self.client, params);
+        };
 
-            let result = client.request(req.method(), req.params()).await?;
+        let req = RequestType::new("eth_call", params.into_owned());
 
-            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
-            let hash = req.params_hash()?;
-            let _ = cache.put(hash, json_str);
+        Ok(cached_provider_call(&self.cache, self.client.clone(), req, move |_| Some(cached_at)))
+    }
 
-            Ok(result)
-        }))
+    fn estimate_gas(
+        &self,
+        params: EthCallParams<'_, N>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>> {
+        Caller::<T, N, Resp>::estimate_gas(&self.client, params)
+    }
+}
+
+/// The block a response was read at, which decides how long it can be cached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CachedAt {
+    /// The response can never change, e.g. because it was read at a block hash.
+    Immutable,
+    /// The response was read at the block with the given number, and can change if that block is
+    /// reorged.
+    Block(u64),
+}
+
+impl CachedAt {
+    /// Returns how a response read at the given block can be cached, or `None` if it cannot be,
+    /// e.g. because it was read at the latest block.
+    const fn from_block_id(block_id: BlockId) -> Option<Self> {
+        match block_id {
+            BlockId::Hash(_) => Some(Self::Immutable),
+            BlockId::Number(BlockNumberOrTag::Number(number)) => Some(Self::Block(number)),
+            BlockId::Number(_) => None,
+        }
     }
 }
 
@@ -416,16 +484,11 @@ impl<Params: RpcParam> RequestType<Params> {
         self.params.clone()
     }
 
-    /// Returns true if the BlockId has been set to a tag value such as "latest", "earliest", or
-    /// "pending".
-    const fn has_block_tag(&self) -> bool {
-        if let Some(block_id) = self.block_id {
-            match block_id {
-                BlockId::Hash(_) | BlockId::Number(BlockNumberOrTag::Number(_)) => return false,
-                _ => return true,
-            }
-        }
-        false
+    /// Returns how the response can be cached, or `None` if the BlockId has been set to a tag
+    /// value such as "latest", "earliest", or "pending", or has not been set at all, in which
+    /// case the node reads at the latest block.
+    fn cached_at(&self) -> Option<CachedAt> {
+        self.block_id.and_then(CachedAt::from_block_id)
     }
 }
 
@@ -436,18 +499,436 @@ struct FsCacheEntry {
     /// Serialized response to the request from which the hash was computed.
     value: String,
 }
+
+/// A response stored in a [`CacheStore`].
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+    /// Serialized response.
+    value: String,
+    /// Time after which the response must be fetched again, or `None` if it never changes.
+    expires_at: Option<Instant>,
+}
+
+impl CacheEntry {
+    /// Creates an entry for a response that never changes.
+    pub const fn immutable(value: String) -> Self {
+        Self { value, expires_at: None }
+    }
+
+    /// Creates an entry for a response that may change, and expires after the given TTL.
+    pub fn expiring(value: String, ttl: Duration) -> Self {
+        Self { value, expires_at: Some(Instant::now() + ttl) }
+    }
+
+    /// Returns the serialized response.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Consumes the entry, returning the serialized response.
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    /// Returns true if the response never changes.
+    pub const fn is_immutable(&self) -> bool {
+        self.expires_at.is_none()
+    }
+
+    /// Returns true if the response has expired and must be fetched again.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= Instant::now())
+    }
+}
+
+/// A storage backend for a [`SharedCache`], mapping request hashes to responses.
+///
+/// Stores need not persist entries that are not [immutable](CacheEntry::is_immutable), since
+/// they expire anyway.
+pub trait CacheStore: fmt::Debug + Send + Sync {
+    /// Gets the entry stored for the key, if any.
+    fn get(&self, key: &B256) -> Option<CacheEntry>;
+
+    /// Stores the entry for the key, and returns true if it replaced an existing entry.
+    fn put(&self, key: B256, entry: CacheEntry) -> TransportResult<bool>;
+
+    /// Removes the entry stored for the key, if any.
+    fn remove(&self, key: &B256);
+
+    /// Atomically removes the entry stored for the key if `predicate` returns true for it, and
+    /// returns true if it was removed.
+    ///
+    /// Unlike a [`get`](Self::get) followed by a [`remove`](Self::remove), this does not remove an
+    /// entry put for the key in between.
+    fn remove_if(&self, key: &B256, predicate: &dyn Fn(&CacheEntry) -> bool) -> bool;
+
+    /// Returns all the stored entries.
+    fn entries(&self) -> Vec<(B256, CacheEntry)>;
+
+    /// Maximum number of entries that can be stored, or `u32::MAX` if unbounded.
+    fn max_items(&self) -> u32 {
+        u32::MAX
+    }
+}
+
+/// An in-memory [`CacheStore`] that evicts the least recently used entries once full.
+#[derive(Debug)]
+pub struct MemoryCacheStore {
+    inner: RwLock<LruMap<B256, CacheEntry>>,
+    max_items: u32,
+}
+
+impl MemoryCacheStore {
+    /// Instantiate a new in-memory store with the maximum number of items to store.
+    pub fn new(max_items: u32) -> Self {
+        Self { inner: RwLock::new(LruMap::new(ByLength::new(max_items))), max_items }
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get(&self, key: &B256) -> Option<CacheEntry> {
+        // Need to acquire a write guard to change the order of keys in LRU cache.
+        self.inner.write().get(key).cloned()
+    }
+
+    fn put(&self, key: B256, entry: CacheEntry) -> TransportResult<bool> {
+        Ok(self.inner.write().insert(key, entry))
+    }
+
+    fn remove(&self, key: &B256) {
+        self.inner.write().remove(key);
+    }
+
+    fn remove_if(&self, key: &B256, predicate: &dyn Fn(&CacheEntry) -> bool) -> bool {
+        let mut inner = self.inner.write();
+        let remove = inner.peek(key).is_some_and(predicate);
+        if remove {
+            inner.remove(key);
+        }
+        remove
+    }
+
+    fn entries(&self) -> Vec<(B256, CacheEntry)> {
+        self.inner.read().iter().map(|(key, entry)| (*key, entry.clone())).collect()
+    }
+
+    fn max_items(&self) -> u32 {
+        self.max_items
+    }
+}
+
+/// Location of the line of an immutable entry in the file of a [`DiskCacheStore`].
+#[derive(Clone, Copy, Debug)]
+struct LineSpan {
+    /// Offset of the line in the file.
+    offset: u64,
+    /// Length of the line, including the newline.
+    len: u64,
+}
+
+/// In-memory state of a [`DiskCacheStore`].
+#[derive(Debug)]
+struct DiskIndex {
+    /// Most recently used entries.
+    entries: LruMap<B256, CacheEntry>,
+    /// Lines of the immutable entries in the file.
+    lines: B256HashMap<LineSpan>,
+    /// Number of lines of the file that are not in `lines`.
+    stale_lines: usize,
+}
+
+impl DiskIndex {
+    fn remove(&mut self, key: &B256) {
+        self.entries.remove(key);
+        if self.lines.remove(key).is_some() {
+            self.stale_lines += 1;
+        }
+    }
+}
+
+/// An on-disk [`CacheStore`] that appends immutable entries to a file, one JSON object per line.
+///
+/// Only the location of the entries in the file is kept in memory, along with at most
+/// [`memory_items`](Self::memory_items) recently used entries, other entries being read from the
+/// file when needed. Entries that are not immutable are only kept in memory, and removing an
+/// entry only removes it from the file once the store is [compacted](Self::compact).
+#[derive(Debug)]
+pub struct DiskCacheStore {
+    path: PathBuf,
+    file: Mutex<File>,
+    index: RwLock<DiskIndex>,
+    memory_items: u32,
+}
+
+impl DiskCacheStore {
+    /// Default maximum number of entries kept in memory.
+    pub const DEFAULT_MEMORY_ITEMS: u32 = 10_000;
+
+    /// Opens the store in the file at the given path, keeping at most
+    /// [`DEFAULT_MEMORY_ITEMS`](Self::DEFAULT_MEMORY_ITEMS) entries in memory, see
+    /// [`open_with_memory_items`](Self::open_with_memory_items).
+    pub fn open(path: impl Into<PathBuf>) -> TransportResult<Self> {
+        Self::open_with_memory_items(path, Self::DEFAULT_MEMORY_ITEMS)
+    }
+
+    /// Opens the store in the file at the given path, creating the file if it does not exist, and
+    /// indexes the entries it contains, keeping at most `memory_items` entries in memory.
+    ///
+    /// Lines that cannot be parsed, e.g. one left partially written by a crash, are skipped. The
+    /// file is compacted if it contains such lines, or several lines for the same key.
+    pub fn open_with_memory_items(
+        path: impl Into<PathBuf>,
+        memory_items: u32,
+    ) -> TransportResult<Self> {
+        let path = path.into();
+        let file = Self::open_file(&path)?;
+
+        let mut lines = B256HashMap::default();
+        let mut stale_lines = 0;
+        let mut reader = BufReader::new(&file);
+        let mut line = String::new();
+        let mut offset = 0;
+        loop {
+            line.clear();
+            let len = reader.read_line(&mut line).map_err(TransportErrorKind::custom)? as u64;
+            if len == 0 {
+                break;
+            }
+            let span = LineSpan { offset, len };
+            offset += len;
+            match serde_json::from_str::<FsCacheEntry>(&line) {
+                Ok(entry) if line.ends_with('\n') => {
+                    stale_lines += usize::from(lines.insert(entry.key, span).is_some());
+                }
+                _ => stale_lines += 1,
+            }
+        }
+
+        let index =
+            DiskIndex { entries: LruMap::new(ByLength::new(memory_items)), lines, stale_lines };
+        let store = Self { path, file: Mutex::new(file), index: RwLock::new(index), memory_items };
+        if stale_lines > 0 {
+            store.compact()?;
+        }
+        Ok(store)
+    }
+
+    /// Returns the path of the file backing the store.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the maximum number of entries kept in memory.
+    pub const fn memory_items(&self) -> u32 {
+        self.memory_items
+    }
+
+    /// Rewrites the file with the lines of the immutable entries of the store, dropping the lines
+    /// of removed entries and the lines that cannot be parsed, and evicts expired entries from
+    /// memory.
+    ///
+    /// The new file replaces the old one only once fully written, so that a crash cannot lose
+    /// entries.
+    pub fn compact(&self) -> TransportResult<()> {
+        let mut index = self.index.write();
+        let mut file = self.file.lock();
+
+        let expired: Vec<B256> = index
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.is_expired())
+            .map(|(key, _)| *key)
+            .collect();
+        for key in &expired {
+            index.entries.remove(key);
+        }
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let mut tmp = File::create(&tmp_path).map_err(TransportErrorKind::custom)?;
+        let mut lines = B256HashMap::default();
+        let mut offset = 0;
+        for (key, span) in &index.lines {
+            let line = Self::read_span(&mut file, *span)?;
+            tmp.write_all(&line).map_err(TransportErrorKind::custom)?;
+            lines.insert(*key, LineSpan { offset, len: span.len });
+            offset += span.len;
+        }
+        tmp.sync_all().map_err(TransportErrorKind::custom)?;
+        std::fs::rename(&tmp_path, &self.path).map_err(TransportErrorKind::custom)?;
+
+        *file = Self::open_file(&self.path)?;
+        index.lines = lines;
+        index.stale_lines = 0;
+        Ok(())
+    }
+
+    fn open_file(path: &PathBuf) -> TransportResult<File> {
+        OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .map_err(TransportErrorKind::custom)
+    }
+
+    /// Serializes the entry to a line of the file.
+    fn line(key: B256, entry: &CacheEntry) -> TransportResult<String> {
+        let mut line = serde_json::to_string(&FsCacheEntry { key, value: entry.value.clone() })
+            .map_err(TransportErrorKind::custom)?;
+        line.push('\n');
+        Ok(line)
+    }
+
+    /// Reads the line at the given location of the file.
+    fn read_span(file: &mut File, span: LineSpan) -> TransportResult<Vec<u8>> {
+        let mut line = vec![0; span.len as usize];
+        file.seek(SeekFrom::Start(span.offset)).map_err(TransportErrorKind::custom)?;
+        file.read_exact(&mut line).map_err(TransportErrorKind::custom)?;
+        Ok(line)
+    }
+
+    /// Reads the entry at the given location of the file.
+    fn read_entry(&self, span: LineSpan) -> TransportResult<CacheEntry> {
+        let line = Self::read_span(&mut self.file.lock(), span)?;
+        let entry =
+            serde_json::from_slice::<FsCacheEntry>(&line).map_err(TransportErrorKind::custom)?;
+        Ok(CacheEntry::immutable(entry.value))
+    }
+}
+
+impl CacheStore for DiskCacheStore {
+    fn get(&self, key: &B256) -> Option<CacheEntry> {
+        // Need to acquire a write guard to change the order of keys in LRU cache.
+        let mut index = self.index.write();
+        if let Some(entry) = index.entries.get(key) {
+            return Some(entry.clone());
+        }
+        let span = *index.lines.get(key)?;
+        let entry = self.read_entry(span).ok()?;
+        index.entries.insert(*key, entry.clone());
+        Some(entry)
+    }
+
+    fn put(&self, key: B256, entry: CacheEntry) -> TransportResult<bool> {
+        let mut index = self.index.write();
+        // An immutable entry is already in the file, and never changes.
+        let stored_immutable = index.lines.contains_key(&key);
+        let replaced = stored_immutable || index.entries.peek(&key).is_some();
+        if entry.is_immutable() && !stored_immutable {
+            let line = Self::line(key, &entry)?;
+            let mut file = self.file.lock();
+            let offset = file.seek(SeekFrom::End(0)).map_err(TransportErrorKind::custom)?;
+            file.write_all(line.as_bytes()).map_err(TransportErrorKind::custom)?;
+            index.lines.insert(key, LineSpan { offset, len: line.len() as u64 });
+        }
+        index.entries.insert(key, entry);
+        Ok(replaced)
+    }
+
+    fn remove(&self, key: &B256) {
+        self.index.write().remove(key);
+    }
+
+    fn remove_if(&self, key: &B256, predicate: &dyn Fn(&CacheEntry) -> bool) -> bool {
+        let mut index = self.index.write();
+        let remove = match index.entries.peek(key) {
+            Some(entry) => predicate(entry),
+            None => index
+                .lines
+                .get(key)
+                .is_some_and(|span| self.read_entry(*span).is_ok_and(|entry| predicate(&entry))),
+        };
+        if remove {
+            index.remove(key);
+        }
+        remove
+    }
+
+    fn entries(&self) -> Vec<(B256, CacheEntry)> {
+        let index = self.index.read();
+        let mut entries: Vec<_> =
+            index.entries.iter().map(|(key, entry)| (*key, entry.clone())).collect();
+        for (key, span) in &index.lines {
+            if index.entries.peek(key).is_none() {
+                if let Ok(entry) = self.read_entry(*span) {
+                    entries.push((*key, entry));
+                }
+            }
+        }
+        entries
+    }
+}
+
+/// Hit and miss counts of a [`SharedCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups served from the cache.
+    pub hits: u64,
+    /// Number of lookups that found no entry, or an expired one.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns the fraction of lookups served from the cache, or 0 if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
 /// Shareable cache.
+///
+/// Tracks the latest finalized block, so that responses read at a final block are cached
+/// forever, and responses read at a more recent block expire after the TTL.
 #[derive(Debug, Clone)]
 pub struct SharedCache {
-    inner: Arc<RwLock<LruMap<B256, String>>>,
+    store: Arc<dyn CacheStore>,
+    /// Maximum number of entries of the store.
     max_items: u32,
+    ttl: Duration,
+    /// Number of the latest known finalized block. The genesis block is always final.
+    finalized: Arc<AtomicU64>,
+    /// Last time the finalized block was fetched from the RPC.
+    finalized_fetched_at: Arc<Mutex<Option<Instant>>>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
 }
 
 impl SharedCache {
-    /// Instantiate a new shared cache.
+    /// Default time after which responses read at a block that is not final expire.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(12);
+
+    /// Instantiate a new shared cache, storing at most `max_items` in memory.
     pub fn new(max_items: u32) -> Self {
-        let inner = Arc::new(RwLock::new(LruMap::new(ByLength::new(max_items))));
-        Self { inner, max_items }
+        Self::with_store(MemoryCacheStore::new(max_items))
+    }
+
+    /// Instantiate a new shared cache backed by the given store.
+    pub fn with_store(store: impl CacheStore + 'static) -> Self {
+        Self {
+            max_items: store.max_items(),
+            store: Arc::new(store),
+            ttl: Self::DEFAULT_TTL,
+            finalized: Arc::default(),
+            finalized_fetched_at: Arc::default(),
+            hits: Arc::default(),
+            misses: Arc::default(),
+        }
+    }
+
+    /// Sets the time after which responses read at a block that is not final expire.
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Time after which responses read at a block that is not final expire.
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
     }
 
     /// Maximum number of items that can be stored in the cache.
@@ -455,15 +936,59 @@ impl SharedCache {
         self.max_items
     }
 
-    /// Puts a value into the cache, and returns the old value if it existed.
+    /// Returns the number of the latest known finalized block.
+    pub fn finalized_block(&self) -> u64 {
+        self.finalized.load(Ordering::Relaxed)
+    }
+
+    /// Records that the block with the given number is finalized. Never moves the finalized block
+    /// backwards.
+    pub fn set_finalized_block(&self, number: u64) {
+        self.finalized.fetch_max(number, Ordering::Relaxed);
+    }
+
+    /// Returns the hit and miss counts of the cache.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Puts a value that never changes into the cache, and returns true if it replaced an
+    /// existing value.
     pub fn put(&self, key: B256, value: String) -> TransportResult<bool> {
-        Ok(self.inner.write().insert(key, value))
+        self.store.put(key, CacheEntry::immutable(value))
     }
 
-    /// Gets a value from the cache, if it exists.
+    /// Puts a value read at the block with the given number into the cache, and returns true if it
+    /// replaced an existing value.
+    ///
+    /// The value never expires if the block is final, and expires after the TTL otherwise.
+    pub fn put_at_block(&self, key: B256, value: String, number: u64) -> TransportResult<bool> {
+        let entry = if number <= self.finalized_block() {
+            CacheEntry::immutable(value)
+        } else {
+            CacheEntry::expiring(value, self.ttl)
+        };
+        self.store.put(key, entry)
+    }
+
+    /// Gets a value from the cache, if it exists and has not expired.
     pub fn get(&self, key: &B256) -> Option<String> {
-        // Need to acquire a write guard to change the order of keys in LRU cache.
-        self.inner.write().get(key).cloned()
+        let entry = self.store.get(key).filter(|entry| {
+            let expired = entry.is_expired();
+            if expired {
+                // The entry may have been replaced by a fresh one in the meantime.
+                self.store.remove_if(key, &CacheEntry::is_expired);
+            }
+            !expired
+        });
+
+        let counter = if entry.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        entry.map(CacheEntry::into_value)
     }
 
     /// Get deserialized value from the cache.
@@ -476,18 +1001,18 @@ impl SharedCache {
         Ok(Some(result))
     }
 
-    /// Saves the cache to a file specified by the path.
+    /// Saves the immutable entries of the cache to a file specified by the path.
     /// If the files does not exist, it creates one.
     /// If the file exists, it overwrites it.
     pub fn save_cache(&self, path: PathBuf) -> TransportResult<()> {
-        let entries: Vec<FsCacheEntry> = {
-            self.inner
-                .read()
-                .iter()
-                .map(|(key, value)| FsCacheEntry { key: *key, value: value.clone() })
-                .collect()
-        };
-        let file = std::fs::File::create(path).map_err(TransportErrorKind::custom)?;
+        let entries: Vec<FsCacheEntry> = self
+            .store
+            .entries()
+            .into_iter()
+            .filter(|(_, entry)| entry.is_immutable())
+            .map(|(key, entry)| FsCacheEntry { key, value: entry.into_value() })
+            .collect();
+        let file = File::create(path).map_err(TransportErrorKind::custom)?;
         serde_json::to_writer(file, &entries).map_err(TransportErrorKind::custom)?;
         Ok(())
     }
@@ -498,17 +1023,63 @@ impl SharedCache {
         if !path.exists() {
             return Ok(());
         };
-        let file = std::fs::File::open(path).map_err(TransportErrorKind::custom)?;
+        let file = File::open(path).map_err(TransportErrorKind::custom)?;
         let file = BufReader::new(file);
         let entries: Vec<FsCacheEntry> =
             serde_json::from_reader(file).map_err(TransportErrorKind::custom)?;
-        let mut cache = self.inner.write();
         for entry in entries {
-            cache.insert(entry.key, entry.value);
+            self.put(entry.key, entry.value)?;
         }
 
         Ok(())
     }
+
+    /// Returns true if the finalized block should be fetched from the RPC, which is done at most
+    /// once per TTL.
+    fn should_fetch_finalized(&self) -> bool {
+        let mut fetched_at = self.finalized_fetched_at.lock();
+        let now = Instant::now();
+        if fetched_at.is_some_and(|fetched_at| now < fetched_at + self.ttl) {
+            return false;
+        }
+        *fetched_at = Some(now);
+        true
+    }
+}
+
+/// Number of a block returned by `eth_getBlockByNumber`, ignoring all other fields.
+#[derive(Debug, Deserialize)]
+struct BlockNumberOnly {
+    number: U64,
+}
+
+/// Puts a response read at the given block into the cache.
+///
+/// If the block is more recent than the latest known finalized block, fetches the finalized
+/// block first, so that a response read at a block that has since become final is not stored as
+/// expiring. Nodes that do not support the `finalized` tag are tolerated, in which case only
+/// responses read at the genesis block or a block hash are cached forever.
+async fn put_response<T: Transport + Clone>(
+    cache: &SharedCache,
+    client: &RpcClientInner<T>,
+    key: B256,
+    value: String,
+    cached_at: CachedAt,
+) -> TransportResult<bool> {
+    let number = match cached_at {
+        CachedAt::Immutable => return cache.put(key, value),
+        CachedAt::Block(number) => number,
+    };
+
+    if number > cache.finalized_block() && cache.should_fetch_finalized() {
+        let finalized: TransportResult<Option<BlockNumberOnly>> =
+            client.request("eth_getBlockByNumber", (BlockNumberOrTag::Finalized, false)).await;
+        if let Ok(Some(block)) = finalized {
+            cache.set_finalized_block(block.number.to());
+        }
+    }
+
+    cache.put_at_block(key, value, number)
 }
 
 /// Attempts to fetch the response from the cache by using the hash of the request params.
@@ -516,9 +1087,11 @@ impl SharedCache {
 /// In case of a cache miss, fetches from the RPC and saves the response to the cache.
 ///
 /// This helps overriding [`Provider`] methods that return [`TransportResult<T>`].
-async fn cache_get_or_fetch<Params: RpcParam, Resp: RpcObject>(
+async fn cache_get_or_fetch<T: Transport + Clone, Params: RpcParam, Resp: RpcObject>(
     cache: &SharedCache,
+    client: &RpcClientInner<T>,
     req: RequestType<Params>,
+    cached_at: CachedAt,
     fetch_fn: impl std::future::Future<Output = TransportResult<Option<Resp>>>,
 ) -> TransportResult<Option<Resp>> {
     let hash = req.params_hash()?;
@@ -529,12 +1102,54 @@ async fn cache_get_or_fetch<Params: RpcParam, Resp: RpcObject>(
     let result = fetch_fn.await?;
     if let Some(ref data) = result {
         let json_str = serde_json::to_string(data).map_err(TransportErrorKind::custom)?;
-        let _ = cache.put(hash, json_str)?;
+        let _ = put_response(cache, client, hash, json_str, cached_at).await?;
     }
 
     Ok(result)
 }
 
+/// Attempts to fetch the response from the cache by using the hash of the request params.
+///
+/// In case of a cache miss, fetches from the RPC and saves the response to the cache, unless
+/// `cached_at` returns `None` for it.
+///
+/// This helps overriding [`Provider`] methods that return [`ProviderCall`].
+fn cached_provider_call<T, Params, Resp>(
+    cache: &SharedCache,
+    client: WeakClient<T>,
+    req: RequestType<Params>,
+    cached_at: impl FnOnce(&Resp) -> Option<CachedAt> + Send + 'static,
+) -> ProviderCall<T, Params, Resp>
+where
+    T: Transport + Clone,
+    Params: RpcParam + 'static,
+    Resp: RpcReturn + Serialize,
+{
+    let params_hash = req.params_hash().ok();
+
+    if let Some(hash) = params_hash {
+        if let Ok(Some(cached)) = cache.get_deserialized(&hash) {
+            return ProviderCall::BoxedFuture(Box::pin(async move { Ok(cached) }));
+        }
+    }
+
+    let cache = cache.clone();
+    ProviderCall::BoxedFuture(Box::pin(async move {
+        let client =
+            client.upgrade().ok_or_else(|| TransportErrorKind::custom_str("RPC client dropped"))?;
+
+        let result = client.request(req.method(), req.params()).await?;
+
+        if let Some(cached_at) = cached_at(&result) {
+            let json_str = serde_json::to_string(&result).map_err(TransportErrorKind::custom)?;
+            let hash = req.params_hash()?;
+            let _ = put_response(&cache, &client, hash, json_str, cached_at).await;
+        }
+
+        Ok(result)
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -756,4 +1371,137 @@ mod tests {
         })
         .await;
     }
+
+    #[test]
+    fn test_expiring_entries() {
+        let cache = SharedCache::new(100).with_ttl(Duration::ZERO);
+        let key = B256::with_last_byte(1);
+
+        // Block 5 is not final yet, so the entry expires immediately.
+        cache.put_at_block(key, "1".to_string(), 5).unwrap();
+        assert_eq!(cache.get(&key), None);
+
+        cache.set_finalized_block(5);
+        cache.set_finalized_block(4);
+        assert_eq!(cache.finalized_block(), 5);
+
+        cache.put_at_block(key, "2".to_string(), 5).unwrap();
+        assert_eq!(cache.get(&key), Some("2".to_string()));
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+        assert_eq!(cache.stats().hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_disk_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rpc-cache.jsonl");
+        let immutable = B256::with_last_byte(1);
+        let expiring = B256::with_last_byte(2);
+
+        let store = DiskCacheStore::open(&path).unwrap();
+        store.put(immutable, CacheEntry::immutable("1".to_string())).unwrap();
+        store
+            .put(expiring, CacheEntry::expiring("2".to_string(), Duration::from_secs(60)))
+            .unwrap();
+        assert_eq!(store.get(&expiring).unwrap().value(), "2");
+        drop(store);
+
+        // A line left partially written is skipped.
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(b"{\"key\":").unwrap();
+
+        // Putting an immutable entry again does not append it to the file again.
+        let store = DiskCacheStore::open(&path).unwrap();
+        store.put(immutable, CacheEntry::immutable("1".to_string())).unwrap();
+        let lines = || std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines(), 1);
+
+        // Compaction drops the lines of removed entries.
+        store.put(expiring, CacheEntry::immutable("2".to_string())).unwrap();
+        store.remove(&expiring);
+        assert_eq!(lines(), 2);
+        store.compact().unwrap();
+        assert_eq!(lines(), 1);
+        drop(store);
+
+        let cache = SharedCache::with_store(DiskCacheStore::open(&path).unwrap());
+        assert_eq!(cache.get(&immutable), Some("1".to_string()));
+        assert_eq!(cache.get(&expiring), None);
+        assert_eq!(cache.max_items(), u32::MAX);
+    }
+
+    #[test]
+    fn test_disk_store_memory_items() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rpc-cache.jsonl");
+        let first = B256::with_last_byte(1);
+        let second = B256::with_last_byte(2);
+        let expired = B256::with_last_byte(3);
+
+        // Entries evicted from memory are read from the file.
+        let store = DiskCacheStore::open_with_memory_items(&path, 1).unwrap();
+        assert_eq!(store.memory_items(), 1);
+        assert!(!store.put(first, CacheEntry::immutable("1".to_string())).unwrap());
+        assert!(!store.put(second, CacheEntry::immutable("2".to_string())).unwrap());
+        assert_eq!(store.get(&first).unwrap().value(), "1");
+        assert_eq!(store.get(&second).unwrap().value(), "2");
+        assert!(store.put(first, CacheEntry::immutable("1".to_string())).unwrap());
+        assert_eq!(store.entries().len(), 2);
+
+        // Compaction evicts expired entries, and keeps the lines of the evicted ones.
+        store.put(expired, CacheEntry::expiring("3".to_string(), Duration::ZERO)).unwrap();
+        store.compact().unwrap();
+        assert!(store.get(&expired).is_none());
+        let mut entries = store.entries();
+        entries.sort_by_key(|(key, _)| *key);
+        let values: Vec<_> = entries.iter().map(|(_, entry)| entry.value()).collect();
+        assert_eq!(values, ["1", "2"]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_remove_if() {
+        let store = MemoryCacheStore::new(100);
+        let key = B256::with_last_byte(1);
+
+        // An expired entry replaced by a fresh one is kept.
+        store.put(key, CacheEntry::expiring("1".to_string(), Duration::ZERO)).unwrap();
+        let expired = store.get(&key).unwrap();
+        assert!(expired.is_expired());
+        store.put(key, CacheEntry::expiring("2".to_string(), Duration::from_secs(60))).unwrap();
+        assert!(!store.remove_if(&key, &CacheEntry::is_expired));
+        assert_eq!(store.get(&key).unwrap().value(), "2");
+
+        store.put(key, CacheEntry::expiring("3".to_string(), Duration::ZERO)).unwrap();
+        assert!(store.remove_if(&key, &CacheEntry::is_expired));
+        assert!(store.get(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_and_call_at_block_hash() {
+        let cache_layer = CacheLayer::new(100);
+        let shared_cache = cache_layer.cache();
+        let anvil = Anvil::new().spawn();
+        let provider = ProviderBuilder::new().layer(cache_layer).on_http(anvil.endpoint_url());
+
+        let from = anvil.addresses()[0];
+
+        // Reads at the latest block are never cached.
+        provider.get_balance(from).await.unwrap();
+        provider.get_balance(from).await.unwrap();
+        assert_eq!(shared_cache.stats(), CacheStats::default());
+
+        let block = provider.get_block(BlockId::latest(), BlockTransactionsKind::Hashes).await;
+        let block_id = BlockId::hash(block.unwrap().unwrap().header.hash);
+
+        let balance = provider.get_balance(from).block_id(block_id).await.unwrap(); // Received from RPC.
+        let balance2 = provider.get_balance(from).block_id(block_id).await.unwrap(); // Received from cache.
+        assert_eq!(balance, balance2);
+        assert_eq!(shared_cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        let tx = TransactionRequest::default().from(from).to(Address::repeat_byte(5));
+        let output = provider.call(&tx).block(block_id).await.unwrap(); // Received from RPC.
+        let output2 = provider.call(&tx).block(block_id).await.unwrap(); // Received from cache.
+        assert_eq!(output, output2);
+        assert_eq!(shared_cache.stats(), CacheStats { hits: 2, misses: 2 });
+    }
 }
@@ -15,4 +15,7 @@ pub mod seismic;
 #[cfg(not(target_arch = "wasm32"))]
 mod cache;
 #[cfg(not(target_arch = "wasm32"))]
-pub use cache::{CacheLayer, CacheProvider, SharedCache};
+pub use cache::{
+    CacheEntry, CacheLayer, CacheProvider, CacheStats, CacheStore, DiskCacheStore,
+    MemoryCacheStore, SharedCache,
+};
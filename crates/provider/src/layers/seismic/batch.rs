@@ -0,0 +1,185 @@
+//! Batches of encrypted calls.
+
+use super::{decrypt_revert, SeismicCrypto, SeismicError, SeismicProvider};
+use crate::Provider;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::Bytes;
+use alloy_rpc_client::BatchRequest;
+use alloy_transport::{Transport, TransportResult};
+use seismic_enclave::PublicKey;
+use std::fmt;
+
+/// A batch of unsigned seismic calls, sent as a single JSON-RPC batch.
+///
+/// Created by [`SeismicProvider::seismic_batch`]. The input of every call is
+/// encrypted with its own key against a TEE public key fetched once for the
+/// batch, and every output is decrypted with the key and nonce of its call.
+///
+/// Calls are sent as is to the node, the fillers of the inner provider are
+/// not applied. Calls without a nonce are handled according to the
+/// [`MissingNoncePolicy`](super::MissingNoncePolicy) of the layer.
+#[must_use = "a batch does nothing unless sent"]
+pub struct SeismicBatch<'a, P, T, N: Network, C: SeismicCrypto> {
+    provider: &'a SeismicProvider<P, T, N, C>,
+    calls: Vec<N::TransactionRequest>,
+}
+
+impl<P, T, N: Network, C: SeismicCrypto> fmt::Debug for SeismicBatch<'_, P, T, N, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeismicBatch").field("calls", &self.calls.len()).finish_non_exhaustive()
+    }
+}
+
+/// A call of the batch being sent.
+struct PendingCall<N: Network, K> {
+    /// The position of the call in the batch.
+    index: usize,
+    /// The call, its input still in plaintext.
+    tx: N::TransactionRequest,
+    /// The key and nonce the input was last encrypted with, if encrypted.
+    key: Option<(K, u64)>,
+}
+
+impl<'a, P, T, N, C> SeismicBatch<'a, P, T, N, C>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    pub(super) const fn new(provider: &'a SeismicProvider<P, T, N, C>) -> Self {
+        Self { provider, calls: Vec::new() }
+    }
+
+    /// Adds a call to the batch.
+    pub fn add_call(mut self, tx: N::TransactionRequest) -> Self {
+        self.calls.push(tx);
+        self
+    }
+
+    /// Returns the number of calls in the batch.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns `true` if the batch has no calls.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Sends the batch, returning the decrypted output of every call in the
+    /// order they were added.
+    ///
+    /// Calls failing on their own, e.g. reverting, fail only their own result.
+    /// Calls rejected by the node because the enclave key was rotated are
+    /// encrypted again with the new key and sent in a second batch. The
+    /// returned error is that of the batch as a whole, e.g. a transport error
+    /// or a failure to fetch the TEE public key.
+    pub async fn send(self) -> TransportResult<Vec<TransportResult<Bytes>>> {
+        let Self { provider, calls } = self;
+        let mut results: Vec<Option<TransportResult<Bytes>>> =
+            std::iter::repeat_with(|| None).take(calls.len()).collect();
+
+        let mut pending = Vec::with_capacity(calls.len());
+        for (index, mut tx) in calls.into_iter().enumerate() {
+            let checked = provider
+                .check_unsigned_call(&tx)
+                .and_then(|()| provider.fill_missing_nonce(&mut tx, true));
+            match checked {
+                Ok(()) => pending.push(PendingCall::<N, C::Key> { index, tx, key: None }),
+                Err(err) => results[index] = Some(Err(err)),
+            }
+        }
+
+        // a single TEE public key for the whole batch
+        let mut tee_pubkey = None;
+        if pending.iter().any(|call| provider.should_encrypt_input(&call.tx)) {
+            tee_pubkey = Some(provider.tee_pubkey().await?);
+        }
+        let mut retried = false;
+        while !pending.is_empty() {
+            let client = provider.inner.client();
+            let mut batch = BatchRequest::new(client);
+            let mut waiters = Vec::with_capacity(pending.len());
+            for mut call in pending {
+                let mut tx = call.tx.clone();
+                if let Some(tee_pubkey) = &tee_pubkey {
+                    if provider.should_encrypt_input(&tx) {
+                        match encrypt(provider, &mut tx, tee_pubkey) {
+                            Ok(key) => call.key = Some(key),
+                            Err(err) => {
+                                results[call.index] = Some(Err(err));
+                                continue;
+                            }
+                        }
+                    }
+                }
+                waiters.push((batch.add_call::<_, Bytes>("eth_call", &(tx,))?, call));
+            }
+            batch.send().await?;
+
+            pending = Vec::new();
+            let mut rejected = Vec::new();
+            for (waiter, call) in waiters {
+                let result = waiter.await;
+                let Some((key, nonce)) = &call.key else {
+                    results[call.index] = Some(result);
+                    continue;
+                };
+                let tee_pubkey = tee_pubkey.as_ref().expect("set for encrypted calls");
+                results[call.index] = Some(match result {
+                    Ok(output) => {
+                        provider.crypto.decrypt(tee_pubkey, key, &output, *nonce).map_err(
+                            |source| SeismicError::Decryption { nonce: *nonce, source }.into(),
+                        )
+                    }
+                    Err(err) if !retried && provider.tee_key.is_key_mismatch(&err) => {
+                        rejected.push((err, call));
+                        continue;
+                    }
+                    Err(err) => Err(decrypt_revert(&provider.crypto, tee_pubkey, key, *nonce, err)),
+                });
+            }
+
+            if rejected.is_empty() {
+                break;
+            }
+            // the enclave key may have been rotated, retry the rejected calls once with the new key
+            let stale = tee_pubkey.expect("set for encrypted calls");
+            retried = true;
+            match provider.rotated_tee_pubkey(&stale).await? {
+                Some(rotated) => {
+                    tee_pubkey = Some(rotated);
+                    pending = rejected.into_iter().map(|(_, call)| call).collect();
+                }
+                None => {
+                    for (err, call) in rejected {
+                        results[call.index] = Some(Err(err));
+                    }
+                }
+            }
+        }
+
+        Ok(results.into_iter().map(|result| result.expect("every call has a result")).collect())
+    }
+}
+
+/// Encrypts the input of `tx`, returning the key and nonce it was encrypted
+/// with.
+fn encrypt<P, T, N, C>(
+    provider: &SeismicProvider<P, T, N, C>,
+    tx: &mut N::TransactionRequest,
+    tee_pubkey: &PublicKey,
+) -> TransportResult<(C::Key, u64)>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    let plaintext = provider.plaintext_input(tx);
+    let key = provider.encrypt_input(tx, &plaintext, tee_pubkey)?;
+    // the call is sent without going through the fillers, nothing re-encrypts it
+    provider.inputs.remove(&provider.crypto.encryption_pubkey(&key));
+    Ok((key, tx.nonce().expect("checked by should_encrypt_input")))
+}
@@ -0,0 +1,113 @@
+//! Encryption backends used by the seismic provider.
+
+use alloy_primitives::{Bytes, FixedBytes};
+use seismic_enclave::{ecdh_decrypt, ecdh_encrypt, rand, Keypair, PublicKey, Secp256k1};
+use std::fmt::Debug;
+
+/// Error returned by a [`SeismicCrypto`] backend.
+pub type SeismicCryptoError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Encryption backend of the [`SeismicProvider`](super::SeismicProvider).
+///
+/// The provider asks the backend for a fresh client key for every encrypted
+/// request, encrypts the transaction input to the TEE public key of the node
+/// with it and decrypts the response with the same key.
+///
+/// Implementing this trait allows swapping the cipher or the key source, e.g.
+/// deterministic keys for test vectors or client keys held in an HSM, where
+/// [`SeismicCrypto::Key`] is only a handle to the key material.
+pub trait SeismicCrypto: Debug + Clone + Send + Sync + 'static {
+    /// The client key used for a single encrypted request.
+    type Key: Debug + Clone + Send + Sync + 'static;
+
+    /// Generates the key for a new encrypted request.
+    fn generate_key(&self) -> Result<Self::Key, SeismicCryptoError>;
+
+    /// Returns the compressed public key of `key`, sent to the node as the
+    /// `encryption_pubkey` of the transaction.
+    fn encryption_pubkey(&self, key: &Self::Key) -> FixedBytes<33>;
+
+    /// Encrypts `plaintext` to the TEE public key of the node.
+    fn encrypt(
+        &self,
+        tee_pubkey: &PublicKey,
+        key: &Self::Key,
+        plaintext: &[u8],
+        nonce: u64,
+    ) -> Result<Bytes, SeismicCryptoError>;
+
+    /// Decrypts `ciphertext` returned by the node.
+    fn decrypt(
+        &self,
+        tee_pubkey: &PublicKey,
+        key: &Self::Key,
+        ciphertext: &[u8],
+        nonce: u64,
+    ) -> Result<Bytes, SeismicCryptoError>;
+}
+
+/// The default [`SeismicCrypto`] backend.
+///
+/// Generates a random secp256k1 keypair for every request and encrypts with
+/// the ECDH shared secret between it and the TEE public key of the node.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct EcdhCrypto;
+
+impl SeismicCrypto for EcdhCrypto {
+    type Key = Keypair;
+
+    fn generate_key(&self) -> Result<Keypair, SeismicCryptoError> {
+        Ok(Keypair::new(&Secp256k1::new(), &mut rand::thread_rng()))
+    }
+
+    fn encryption_pubkey(&self, key: &Keypair) -> FixedBytes<33> {
+        FixedBytes(key.public_key().serialize())
+    }
+
+    fn encrypt(
+        &self,
+        tee_pubkey: &PublicKey,
+        key: &Keypair,
+        plaintext: &[u8],
+        nonce: u64,
+    ) -> Result<Bytes, SeismicCryptoError> {
+        ecdh_encrypt(tee_pubkey, &key.secret_key(), plaintext.to_vec(), nonce)
+            .map(Bytes::from)
+            .map_err(|e| format!("{e:?}").into())
+    }
+
+    fn decrypt(
+        &self,
+        tee_pubkey: &PublicKey,
+        key: &Keypair,
+        ciphertext: &[u8],
+        nonce: u64,
+    ) -> Result<Bytes, SeismicCryptoError> {
+        ecdh_decrypt(tee_pubkey, &key.secret_key(), ciphertext.to_vec(), nonce)
+            .map(Bytes::from)
+            .map_err(|e| format!("{e:?}").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes;
+
+    #[test]
+    fn ecdh_round_trip() {
+        let crypto = EcdhCrypto;
+        let tee = crypto.generate_key().unwrap();
+        let key = crypto.generate_key().unwrap();
+        assert_eq!(crypto.encryption_pubkey(&key).as_slice(), &key.public_key().serialize());
+
+        let plaintext = bytes!("43bd0d70");
+        let ciphertext = crypto.encrypt(&tee.public_key(), &key, &plaintext, 7).unwrap();
+        assert_ne!(ciphertext, plaintext);
+
+        // the node decrypts with its own secret and the client's public key
+        let decrypted = crypto.decrypt(&key.public_key(), &tee, &ciphertext, 7).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+}
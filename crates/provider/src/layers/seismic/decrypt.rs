@@ -0,0 +1,44 @@
+//! Decryption of seismic data returned by the node outside of call outputs.
+
+use super::SeismicCrypto;
+use alloy_json_rpc::RpcError;
+use alloy_primitives::Bytes;
+use alloy_transport::TransportError;
+use seismic_enclave::PublicKey;
+use serde_json::value::to_raw_value;
+
+/// A seismic transaction along with its decrypted input.
+///
+/// Returned by
+/// [`SeismicProvider::decrypt_transaction`](super::SeismicProvider::decrypt_transaction).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecryptedTransaction<Tx> {
+    /// The transaction as returned by the node, its input still encrypted.
+    pub tx: Tx,
+    /// The decrypted input of the transaction.
+    pub input: Bytes,
+}
+
+/// Decrypts the revert data of a failed encrypted call, if the node encrypted
+/// it with the key of the request.
+///
+/// Revert data that doesn't decrypt with the key was not encrypted, the error
+/// is returned unchanged then.
+pub(crate) fn decrypt_revert<C: SeismicCrypto>(
+    crypto: &C,
+    tee_pubkey: &PublicKey,
+    key: &C::Key,
+    nonce: u64,
+    err: TransportError,
+) -> TransportError {
+    let RpcError::ErrorResp(mut payload) = err else { return err };
+    let Some(data) = payload.as_revert_data().filter(|data| !data.is_empty()) else {
+        return RpcError::ErrorResp(payload);
+    };
+    if let Ok(plaintext) = crypto.decrypt(tee_pubkey, key, &data, nonce) {
+        if let Ok(data) = to_raw_value(&plaintext) {
+            payload.data = Some(data);
+        }
+    }
+    RpcError::ErrorResp(payload)
+}
@@ -0,0 +1,128 @@
+//! Errors of the seismic provider.
+
+use super::SeismicCryptoError;
+use alloy_json_rpc::RpcError;
+use alloy_primitives::{Address, FixedBytes, TxHash};
+use alloy_transport::{TransportError, TransportErrorKind};
+
+/// An error raised by the seismic provider while encrypting a request or
+/// decrypting what the node returned.
+///
+/// It is returned as the [`TransportErrorKind::Custom`] source of a
+/// [`TransportError`], use [`SeismicError::from_transport_err`] to get it
+/// back:
+///
+/// ```
+/// # async fn example(provider: impl alloy_provider::Provider, tx: alloy_provider::SendableTx<alloy_network::Ethereum>) {
+/// use alloy_provider::SeismicError;
+///
+/// if let Err(err) = provider.seismic_call(tx).await {
+///     match SeismicError::from_transport_err(&err) {
+///         Some(SeismicError::TeePubkey(_)) => println!("the node has no enclave"),
+///         Some(SeismicError::Decryption { nonce, .. }) => println!("bad output for nonce {nonce}"),
+///         _ => println!("{err}"),
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SeismicError {
+    /// The TEE public key could not be fetched, e.g. because the node does not
+    /// run an enclave.
+    #[error("failed to fetch the TEE public key")]
+    TeePubkey(#[source] TransportError),
+    /// The node returned a TEE public key which is not a valid secp256k1 key.
+    #[error("invalid TEE public key")]
+    InvalidTeePubkey(#[source] SeismicCryptoError),
+    /// The crypto backend failed to generate a client key.
+    #[error("failed to generate an encryption key")]
+    KeyGeneration(#[source] SeismicCryptoError),
+    /// The input could not be encrypted.
+    #[error("failed to encrypt input with nonce {nonce}")]
+    Encryption {
+        /// The nonce the input was encrypted with.
+        nonce: u64,
+        /// The error of the crypto backend.
+        #[source]
+        source: SeismicCryptoError,
+    },
+    /// Data returned by the node, such as a call output or the input of a
+    /// fetched transaction, could not be decrypted. The ciphertext is either
+    /// corrupted or bound to another nonce or key.
+    #[error("failed to decrypt with nonce {nonce}")]
+    Decryption {
+        /// The nonce the data was expected to be encrypted with.
+        nonce: u64,
+        /// The error of the crypto backend.
+        #[source]
+        source: SeismicCryptoError,
+    },
+    /// The request has input but no nonce to encrypt it with, see
+    /// [`MissingNoncePolicy`](super::MissingNoncePolicy).
+    #[error("no nonce to encrypt the input with")]
+    MissingNonce,
+    /// The transaction has no encryption public key.
+    #[error("not a seismic transaction")]
+    NotSeismic,
+    /// The transaction was encrypted with another client key than the given one.
+    #[error("transaction was not encrypted with the given key")]
+    KeyMismatch,
+    /// No [`EncryptionKeyStore`](super::EncryptionKeyStore) is set on the layer.
+    #[error("no encryption key store set")]
+    NoKeyStore,
+    /// The key store has no key for the transaction.
+    #[error("no encryption key stored for transaction {0}")]
+    UnknownTransactionKey(TxHash),
+    /// The file of a [`FileKeyStore`](super::FileKeyStore) could not be read or written.
+    #[error("encryption key store I/O error")]
+    KeyStoreIo(#[source] std::io::Error),
+    /// The file of a [`FileKeyStore`](super::FileKeyStore) is not a valid key store.
+    #[error("malformed encryption key store")]
+    KeyStoreFormat(#[source] serde_json::Error),
+    /// The key stored for the transaction is not a valid key of the crypto backend.
+    #[error("invalid encryption key stored for transaction {0}")]
+    InvalidStoredKey(TxHash),
+    /// The TEE public key stored for the transaction is not a valid secp256k1 key.
+    #[error("invalid TEE public key stored for transaction {0}")]
+    InvalidStoredTeePubkey(TxHash),
+    /// The node does not know the transaction.
+    #[error("transaction {0} not found")]
+    TransactionNotFound(TxHash),
+    /// Shielded calldata would have been sent unsigned to an
+    /// [authenticated contract](super::SeismicLayer::with_authenticated_contracts).
+    #[error(
+        "refusing to send shielded calldata unsigned to authenticated contract {0}, the call \
+         must be signed"
+    )]
+    UnsignedAuthenticatedCall(Address),
+    /// A signed call reached the root provider unsigned, no filler of the
+    /// stack is able to sign it.
+    #[error("signed call requires a provider able to sign the transaction")]
+    SignerRequired,
+    /// A filler replaced the input of the transaction after it was encrypted.
+    #[error(
+        "input of seismic transaction with encryption pubkey {0} was replaced after encryption, \
+         refusing to send a ciphertext not bound to its nonce"
+    )]
+    CiphertextReplaced(FixedBytes<33>),
+    /// A filler unset the nonce of the transaction after its input was encrypted.
+    #[error("nonce of seismic transaction with encryption pubkey {0} was unset after encryption")]
+    NonceUnset(FixedBytes<33>),
+}
+
+impl SeismicError {
+    /// Returns the seismic error carried by `err`, if any.
+    pub fn from_transport_err(err: &TransportError) -> Option<&Self> {
+        match err {
+            RpcError::Transport(TransportErrorKind::Custom(err)) => err.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<SeismicError> for TransportError {
+    fn from(err: SeismicError) -> Self {
+        TransportErrorKind::custom(err)
+    }
+}
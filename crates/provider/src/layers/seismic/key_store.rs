@@ -0,0 +1,287 @@
+//! Storage of the client keys used to encrypt sent seismic transactions.
+
+use super::SeismicError;
+use alloy_primitives::{Bytes, FixedBytes, TxHash};
+use alloy_transport::TransportResult;
+use seismic_enclave::{Keypair, PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    io::{BufReader, BufWriter, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex, RwLock},
+};
+
+/// The client key a transaction input was encrypted with, along with the TEE
+/// public key it was encrypted to.
+#[derive(Debug, Clone)]
+pub struct StoredEncryptionKey<K> {
+    /// The client key the input was encrypted with.
+    pub key: K,
+    /// The TEE public key of the node at the time the input was encrypted.
+    pub tee_pubkey: PublicKey,
+}
+
+/// Persistence backend for the keys of sent seismic transactions, keyed by
+/// transaction hash.
+///
+/// Once set on the [`SeismicLayer`](super::SeismicLayer), the key of every
+/// encrypted transaction sent through the provider is stored, allowing the
+/// sender to decrypt its input later on with
+/// [`SeismicProvider::decrypt_own_transaction`](super::SeismicProvider::decrypt_own_transaction).
+pub trait EncryptionKeyStore<K>: Debug + Send + Sync {
+    /// Stores the key used to encrypt the transaction with the given hash.
+    fn insert(&self, tx_hash: TxHash, key: StoredEncryptionKey<K>) -> TransportResult<()>;
+
+    /// Returns the key used to encrypt the transaction with the given hash.
+    fn get(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>>;
+
+    /// Removes and returns the key of the transaction with the given hash.
+    fn remove(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>>;
+}
+
+/// An [`EncryptionKeyStore`] keeping the keys in memory.
+#[derive(Debug, Clone)]
+pub struct InMemoryKeyStore<K> {
+    keys: Arc<RwLock<HashMap<TxHash, StoredEncryptionKey<K>>>>,
+}
+
+impl<K> Default for InMemoryKeyStore<K> {
+    fn default() -> Self {
+        Self { keys: Arc::default() }
+    }
+}
+
+impl<K> InMemoryKeyStore<K> {
+    /// Instantiate a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K: Debug + Clone + Send + Sync> EncryptionKeyStore<K> for InMemoryKeyStore<K> {
+    fn insert(&self, tx_hash: TxHash, key: StoredEncryptionKey<K>) -> TransportResult<()> {
+        self.keys.write().unwrap_or_else(|e| e.into_inner()).insert(tx_hash, key);
+        Ok(())
+    }
+
+    fn get(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>> {
+        Ok(self.keys.read().unwrap_or_else(|e| e.into_inner()).get(tx_hash).cloned())
+    }
+
+    fn remove(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>> {
+        Ok(self.keys.write().unwrap_or_else(|e| e.into_inner()).remove(tx_hash))
+    }
+}
+
+/// A client key that can be written to and read back from a [`FileKeyStore`].
+pub trait EncodableKey: Sized {
+    /// Encodes the key.
+    fn to_key_bytes(&self) -> Vec<u8>;
+
+    /// Decodes a key encoded with [`EncodableKey::to_key_bytes`].
+    fn from_key_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl EncodableKey for Keypair {
+    fn to_key_bytes(&self) -> Vec<u8> {
+        self.secret_key().secret_bytes().to_vec()
+    }
+
+    fn from_key_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_seckey_slice(&Secp256k1::new(), bytes).ok()
+    }
+}
+
+/// A key as written to the file of a [`FileKeyStore`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileEntry {
+    key: Bytes,
+    tee_pubkey: FixedBytes<33>,
+}
+
+/// An [`EncryptionKeyStore`] persisting the keys to a JSON file.
+///
+/// The keys are held in memory and the whole file is rewritten on every
+/// change, one write at a time across clones of the store. On unix the file is
+/// only readable by its owner, as it holds the secrets needed to decrypt the
+/// inputs of the stored transactions.
+#[derive(Debug, Clone)]
+pub struct FileKeyStore<K> {
+    path: PathBuf,
+    keys: InMemoryKeyStore<K>,
+    /// Held while the file is rewritten, so that concurrent writes neither
+    /// interleave nor replace a newer snapshot with an older one.
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl<K: EncodableKey + Debug + Clone + Send + Sync> FileKeyStore<K> {
+    /// Opens the store at `path`, loading the keys from the file if it exists.
+    pub fn open(path: impl Into<PathBuf>) -> TransportResult<Self> {
+        let path = path.into();
+        let keys = InMemoryKeyStore::new();
+
+        if path.exists() {
+            let file = std::fs::File::open(&path).map_err(SeismicError::KeyStoreIo)?;
+            let entries: HashMap<TxHash, FileEntry> = serde_json::from_reader(BufReader::new(file))
+                .map_err(SeismicError::KeyStoreFormat)?;
+            for (tx_hash, entry) in entries {
+                let key =
+                    K::from_key_bytes(&entry.key).ok_or(SeismicError::InvalidStoredKey(tx_hash))?;
+                let tee_pubkey = PublicKey::from_slice(entry.tee_pubkey.as_slice())
+                    .map_err(|_| SeismicError::InvalidStoredTeePubkey(tx_hash))?;
+                keys.insert(tx_hash, StoredEncryptionKey { key, tee_pubkey })?;
+            }
+        }
+
+        Ok(Self { path, keys, write_lock: Arc::default() })
+    }
+
+    /// Returns the path of the file backing the store.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Writes all keys to the file, replacing its previous content.
+    fn save(&self) -> TransportResult<()> {
+        // the snapshot is taken under the lock, so the last rename always
+        // carries every change made before it
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let entries: HashMap<TxHash, FileEntry> = self
+            .keys
+            .keys
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(tx_hash, stored)| {
+                let entry = FileEntry {
+                    key: stored.key.to_key_bytes().into(),
+                    tee_pubkey: FixedBytes(stored.tee_pubkey.serialize()),
+                };
+                (*tx_hash, entry)
+            })
+            .collect();
+
+        // write to a temporary file first so a crash never leaves a truncated store behind
+        let tmp = self.path.with_extension("tmp");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let file = options.open(&tmp).map_err(SeismicError::KeyStoreIo)?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, &entries).map_err(SeismicError::KeyStoreFormat)?;
+        writer.flush().map_err(SeismicError::KeyStoreIo)?;
+        // make sure the content is on disk before it replaces the previous store
+        writer.get_ref().sync_all().map_err(SeismicError::KeyStoreIo)?;
+        std::fs::rename(&tmp, &self.path).map_err(SeismicError::KeyStoreIo)?;
+        Ok(())
+    }
+}
+
+impl<K: EncodableKey + Debug + Clone + Send + Sync> EncryptionKeyStore<K> for FileKeyStore<K> {
+    fn insert(&self, tx_hash: TxHash, key: StoredEncryptionKey<K>) -> TransportResult<()> {
+        self.keys.insert(tx_hash, key)?;
+        self.save()
+    }
+
+    fn get(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>> {
+        self.keys.get(tx_hash)
+    }
+
+    fn remove(&self, tx_hash: &TxHash) -> TransportResult<Option<StoredEncryptionKey<K>>> {
+        let removed = self.keys.remove(tx_hash)?;
+        if removed.is_some() {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seismic_enclave::rand;
+
+    fn random_keypair() -> Keypair {
+        Keypair::new(&Secp256k1::new(), &mut rand::thread_rng())
+    }
+
+    #[test]
+    fn in_memory_store() {
+        let store = InMemoryKeyStore::new();
+        let tx_hash = TxHash::with_last_byte(1);
+        let key = random_keypair();
+        let tee_pubkey = random_keypair().public_key();
+
+        assert!(store.get(&tx_hash).unwrap().is_none());
+        store.insert(tx_hash, StoredEncryptionKey { key, tee_pubkey }).unwrap();
+        let stored = store.get(&tx_hash).unwrap().unwrap();
+        assert_eq!(stored.key, key);
+        assert_eq!(stored.tee_pubkey, tee_pubkey);
+
+        assert!(store.remove(&tx_hash).unwrap().is_some());
+        assert!(store.get(&tx_hash).unwrap().is_none());
+    }
+
+    #[test]
+    fn file_store_persists_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.json");
+        let tx_hash = TxHash::with_last_byte(1);
+        let key = random_keypair();
+        let tee_pubkey = random_keypair().public_key();
+
+        let store = FileKeyStore::<Keypair>::open(&path).unwrap();
+        store.insert(tx_hash, StoredEncryptionKey { key, tee_pubkey }).unwrap();
+        store.insert(TxHash::with_last_byte(2), StoredEncryptionKey { key, tee_pubkey }).unwrap();
+        store.remove(&TxHash::with_last_byte(2)).unwrap();
+
+        let reopened = FileKeyStore::<Keypair>::open(&path).unwrap();
+        let stored = reopened.get(&tx_hash).unwrap().unwrap();
+        assert_eq!(stored.key, key);
+        assert_eq!(stored.tee_pubkey, tee_pubkey);
+        assert!(reopened.get(&TxHash::with_last_byte(2)).unwrap().is_none());
+    }
+
+    #[test]
+    fn file_store_concurrent_inserts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.json");
+        let key = random_keypair();
+        let tee_pubkey = random_keypair().public_key();
+
+        let store = FileKeyStore::<Keypair>::open(&path).unwrap();
+        std::thread::scope(|scope| {
+            for i in 0..8u8 {
+                let store = store.clone();
+                scope.spawn(move || {
+                    for j in 0..8u8 {
+                        let tx_hash = TxHash::with_last_byte(i * 8 + j);
+                        store.insert(tx_hash, StoredEncryptionKey { key, tee_pubkey }).unwrap();
+                    }
+                });
+            }
+        });
+
+        let reopened = FileKeyStore::<Keypair>::open(&path).unwrap();
+        for i in 0..64 {
+            assert!(reopened.get(&TxHash::with_last_byte(i)).unwrap().is_some());
+        }
+    }
+
+    #[test]
+    fn file_store_rejects_malformed_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.json");
+        std::fs::write(&path, "not a key store").unwrap();
+
+        let err = FileKeyStore::<Keypair>::open(&path).unwrap_err();
+        assert!(matches!(
+            SeismicError::from_transport_err(&err),
+            Some(SeismicError::KeyStoreFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,611 @@
+//! In-process mock of a seismic node.
+
+use super::{EcdhCrypto, SeismicCrypto};
+use alloy_consensus::{
+    Receipt, ReceiptEnvelope, SignableTransaction, Transaction as _, TxEnvelope, TxSeismic, TxType,
+};
+use alloy_eips::{eip2718::Decodable2718, eip712::Decodable712};
+use alloy_json_rpc::{
+    ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest,
+};
+use alloy_primitives::{
+    Address, Bytes, ChainId, FixedBytes, PrimitiveSignature, TxHash, TxKind, B256, U256, U64,
+};
+use alloy_rpc_client::RpcClient;
+use alloy_rpc_types_eth::{
+    SeismicCallRequest, SeismicRawTxRequest, Transaction, TransactionReceipt, TransactionRequest,
+};
+use alloy_transport::{TransportError, TransportFut};
+use seismic_enclave::{rand, Keypair, PublicKey, Secp256k1};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::{to_raw_value, RawValue};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, RwLock},
+    task::{Context, Poll},
+};
+
+/// The result of a call handled by a [`MockSeismicNode`]: the output of the
+/// call, or the data it reverted with.
+pub type MockCallResult = Result<Bytes, Bytes>;
+
+/// The handler answering the calls made to a [`MockSeismicNode`].
+type CallHandler = Arc<dyn Fn(&MockSeismicRequest) -> MockCallResult + Send + Sync>;
+
+/// How a [`MockSeismicRequest`] reached the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MockSeismicRequestKind {
+    /// An unsigned `eth_call`.
+    Call,
+    /// An `eth_call` with a signed transaction, either raw or as EIP-712
+    /// typed data.
+    SignedCall,
+    /// A transaction sent with `eth_sendTransaction` or
+    /// `eth_sendRawTransaction`.
+    Transaction,
+}
+
+/// A call or transaction received by a [`MockSeismicNode`], with its input
+/// decrypted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockSeismicRequest {
+    /// How the request reached the node.
+    pub kind: MockSeismicRequestKind,
+    /// The sender: the recovered signer of signed requests, the `from` field
+    /// of unsigned ones.
+    pub from: Option<Address>,
+    /// The recipient.
+    pub to: TxKind,
+    /// The nonce the input was encrypted with.
+    pub nonce: u64,
+    /// The encryption public key of the client, `None` for requests which
+    /// were not encrypted.
+    pub encryption_pubkey: Option<FixedBytes<33>>,
+    /// The decrypted input.
+    pub input: Bytes,
+    /// Whether the request was signed over its EIP-712 typed data.
+    pub typed_data: bool,
+}
+
+/// An in-process seismic node, usable as a transport.
+///
+/// The node holds a TEE keypair and answers `seismic_getTeePublicKey` with
+/// its public key. The input of calls is decrypted, passed to a handler, and
+/// the output of the handler encrypted back to the client, the same way a
+/// seismic node does. Sent transactions are decrypted, recorded and served
+/// by `eth_getTransactionByHash` and `eth_getTransactionReceipt`, and every
+/// request is recorded, allowing seismic providers, wallets and contract
+/// bindings to be tested without a node.
+///
+/// The other methods answered are `eth_chainId`, `eth_gasPrice`,
+/// `eth_estimateGas`, `eth_getTransactionCount`, and `eth_blockNumber` and
+/// `eth_getBlockByNumber` polled by the heartbeat, which never sees a block.
+/// More can be added with [`MockSeismicNode::with_response`].
+///
+/// ## Example:
+///
+/// ```
+/// use alloy_primitives::Bytes;
+/// use alloy_provider::{MockSeismicNode, ProviderBuilder, SeismicLayer};
+///
+/// // Calls without input revert, the others return their decrypted input.
+/// let node = MockSeismicNode::new().with_handler(|req| {
+///     if req.input.is_empty() {
+///         Err(Bytes::new())
+///     } else {
+///         Ok(req.input.clone())
+///     }
+/// });
+/// let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+/// // Every request made through `provider` is recorded.
+/// assert!(node.requests().is_empty());
+/// ```
+#[derive(Clone)]
+pub struct MockSeismicNode {
+    chain_id: ChainId,
+    handler: CallHandler,
+    responses: Arc<HashMap<String, Box<RawValue>>>,
+    tee_keypair: Arc<RwLock<Keypair>>,
+    requests: Arc<Mutex<Vec<SerializedRequest>>>,
+    seismic_requests: Arc<Mutex<Vec<MockSeismicRequest>>>,
+    transactions: Arc<Mutex<HashMap<TxHash, Transaction>>>,
+}
+
+impl fmt::Debug for MockSeismicNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockSeismicNode")
+            .field("chain_id", &self.chain_id)
+            .field("tee_pubkey", &self.tee_pubkey())
+            .field("responses", &self.responses.keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for MockSeismicNode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockSeismicNode {
+    /// The gas price returned by `eth_gasPrice`.
+    pub const GAS_PRICE: u128 = 1_000_000_000;
+
+    /// The gas returned by `eth_estimateGas`.
+    pub const GAS_ESTIMATE: u64 = 100_000;
+
+    /// Instantiate a new node with a random TEE keypair, on chain 1, whose
+    /// calls return their decrypted input.
+    pub fn new() -> Self {
+        Self {
+            chain_id: 1,
+            handler: Arc::new(|req| Ok(req.input.clone())),
+            responses: Arc::default(),
+            tee_keypair: Arc::new(RwLock::new(Keypair::new(
+                &Secp256k1::new(),
+                &mut rand::thread_rng(),
+            ))),
+            requests: Arc::default(),
+            seismic_requests: Arc::default(),
+            transactions: Arc::default(),
+        }
+    }
+
+    /// Sets the chain ID returned by `eth_chainId`.
+    pub const fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Sets the handler answering calls. Its output is encrypted to the
+    /// client; an error reverts the call with the encrypted data.
+    pub fn with_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&MockSeismicRequest) -> MockCallResult + Send + Sync + 'static,
+    {
+        self.handler = Arc::new(handler);
+        self
+    }
+
+    /// Answers every request for `method` with `response`, taking precedence
+    /// over the methods handled by the node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `response` fails to serialize.
+    pub fn with_response(mut self, method: impl Into<String>, response: impl Serialize) -> Self {
+        let response = to_raw_value(&response).expect("failed to serialize mock response");
+        Arc::make_mut(&mut self.responses).insert(method.into(), response);
+        self
+    }
+
+    /// Returns an [`RpcClient`] over this node, sharing its state.
+    pub fn client(&self) -> RpcClient<Self> {
+        RpcClient::new(self.clone(), true)
+    }
+
+    /// Returns the TEE public key of the node.
+    pub fn tee_pubkey(&self) -> PublicKey {
+        self.tee_keypair.read().unwrap_or_else(|e| e.into_inner()).public_key()
+    }
+
+    /// Replaces the TEE keypair of the node, as on an enclave restart. Inputs
+    /// encrypted to the previous key no longer decrypt.
+    pub fn rotate_tee_key(&self) {
+        *self.tee_keypair.write().unwrap_or_else(|e| e.into_inner()) =
+            Keypair::new(&Secp256k1::new(), &mut rand::thread_rng());
+    }
+
+    /// Returns every request received, in order.
+    pub fn requests(&self) -> Vec<SerializedRequest> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns the number of requests received for `method`.
+    pub fn request_count(&self, method: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|req| req.method() == method)
+            .count()
+    }
+
+    /// Returns the calls and transactions received whose input decrypted, in
+    /// order.
+    pub fn seismic_requests(&self) -> Vec<MockSeismicRequest> {
+        self.seismic_requests.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns the transactions sent to the node.
+    pub fn transactions(&self) -> HashMap<TxHash, Transaction> {
+        self.transactions.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns the transaction with the given hash, if it was sent to the
+    /// node.
+    pub fn transaction(&self, tx_hash: &TxHash) -> Option<Transaction> {
+        self.transactions.lock().unwrap_or_else(|e| e.into_inner()).get(tx_hash).cloned()
+    }
+
+    /// Returns the receipt of a transaction sent to the node. Transactions
+    /// succeed without logs, in block 0.
+    pub fn receipt(&self, tx_hash: &TxHash) -> Option<TransactionReceipt> {
+        let tx = self.transaction(tx_hash)?;
+        let gas_used = Self::GAS_ESTIMATE as u128;
+        let receipt = Receipt { status: true.into(), cumulative_gas_used: gas_used, logs: vec![] }
+            .with_bloom();
+        let inner = match tx.inner.tx_type() {
+            TxType::Legacy => ReceiptEnvelope::Legacy(receipt),
+            TxType::Seismic => ReceiptEnvelope::Seismic(receipt),
+            TxType::SeismicEip1559 => ReceiptEnvelope::SeismicEip1559(receipt),
+            TxType::Eip2930 => ReceiptEnvelope::Eip2930(receipt),
+            TxType::Eip1559 => ReceiptEnvelope::Eip1559(receipt),
+            TxType::Eip4844 => ReceiptEnvelope::Eip4844(receipt),
+            TxType::Eip7702 => ReceiptEnvelope::Eip7702(receipt),
+        };
+        Some(TransactionReceipt {
+            inner,
+            transaction_hash: *tx_hash,
+            transaction_index: Some(0),
+            block_hash: Some(B256::ZERO),
+            block_number: Some(0),
+            gas_used,
+            effective_gas_price: tx.inner.effective_gas_price(None),
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: tx.from,
+            to: tx.inner.to(),
+            contract_address: tx.inner.kind().is_create().then(|| tx.from.create(tx.inner.nonce())),
+            authorization_list: None,
+        })
+    }
+
+    fn handle(&self, req: &SerializedRequest) -> ResponsePayload {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).push(req.clone());
+
+        if let Some(response) = self.responses.get(req.method()) {
+            return ResponsePayload::Success(response.clone());
+        }
+
+        let result = match req.method() {
+            "seismic_getTeePublicKey" => success(&FixedBytes(self.tee_pubkey().serialize())),
+            "eth_chainId" => success(&U64::from(self.chain_id)),
+            "eth_gasPrice" => success(&U256::from(Self::GAS_PRICE)),
+            "eth_estimateGas" => success(&U64::from(Self::GAS_ESTIMATE)),
+            "eth_getTransactionCount" => {
+                param(req).and_then(|from| success(&U64::from(self.transaction_count(from))))
+            }
+            "eth_call" => param(req).and_then(|call| self.call(call)),
+            "eth_sendTransaction" => param(req).and_then(|tx| self.send_transaction(tx)),
+            "eth_sendRawTransaction" => param(req).and_then(|tx| self.send_raw_transaction(tx)),
+            "eth_getTransactionByHash" => {
+                param(req).and_then(|tx_hash| success(&self.transaction(&tx_hash)))
+            }
+            "eth_getTransactionReceipt" => {
+                param(req).and_then(|tx_hash| success(&self.receipt(&tx_hash)))
+            }
+            "eth_blockNumber" => success(&U64::ZERO),
+            "eth_getBlockByNumber" => success(&serde_json::Value::Null),
+            _ => Err(ErrorPayload::method_not_found()),
+        };
+        result.map_or_else(ResponsePayload::Failure, ResponsePayload::Success)
+    }
+
+    fn transaction_count(&self, from: Address) -> usize {
+        self.transactions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .filter(|tx| tx.from == from)
+            .count()
+    }
+
+    fn call(&self, call: SeismicCallRequest) -> Result<Box<RawValue>, ErrorPayload> {
+        let req = match call {
+            SeismicCallRequest::TransactionRequest(tx) => MockSeismicRequest {
+                kind: MockSeismicRequestKind::Call,
+                from: tx.from,
+                to: tx.to.unwrap_or_default(),
+                nonce: tx.nonce.unwrap_or_default(),
+                encryption_pubkey: tx.encryption_pubkey,
+                input: tx.inner.input.into_input().unwrap_or_default(),
+                typed_data: false,
+            },
+            SeismicCallRequest::Bytes(raw) => {
+                signed_request(&decode_raw(&raw)?, MockSeismicRequestKind::SignedCall, false)?
+            }
+            SeismicCallRequest::TypedData(typed_data) => {
+                let tx = TxEnvelope::decode_712(&typed_data).map_err(invalid_params)?;
+                signed_request(&tx, MockSeismicRequestKind::SignedCall, true)?
+            }
+        };
+        let req = self.decrypt(req)?;
+
+        let result = (self.handler)(&req);
+        let encrypt = |data: Bytes| match &req.encryption_pubkey {
+            Some(encryption_pubkey) => self.encrypt(encryption_pubkey, &data, req.nonce),
+            None => Ok(data),
+        };
+        match result {
+            Ok(output) => success(&encrypt(output)?),
+            Err(data) => Err(ErrorPayload {
+                code: 3,
+                message: "execution reverted".into(),
+                data: Some(to_raw_value(&encrypt(data)?).map_err(invalid_params)?),
+            }),
+        }
+    }
+
+    fn send_transaction(&self, tx: TransactionRequest) -> Result<Box<RawValue>, ErrorPayload> {
+        let from = tx.from.ok_or_else(|| invalid_params("missing `from` field"))?;
+        let seismic = TxSeismic {
+            chain_id: tx.chain_id.unwrap_or(self.chain_id),
+            nonce: tx.nonce.unwrap_or_else(|| self.transaction_count(from) as u64),
+            gas_price: tx.gas_price.unwrap_or(Self::GAS_PRICE),
+            gas_limit: tx.gas.unwrap_or(Self::GAS_ESTIMATE),
+            to: tx.to.unwrap_or_default(),
+            value: tx.value.unwrap_or_default(),
+            encryption_pubkey: tx.encryption_pubkey.unwrap_or_default(),
+            message_version: tx.message_version.unwrap_or_default(),
+            input: tx.input.into_input().unwrap_or_default(),
+        };
+        let req = MockSeismicRequest {
+            kind: MockSeismicRequestKind::Transaction,
+            from: Some(from),
+            to: seismic.to,
+            nonce: seismic.nonce,
+            encryption_pubkey: tx.encryption_pubkey,
+            input: seismic.input.clone(),
+            typed_data: false,
+        };
+        self.decrypt(req)?;
+
+        // the node signs for unlocked accounts, which the mock does not hold
+        let signed = seismic.into_signed(PrimitiveSignature::test_signature());
+        self.insert_transaction(signed.into(), from)
+    }
+
+    fn send_raw_transaction(
+        &self,
+        request: SeismicRawTxRequest,
+    ) -> Result<Box<RawValue>, ErrorPayload> {
+        let (tx, typed_data) = match request {
+            SeismicRawTxRequest::Bytes(raw) => (decode_raw(&raw)?, false),
+            SeismicRawTxRequest::TypedData(typed_data) => {
+                (TxEnvelope::decode_712(&typed_data).map_err(invalid_params)?, true)
+            }
+        };
+        let req = signed_request(&tx, MockSeismicRequestKind::Transaction, typed_data)?;
+        let from = req.from.unwrap_or_default();
+        self.decrypt(req)?;
+        self.insert_transaction(tx, from)
+    }
+
+    fn insert_transaction(
+        &self,
+        tx: TxEnvelope,
+        from: Address,
+    ) -> Result<Box<RawValue>, ErrorPayload> {
+        let tx_hash = *tx.tx_hash();
+        let tx = Transaction {
+            inner: tx,
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            from,
+        };
+        self.transactions.lock().unwrap_or_else(|e| e.into_inner()).insert(tx_hash, tx);
+        success(&tx_hash)
+    }
+
+    /// Decrypts the input of `req` and records it.
+    fn decrypt(&self, mut req: MockSeismicRequest) -> Result<MockSeismicRequest, ErrorPayload> {
+        if let Some(encryption_pubkey) = &req.encryption_pubkey {
+            let tee_keypair = *self.tee_keypair.read().unwrap_or_else(|e| e.into_inner());
+            req.input = PublicKey::from_slice(encryption_pubkey.as_slice())
+                .ok()
+                .and_then(|client_pubkey| {
+                    EcdhCrypto.decrypt(&client_pubkey, &tee_keypair, &req.input, req.nonce).ok()
+                })
+                .ok_or_else(decryption_error)?;
+        }
+        self.seismic_requests.lock().unwrap_or_else(|e| e.into_inner()).push(req.clone());
+        Ok(req)
+    }
+
+    fn encrypt(
+        &self,
+        encryption_pubkey: &FixedBytes<33>,
+        plaintext: &[u8],
+        nonce: u64,
+    ) -> Result<Bytes, ErrorPayload> {
+        let tee_keypair = *self.tee_keypair.read().unwrap_or_else(|e| e.into_inner());
+        let client_pubkey =
+            PublicKey::from_slice(encryption_pubkey.as_slice()).map_err(invalid_params)?;
+        EcdhCrypto.encrypt(&client_pubkey, &tee_keypair, plaintext, nonce).map_err(|e| {
+            ErrorPayload::internal_error_message(format!("failed to encrypt output: {e}").into())
+        })
+    }
+}
+
+impl tower::Service<RequestPacket> for MockSeismicNode {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        let respond =
+            |req: &SerializedRequest| Response { id: req.id().clone(), payload: self.handle(req) };
+        let response = match req {
+            RequestPacket::Single(req) => ResponsePacket::Single(respond(&req)),
+            RequestPacket::Batch(reqs) => ResponsePacket::Batch(reqs.iter().map(respond).collect()),
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+fn success<T: Serialize>(value: &T) -> Result<Box<RawValue>, ErrorPayload> {
+    to_raw_value(value).map_err(|e| ErrorPayload::internal_error_message(e.to_string().into()))
+}
+
+/// Deserializes the first parameter of `req`, ignoring the block and state
+/// overrides that may follow it.
+fn param<T: DeserializeOwned>(req: &SerializedRequest) -> Result<T, ErrorPayload> {
+    let params = req.params().ok_or_else(|| invalid_params("missing params"))?;
+    let params: Vec<serde_json::Value> =
+        serde_json::from_str(params.get()).map_err(invalid_params)?;
+    let param = params.into_iter().next().ok_or_else(|| invalid_params("missing params"))?;
+    serde_json::from_value(param).map_err(invalid_params)
+}
+
+fn invalid_params(e: impl fmt::Display) -> ErrorPayload {
+    ErrorPayload {
+        message: format!("invalid params: {e}").into(),
+        ..ErrorPayload::invalid_params()
+    }
+}
+
+fn decode_raw(raw: &Bytes) -> Result<TxEnvelope, ErrorPayload> {
+    TxEnvelope::decode_2718(&mut raw.as_ref()).map_err(invalid_params)
+}
+
+/// Builds the request of a signed seismic transaction, recovering its sender.
+fn signed_request(
+    tx: &TxEnvelope,
+    kind: MockSeismicRequestKind,
+    typed_data: bool,
+) -> Result<MockSeismicRequest, ErrorPayload> {
+    let (to, nonce, encryption_pubkey, input) = match tx {
+        TxEnvelope::Seismic(signed) => {
+            let tx = signed.tx();
+            (tx.to, tx.nonce, tx.encryption_pubkey, tx.input.clone())
+        }
+        TxEnvelope::SeismicEip1559(signed) => {
+            let tx = signed.tx();
+            (tx.to, tx.nonce, tx.encryption_pubkey, tx.input.clone())
+        }
+        tx => return Err(invalid_params(format!("not a seismic transaction: {}", tx.tx_type()))),
+    };
+    let from = tx.recover_signer().map_err(invalid_params)?;
+    Ok(MockSeismicRequest {
+        kind,
+        from: Some(from),
+        to,
+        nonce,
+        encryption_pubkey: Some(encryption_pubkey),
+        input,
+        typed_data,
+    })
+}
+
+/// The error the node returns when it fails to decrypt the input of a request, as sent on the
+/// wire rather than built from [`TEE_DECRYPTION_ERROR_CODE`](super::TEE_DECRYPTION_ERROR_CODE),
+/// so that tests notice when the two drift apart.
+fn decryption_error() -> ErrorPayload {
+    serde_json::from_str(r#"{"code":-32000,"message":"failed to decrypt transaction input"}"#)
+        .expect("valid error payload")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        is_tee_key_mismatch, test_utils::ContractTestContext, Provider, ProviderBuilder, SendableTx,
+    };
+    use alloy_network::{EthereumWallet, TransactionBuilder};
+    use alloy_primitives::bytes;
+    use alloy_rpc_client::BatchRequest;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn signed_requests_recover_sender() {
+        let node = MockSeismicNode::new();
+        let signer = PrivateKeySigner::random();
+        let from = signer.address();
+        let provider =
+            ProviderBuilder::new().seismic(EthereumWallet::from(signer)).on_client(node.client());
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        let tx = TransactionRequest::default()
+            .with_from(from)
+            .with_to(Address::with_last_byte(1))
+            .with_input(plaintext.clone());
+        let res = provider.seismic_signed_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, plaintext);
+
+        let tx_hash = *provider.send_transaction(tx).await.unwrap().tx_hash();
+        assert_eq!(node.transaction(&tx_hash).unwrap().from, from);
+
+        let requests = node.seismic_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].kind, MockSeismicRequestKind::SignedCall);
+        assert_eq!(requests[1].kind, MockSeismicRequestKind::Transaction);
+        for req in requests {
+            assert_eq!(req.from, Some(from));
+            assert_eq!(req.input, plaintext);
+            assert!(req.encryption_pubkey.is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn stale_key_is_rejected() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new().on_client(node.client());
+
+        let stale = node.tee_pubkey();
+        node.rotate_tee_key();
+        assert_ne!(node.tee_pubkey(), stale);
+
+        let client = EcdhCrypto.generate_key().unwrap();
+        let input = EcdhCrypto.encrypt(&stale, &client, &[1, 2, 3], 0).unwrap();
+        let tx = TransactionRequest {
+            nonce: Some(0),
+            encryption_pubkey: Some(EcdhCrypto.encryption_pubkey(&client)),
+            ..Default::default()
+        }
+        .with_input(input);
+        let err = provider.call(&tx).await.unwrap_err();
+        assert!(is_tee_key_mismatch(&err), "{err}");
+        assert!(node.seismic_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn static_responses_and_batches() {
+        let node = MockSeismicNode::new().with_chain_id(5124).with_response("eth_blockNumber", 7);
+        let provider = ProviderBuilder::new().on_client(node.client());
+
+        assert_eq!(provider.get_chain_id().await.unwrap(), 5124);
+        assert_eq!(provider.get_block_number().await.unwrap(), 7);
+        assert!(provider.client().request_noparams::<()>("eth_mining").await.is_err());
+
+        let client = node.client();
+        let mut batch = BatchRequest::new(&client);
+        let chain_id = batch.add_call::<_, U64>("eth_chainId", &()).unwrap();
+        let gas_price = batch.add_call::<_, U256>("eth_gasPrice", &()).unwrap();
+        batch.send().await.unwrap();
+        assert_eq!(chain_id.await.unwrap(), U64::from(5124));
+        assert_eq!(gas_price.await.unwrap(), U256::from(MockSeismicNode::GAS_PRICE));
+
+        assert_eq!(node.request_count("eth_chainId"), 2);
+        assert_eq!(node.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn unencrypted_calls_pass_through() {
+        let node = MockSeismicNode::new().with_handler(|req| Err(req.input.clone()));
+        let provider = ProviderBuilder::new().on_client(node.client());
+
+        let tx = TransactionRequest::default().with_input(bytes!("08c379a0"));
+        let err = provider.call(&tx).await.unwrap_err();
+        let payload = err.as_error_resp().unwrap();
+        assert_eq!(payload.as_revert_data(), Some(bytes!("08c379a0")));
+        assert_eq!(node.seismic_requests()[0].kind, MockSeismicRequestKind::Call);
+    }
+}
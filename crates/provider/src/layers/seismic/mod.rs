@@ -0,0 +1,1673 @@
+//! A provider layer that uses for filling sesimic transactions
+
+use crate::{
+    fillers::{FillProvider, JoinFill, NonceFiller, RecommendedFillers, WalletFiller},
+    Identity, PendingTransactionBuilder, Provider, ProviderBuilder, ProviderLayer, RootProvider,
+    SeismicCallOptions, SendableTx, Stack, WalletProvider,
+};
+use alloy_consensus::{Transaction, TxSeismic};
+use alloy_network::{Ethereum, EthereumWallet, Network, SeismicNetwork, TransactionBuilder};
+use alloy_primitives::{map::AddressHashSet, Address, Bytes, TxHash, TxKind};
+use alloy_rpc_types_eth::{TransactionInput, TransactionRequest};
+use alloy_transport::{Transport, TransportResult};
+use seismic_enclave::{rand, PublicKey};
+use std::{marker::PhantomData, ops::Deref, sync::Arc, time::Duration};
+use tracing::warn;
+
+mod batch;
+pub use batch::SeismicBatch;
+
+mod crypto;
+pub use crypto::{EcdhCrypto, SeismicCrypto, SeismicCryptoError};
+
+mod decrypt;
+use decrypt::decrypt_revert;
+pub use decrypt::DecryptedTransaction;
+
+mod key_store;
+pub use key_store::{
+    EncodableKey, EncryptionKeyStore, FileKeyStore, InMemoryKeyStore, StoredEncryptionKey,
+};
+
+#[cfg(any(test, feature = "seismic-mock"))]
+mod mock;
+#[cfg(any(test, feature = "seismic-mock"))]
+pub use mock::{MockCallResult, MockSeismicNode, MockSeismicRequest, MockSeismicRequestKind};
+
+mod error;
+pub use error::SeismicError;
+
+mod reencrypt;
+pub use reencrypt::EncryptionGuard;
+use reencrypt::{EncryptedInput, EncryptedInputs};
+
+mod typed_data;
+pub use typed_data::{SeismicTypedDataLayer, SeismicTypedDataProvider};
+
+mod tee_key;
+pub use tee_key::{
+    is_tee_key_mismatch, TeeKeyCache, DEFAULT_TEE_KEY_TTL, TEE_DECRYPTION_ERROR_CODE,
+    TEE_DECRYPTION_ERROR_MESSAGE,
+};
+
+#[cfg(feature = "ws")]
+use alloy_transport::TransportError;
+
+/// The layers installed by [`ProviderBuilder::seismic`] on top of `L`.
+///
+/// From the outermost in: a nonce filler, the [`SeismicLayer`], the
+/// recommended fillers joined with the [`EncryptionGuard`] of the layer and the
+/// signing filler `W`, and the [`SeismicTypedDataLayer`].
+pub type SeismicLayers<W, N = Ethereum, C = EcdhCrypto, L = Identity> = Stack<
+    SeismicTypedDataLayer,
+    Stack<
+        JoinFill<JoinFill<<N as RecommendedFillers>::RecommendedFillers, EncryptionGuard<C>>, W>,
+        Stack<SeismicLayer<C>, Stack<JoinFill<Identity, NonceFiller>, L>>,
+    >,
+>;
+
+/// A provider built by [`ProviderBuilder::seismic`] over the transport `T`.
+pub type SeismicStackProvider<T, W, N = Ethereum, C = EcdhCrypto> = FillProvider<
+    JoinFill<Identity, NonceFiller>,
+    SeismicProvider<
+        FillProvider<
+            JoinFill<
+                JoinFill<<N as RecommendedFillers>::RecommendedFillers, EncryptionGuard<C>>,
+                W,
+            >,
+            SeismicTypedDataProvider<RootProvider<T, N>, T, N>,
+            T,
+            N,
+        >,
+        T,
+        N,
+        C,
+    >,
+    T,
+    N,
+>;
+
+impl<L, F, N> ProviderBuilder<L, F, N>
+where
+    N: RecommendedFillers + SeismicNetwork,
+{
+    /// Installs the seismic layers, with `wallet` signing the encrypted
+    /// transactions.
+    ///
+    /// Works over any transport, e.g. a single websocket connection serves
+    /// both encrypted sends and subscriptions:
+    ///
+    /// ```no_run
+    /// # async fn example(wallet: alloy_network::EthereumWallet) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_provider::{Provider, ProviderBuilder};
+    ///
+    /// let provider = ProviderBuilder::new().seismic(wallet).on_builtin("ws://localhost:8546").await?;
+    /// let tee_pubkey = provider.get_tee_pubkey().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Transactions must be signed after their input is encrypted, so the
+    /// wallet has to be given here rather than with [`ProviderBuilder::wallet`],
+    /// whose filler is the outermost one.
+    pub fn seismic<W>(
+        self,
+        wallet: W,
+    ) -> ProviderBuilder<SeismicLayers<WalletFiller<W>, N, EcdhCrypto, L>, F, N> {
+        self.seismic_with(SeismicLayer::new(), WalletFiller::new(wallet))
+    }
+
+    /// Installs the seismic layers without a signer, transactions are sent
+    /// unsigned to the node.
+    pub fn seismic_unsigned(
+        self,
+    ) -> ProviderBuilder<SeismicLayers<Identity, N, EcdhCrypto, L>, F, N> {
+        self.seismic_with(SeismicLayer::new(), Identity)
+    }
+
+    /// Installs the seismic layers over the layers already added, with a
+    /// configured [`SeismicLayer`] and the signing filler `wallet`, e.g. a
+    /// [`WalletFiller`] or [`Identity`].
+    pub fn seismic_with<W, C: SeismicCrypto>(
+        self,
+        layer: SeismicLayer<C>,
+        wallet: W,
+    ) -> ProviderBuilder<SeismicLayers<W, N, C, L>, F, N> {
+        let guard = layer.encryption_guard();
+        self.layer(JoinFill::new(Identity, NonceFiller::default()))
+            .layer(layer)
+            .layer(JoinFill::new(JoinFill::new(N::recommended_fillers(), guard), wallet))
+            .layer(SeismicTypedDataLayer)
+    }
+}
+
+/// Seismic provider
+pub type SeismicSignedProviderInner = SeismicStackProvider<
+    alloy_transport_http::Http<alloy_transport_http::Client>,
+    WalletFiller<EthereumWallet>,
+>;
+
+/// Seismic signed provider
+#[derive(Debug, Clone)]
+pub struct SeismicSignedProvider(SeismicSignedProviderInner);
+
+impl SeismicSignedProvider {
+    /// Creates a new seismic signed provider
+    pub fn new(wallet: EthereumWallet, url: reqwest::Url) -> Self {
+        Self(ProviderBuilder::new().seismic(wallet).on_http(url))
+    }
+
+    /// Simulates a transaction signed by the wallet of the provider, see
+    /// [`SeismicProvider::signed_call`].
+    pub async fn signed_call(&self, tx: TransactionRequest) -> TransportResult<Bytes> {
+        self.0.inner.signed_call(tx).await
+    }
+}
+
+impl Deref for SeismicSignedProvider {
+    type Target = SeismicSignedProviderInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Seismic unsigned provider
+pub type SeismicUnsignedProviderInner =
+    SeismicStackProvider<alloy_transport_http::Http<alloy_transport_http::Client>, Identity>;
+
+/// Seismic unsigned provider
+#[derive(Debug, Clone)]
+pub struct SeismicUnsignedProvider(SeismicUnsignedProviderInner);
+
+impl SeismicUnsignedProvider {
+    /// Creates a new seismic unsigned provider
+    pub fn new(url: reqwest::Url) -> Self {
+        Self(ProviderBuilder::new().seismic_unsigned().on_http(url))
+    }
+}
+
+impl Deref for SeismicUnsignedProvider {
+    type Target = SeismicUnsignedProviderInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Seismic unsigned websocket provider
+#[cfg(feature = "ws")]
+pub type SeismicUnsignedWsProviderInner =
+    SeismicStackProvider<alloy_transport::BoxTransport, Identity>;
+
+#[cfg(feature = "ws")]
+/// Seismic unsigned websocket provider
+#[derive(Debug, Clone)]
+pub struct SeismicUnsignedWsProvider(pub SeismicUnsignedWsProviderInner);
+
+#[cfg(feature = "ws")]
+impl SeismicUnsignedWsProvider {
+    /// creates a new websocket provider for a client
+    pub async fn new(url: impl Into<String>) -> Result<Self, TransportError> {
+        let provider = ProviderBuilder::new().seismic_unsigned().on_builtin(&url.into()).await?;
+        Ok(Self(provider))
+    }
+
+    /// Get the inner provider
+    pub fn inner(&self) -> &SeismicUnsignedWsProviderInner {
+        &self.0
+    }
+}
+
+/// Get a seismic transaction builder
+pub fn build_seismic_tx(plaintext: Bytes, to: TxKind, from: Address) -> TransactionRequest {
+    TransactionRequest {
+        from: Some(from),
+        to: Some(to),
+        input: TransactionInput { input: Some(plaintext), data: None },
+        transaction_type: Some(TxSeismic::TX_TYPE),
+        ..Default::default()
+    }
+}
+
+/// What a [`SeismicProvider`] does with a request whose input can't be
+/// encrypted because its nonce is not set.
+///
+/// The input of seismic requests is encrypted with their nonce, which the
+/// node uses to decrypt it. Transactions must be encrypted with the nonce of
+/// their sender, but unsigned calls only need the nonce they carry to match
+/// the one their input was encrypted with. Signed calls with a `from` field
+/// are always encrypted with the pending nonce of their sender, the policy
+/// only applies to those whose sender is left to the signer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingNoncePolicy {
+    /// Calls are encrypted with a random nonce set in the request, transactions
+    /// are refused with [`SeismicError::MissingNonce`].
+    #[default]
+    RandomNonce,
+    /// Calls and transactions are refused with [`SeismicError::MissingNonce`].
+    Reject,
+    /// The input is sent in plaintext, with a warning logged for every
+    /// request.
+    Plaintext,
+}
+
+/// Seismic middlware for encrypting transactions and decrypting responses
+///
+/// The TEE public key of the node is cached and shared by every provider built
+/// from this layer, see [`TeeKeyCache`]. Inputs are encrypted with the
+/// [`SeismicCrypto`] backend `C`, which defaults to [`EcdhCrypto`].
+///
+/// Transactions signed over EIP-712 typed data are sent as raw bytes unless a
+/// [`SeismicTypedDataLayer`] is installed below the signer, as
+/// [`ProviderBuilder::seismic`] does.
+#[derive(Debug, Clone)]
+pub struct SeismicLayer<C: SeismicCrypto = EcdhCrypto> {
+    /// Cache for the TEE public key of the node.
+    tee_key: TeeKeyCache,
+    /// Encryption backend.
+    crypto: C,
+    /// Optional store for the keys of sent transactions.
+    key_store: Option<Arc<dyn EncryptionKeyStore<C::Key>>>,
+    /// Contracts that only accept signed calls.
+    authenticated_contracts: Arc<AddressHashSet>,
+    /// Inputs encrypted by the providers built from this layer.
+    inputs: EncryptedInputs<C::Key>,
+    /// Handling of requests without a nonce.
+    missing_nonce: MissingNoncePolicy,
+}
+
+impl<C: SeismicCrypto + Default> Default for SeismicLayer<C> {
+    fn default() -> Self {
+        Self {
+            tee_key: TeeKeyCache::default(),
+            crypto: C::default(),
+            key_store: None,
+            authenticated_contracts: Arc::default(),
+            inputs: EncryptedInputs::default(),
+            missing_nonce: MissingNoncePolicy::default(),
+        }
+    }
+}
+
+impl SeismicLayer {
+    /// Instantiate a new seismic layer, caching the TEE public key for
+    /// [`DEFAULT_TEE_KEY_TTL`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: SeismicCrypto> SeismicLayer<C> {
+    /// Replaces the encryption backend used by providers built from this layer.
+    ///
+    /// Any key store set with [`SeismicLayer::with_key_store`] is dropped, as it
+    /// holds keys of the previous backend.
+    pub fn with_crypto<C2: SeismicCrypto>(self, crypto: C2) -> SeismicLayer<C2> {
+        SeismicLayer {
+            tee_key: self.tee_key,
+            crypto,
+            key_store: None,
+            authenticated_contracts: self.authenticated_contracts,
+            inputs: EncryptedInputs::default(),
+            missing_nonce: self.missing_nonce,
+        }
+    }
+
+    /// Keeps the key of every encrypted transaction sent through the provider
+    /// in `store`, so its input can later be decrypted with
+    /// [`SeismicProvider::decrypt_own_transaction`].
+    pub fn with_key_store(mut self, store: impl EncryptionKeyStore<C::Key> + 'static) -> Self {
+        self.key_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Marks contracts whose view functions authenticate the caller, e.g. by
+    /// reading `msg.sender`.
+    ///
+    /// Unsigned calls to these contracts are executed with an unauthenticated
+    /// sender, so [`Provider::seismic_call`] signs the calls to them with the
+    /// signer of the provider, and refuses to send their shielded calldata
+    /// when there is none. Unsigned batches of calls to them are refused.
+    pub fn with_authenticated_contracts(
+        mut self,
+        contracts: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        Arc::make_mut(&mut self.authenticated_contracts).extend(contracts);
+        self
+    }
+
+    /// Sets what is done with calls and transactions whose input can't be
+    /// encrypted because their nonce is not set, by default calls are
+    /// encrypted with a random nonce. See [`MissingNoncePolicy`].
+    pub const fn with_missing_nonce_policy(mut self, policy: MissingNoncePolicy) -> Self {
+        self.missing_nonce = policy;
+        self
+    }
+
+    /// Returns the encryption backend.
+    pub const fn crypto(&self) -> &C {
+        &self.crypto
+    }
+
+    /// Sets how long a fetched TEE public key is reused before it is fetched
+    /// again. A TTL of [`Duration::ZERO`] fetches the key before every
+    /// encrypted request.
+    pub fn with_tee_key_ttl(mut self, ttl: Duration) -> Self {
+        self.tee_key = self.tee_key.with_ttl(ttl);
+        self
+    }
+
+    /// Sets the error the node returns when it fails to decrypt an input, which
+    /// makes providers fetch the TEE public key again and retry once. See
+    /// [`TeeKeyCache::with_decryption_error`].
+    pub fn with_tee_decryption_error(
+        mut self,
+        code: i64,
+        message: impl Into<std::borrow::Cow<'static, str>>,
+    ) -> Self {
+        self.tee_key = self.tee_key.with_decryption_error(code, message);
+        self
+    }
+
+    /// Returns the shared TEE public key cache.
+    pub fn tee_key_cache(&self) -> TeeKeyCache {
+        self.tee_key.clone()
+    }
+
+    /// Returns the filler re-encrypting the inputs of the providers built from
+    /// this layer when their nonce changes, see [`EncryptionGuard`].
+    ///
+    /// Only needed when the layer is installed by hand, the guard must be
+    /// joined after the fillers below the layer and before the signer.
+    pub fn encryption_guard(&self) -> EncryptionGuard<C> {
+        EncryptionGuard::new(self.crypto.clone(), self.inputs.clone())
+    }
+}
+
+impl<P, T, N, C> ProviderLayer<P, T, N> for SeismicLayer<C>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    type Provider = SeismicProvider<P, T, N, C>;
+
+    fn layer(&self, inner: P) -> Self::Provider {
+        SeismicProvider::new(
+            inner,
+            self.tee_key_cache(),
+            self.crypto.clone(),
+            self.key_store.clone(),
+            self.authenticated_contracts.clone(),
+            self.inputs.clone(),
+            self.missing_nonce,
+        )
+    }
+}
+
+/// Seismic middlware for encrypting transactions and decrypting responses
+#[derive(Debug, Clone)]
+pub struct SeismicProvider<P, T, N, C: SeismicCrypto = EcdhCrypto> {
+    /// Inner provider.
+    inner: P,
+    /// Cache for the TEE public key of the node.
+    tee_key: TeeKeyCache,
+    /// Encryption backend.
+    crypto: C,
+    /// Optional store for the keys of sent transactions.
+    key_store: Option<Arc<dyn EncryptionKeyStore<C::Key>>>,
+    /// Contracts that only accept signed calls.
+    authenticated_contracts: Arc<AddressHashSet>,
+    /// Inputs encrypted by this provider, shared with the [`EncryptionGuard`].
+    inputs: EncryptedInputs<C::Key>,
+    /// Handling of requests without a nonce.
+    missing_nonce: MissingNoncePolicy,
+    /// Phantom data
+    _pd: PhantomData<(T, N)>,
+}
+
+impl<P, T, N, C> SeismicProvider<P, T, N, C>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    /// Create a new seismic provider
+    const fn new(
+        inner: P,
+        tee_key: TeeKeyCache,
+        crypto: C,
+        key_store: Option<Arc<dyn EncryptionKeyStore<C::Key>>>,
+        authenticated_contracts: Arc<AddressHashSet>,
+        inputs: EncryptedInputs<C::Key>,
+        missing_nonce: MissingNoncePolicy,
+    ) -> Self {
+        Self {
+            inner,
+            tee_key,
+            crypto,
+            key_store,
+            authenticated_contracts,
+            inputs,
+            missing_nonce,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Returns whether calls to `contract` must be signed, see
+    /// [`SeismicLayer::with_authenticated_contracts`].
+    pub fn requires_signed_call(&self, contract: &Address) -> bool {
+        self.authenticated_contracts.contains(contract)
+    }
+
+    /// Returns the encryption backend.
+    pub const fn crypto(&self) -> &C {
+        &self.crypto
+    }
+
+    /// Returns the shared TEE public key cache.
+    pub const fn tee_key_cache(&self) -> &TeeKeyCache {
+        &self.tee_key
+    }
+
+    /// Get the TEE public key, served from the cache if it has not expired.
+    pub async fn tee_pubkey(&self) -> TransportResult<PublicKey> {
+        match self.tee_key.get() {
+            Some(tee_pubkey) => Ok(tee_pubkey),
+            None => self.refresh_tee_pubkey().await,
+        }
+    }
+
+    /// Fetch the TEE public key from the node, replacing the cached key.
+    pub async fn refresh_tee_pubkey(&self) -> TransportResult<PublicKey> {
+        let tee_pubkey = PublicKey::from_slice(
+            self.inner.get_tee_pubkey().await.map_err(SeismicError::TeePubkey)?.as_slice(),
+        )
+        .map_err(|e| SeismicError::InvalidTeePubkey(e.into()))?;
+        self.tee_key.set(tee_pubkey);
+        Ok(tee_pubkey)
+    }
+
+    /// Drop the cached TEE public key, the next encrypted request fetches it again.
+    pub fn invalidate_tee_pubkey(&self) {
+        self.tee_key.invalidate();
+    }
+
+    /// Generate a fresh client key for an encrypted request.
+    pub fn generate_encryption_key(&self) -> TransportResult<C::Key> {
+        self.crypto.generate_key().map_err(|e| SeismicError::KeyGeneration(e).into())
+    }
+
+    /// Should encrypt input
+    pub fn should_encrypt_input<B: TransactionBuilder<N>>(&self, tx: &B) -> bool {
+        tx.input().map_or(false, |input| !input.is_empty()) && tx.nonce().is_some()
+    }
+
+    /// Applies the [`MissingNoncePolicy`] to a request with input but no nonce,
+    /// `call` telling whether it is a call or a transaction.
+    fn fill_missing_nonce(
+        &self,
+        builder: &mut N::TransactionRequest,
+        call: bool,
+    ) -> TransportResult<()> {
+        if builder.nonce().is_some() || builder.input().map_or(true, |input| input.is_empty()) {
+            return Ok(());
+        }
+        match self.missing_nonce {
+            MissingNoncePolicy::RandomNonce if call => builder.set_nonce(rand::random()),
+            MissingNoncePolicy::Plaintext => {
+                warn!(call, "no nonce to encrypt the input with, sending it in plaintext")
+            }
+            _ => return Err(SeismicError::MissingNonce.into()),
+        }
+        Ok(())
+    }
+
+    /// Returns the plaintext input of the transaction.
+    ///
+    /// The input is always taken as plaintext, the provider forgets the inputs
+    /// it encrypted once they are sent. To resubmit a sent transaction, e.g.
+    /// with a bumped nonce, set its input back to the plaintext returned by
+    /// [`decrypt_own_transaction`](Self::decrypt_own_transaction).
+    fn plaintext_input(&self, builder: &N::TransactionRequest) -> Bytes {
+        builder.input().cloned().unwrap_or_default()
+    }
+
+    /// Encrypts `plaintext` to the TEE public key with a freshly generated
+    /// key and sets it as the input of the transaction.
+    ///
+    /// The plaintext is remembered, so the input can be re-encrypted by the
+    /// [`EncryptionGuard`] if the nonce changes before the transaction is
+    /// signed.
+    ///
+    /// Returns the generated key, which is needed to decrypt the response.
+    fn encrypt_input(
+        &self,
+        builder: &mut N::TransactionRequest,
+        plaintext: &Bytes,
+        tee_pubkey: &PublicKey,
+    ) -> TransportResult<C::Key> {
+        // Generate new key for this transaction
+        let encryption_key = self.generate_encryption_key()?;
+        let encryption_pubkey = self.crypto.encryption_pubkey(&encryption_key);
+        builder.set_encryption_pubkey(encryption_pubkey);
+
+        // Encrypt using recipient's public key and generated key
+        let nonce = builder.nonce().unwrap();
+        let encrypted_input = self
+            .crypto
+            .encrypt(tee_pubkey, &encryption_key, plaintext, nonce)
+            .map_err(|source| SeismicError::Encryption { nonce, source })?;
+        builder.set_input(encrypted_input.clone());
+
+        self.inputs.insert(
+            encryption_pubkey,
+            EncryptedInput {
+                plaintext: plaintext.clone(),
+                ciphertext: encrypted_input,
+                nonce,
+                key: encryption_key.clone(),
+                tee_pubkey: *tee_pubkey,
+            },
+        );
+        Ok(encryption_key)
+    }
+
+    /// Decrypts the input of a seismic transaction encrypted with `key`, e.g. a
+    /// transaction fetched from the node or a [`TxEnvelope::Seismic`].
+    ///
+    /// [`TxEnvelope::Seismic`]: alloy_consensus::TxEnvelope::Seismic
+    pub fn decrypt_transaction<Tx: Transaction>(
+        &self,
+        tx: Tx,
+        key: &StoredEncryptionKey<C::Key>,
+    ) -> TransportResult<DecryptedTransaction<Tx>> {
+        let encryption_pubkey = tx.encryption_pubkey().ok_or(SeismicError::NotSeismic)?;
+        if *encryption_pubkey != self.crypto.encryption_pubkey(&key.key) {
+            return Err(SeismicError::KeyMismatch.into());
+        }
+
+        let nonce = tx.nonce();
+        let input = self
+            .crypto
+            .decrypt(&key.tee_pubkey, &key.key, tx.input(), nonce)
+            .map_err(|source| SeismicError::Decryption { nonce, source })?;
+        Ok(DecryptedTransaction { tx, input })
+    }
+
+    /// Decrypts the input of a transaction sent through this provider.
+    ///
+    /// Requires a key store set with [`SeismicLayer::with_key_store`] at the
+    /// time the transaction was sent.
+    pub async fn decrypt_own_transaction(&self, tx_hash: TxHash) -> TransportResult<Bytes> {
+        let key_store = self.key_store.as_ref().ok_or(SeismicError::NoKeyStore)?;
+        let stored =
+            key_store.get(&tx_hash)?.ok_or(SeismicError::UnknownTransactionKey(tx_hash))?;
+
+        let tx = self
+            .inner
+            .get_transaction_by_hash(tx_hash)
+            .await?
+            .ok_or(SeismicError::TransactionNotFound(tx_hash))?;
+        Ok(self.decrypt_transaction(tx, &stored)?.input)
+    }
+
+    /// Encrypts the input of `tx`, executes it via `eth_call` and decrypts the
+    /// output.
+    ///
+    /// If `signed` is set, the inner provider must sign the transaction, see
+    /// [`Provider::seismic_signed_call`].
+    async fn encrypted_call(
+        &self,
+        mut tx: SendableTx<N>,
+        signed: bool,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        if let Some(builder) = tx.as_mut_builder() {
+            match builder.from() {
+                // the signature covers the nonce, use the one of the sender
+                Some(from)
+                    if signed
+                        && builder.nonce().is_none()
+                        && builder.input().is_some_and(|input| !input.is_empty()) =>
+                {
+                    builder.set_nonce(self.inner.get_transaction_count(from).pending().await?);
+                }
+                _ => self.fill_missing_nonce(builder, true)?,
+            }
+            if self.should_encrypt_input(builder) {
+                let plaintext_input = self.plaintext_input(builder);
+                let mut tee_pubkey = self.tee_pubkey().await?;
+                let mut retried = false;
+
+                loop {
+                    let encryption_key =
+                        self.encrypt_input(builder, &plaintext_input, &tee_pubkey)?;
+
+                    let call = SendableTx::Builder(builder.clone());
+                    let result = if signed {
+                        self.inner.seismic_signed_call_with(call, options.clone()).await
+                    } else {
+                        self.inner.seismic_call_with(call, options.clone()).await
+                    };
+                    // the output is bound to the nonce the input was last encrypted with
+                    let nonce = self
+                        .inputs
+                        .remove(&self.crypto.encryption_pubkey(&encryption_key))
+                        .map_or_else(|| builder.nonce().unwrap(), |input| input.nonce);
+                    let encrypted_output = match result {
+                        Ok(encrypted_output) => encrypted_output,
+                        Err(err) if !retried && self.tee_key.is_key_mismatch(&err) => {
+                            // the enclave key may have been rotated, retry once with the new key
+                            match self.rotated_tee_pubkey(&tee_pubkey).await? {
+                                Some(rotated) => tee_pubkey = rotated,
+                                None => return Err(err),
+                            }
+                            retried = true;
+                            continue;
+                        }
+                        Err(err) => {
+                            // the node encrypts the revert data like the output
+                            return Err(decrypt_revert(
+                                &self.crypto,
+                                &tee_pubkey,
+                                &encryption_key,
+                                nonce,
+                                err,
+                            ));
+                        }
+                    };
+
+                    // Decrypt the output using the encryption key
+                    return self
+                        .crypto
+                        .decrypt(&tee_pubkey, &encryption_key, &encrypted_output, nonce)
+                        .map_err(|source| SeismicError::Decryption { nonce, source }.into());
+                }
+            }
+        }
+        if signed {
+            self.inner.seismic_signed_call_with(tx, options).await
+        } else {
+            self.inner.seismic_call_with(tx, options).await
+        }
+    }
+
+    /// Starts a batch of unsigned calls, encrypted against a single TEE public
+    /// key and sent as one JSON-RPC batch.
+    ///
+    /// ```no_run
+    /// # async fn example(calls: Vec<alloy_rpc_types_eth::TransactionRequest>) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_provider::{ProviderBuilder, SeismicLayer};
+    ///
+    /// let provider =
+    ///     ProviderBuilder::new().layer(SeismicLayer::new()).on_http("http://localhost:8545".parse()?);
+    /// let batch = calls.into_iter().fold(provider.seismic_batch(), |batch, call| batch.add_call(call));
+    /// for output in batch.send().await? {
+    ///     println!("{:?}", output);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn seismic_batch(&self) -> SeismicBatch<'_, P, T, N, C> {
+        SeismicBatch::new(self)
+    }
+
+    /// Returns the authenticated contract `tx` sends shielded calldata to, if
+    /// any.
+    fn authenticated_call_target(&self, tx: &N::TransactionRequest) -> Option<Address> {
+        match tx.kind() {
+            Some(TxKind::Call(to))
+                if self.requires_signed_call(&to)
+                    && tx.input().is_some_and(|input| !input.is_empty()) =>
+            {
+                Some(to)
+            }
+            _ => None,
+        }
+    }
+
+    /// Refuses to send shielded calldata unsigned to an authenticated contract.
+    fn check_unsigned_call(&self, tx: &N::TransactionRequest) -> TransportResult<()> {
+        self.authenticated_call_target(tx)
+            .map_or(Ok(()), |to| Err(SeismicError::UnsignedAuthenticatedCall(to).into()))
+    }
+
+    /// Called after the node rejected a ciphertext encrypted to `stale`.
+    ///
+    /// Fetches the TEE public key again and returns it if it was rotated, in
+    /// which case the request should be re-encrypted and retried.
+    async fn rotated_tee_pubkey(&self, stale: &PublicKey) -> TransportResult<Option<PublicKey>> {
+        self.invalidate_tee_pubkey();
+        let tee_pubkey = self.refresh_tee_pubkey().await?;
+        Ok((tee_pubkey != *stale).then_some(tee_pubkey))
+    }
+}
+
+impl<P, T, N, C> SeismicProvider<P, T, N, C>
+where
+    P: Provider<T, N> + WalletProvider<N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    /// Simulates a transaction signed by the wallet of the inner provider.
+    ///
+    /// The input is encrypted like in [`Provider::seismic_call`], the inner
+    /// provider fills and signs the transaction, which is submitted to
+    /// `eth_call` as raw bytes or, for transactions signed over EIP-712 typed
+    /// data, in typed data form. The output is decrypted before returning.
+    ///
+    /// Unlike an unsigned call, the node executes the call with the signer as
+    /// `msg.sender`, which is required by
+    /// [authenticated contracts](SeismicLayer::with_authenticated_contracts).
+    pub async fn signed_call(&self, mut tx: N::TransactionRequest) -> TransportResult<Bytes> {
+        let from = tx.from().unwrap_or_else(|| self.inner.default_signer_address());
+        tx.set_from(from);
+        // the nonce is part of the encryption, fill it before encrypting
+        if tx.nonce().is_none() {
+            tx.set_nonce(self.inner.get_transaction_count(from).pending().await?);
+        }
+        self.seismic_signed_call(SendableTx::Builder(tx)).await
+    }
+}
+
+/// Implement the Provider trait for the SeismicProvider
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, T, N, C> Provider<T, N> for SeismicProvider<P, T, N, C>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: Network,
+    C: SeismicCrypto,
+{
+    fn root(&self) -> &RootProvider<T, N> {
+        self.inner.root()
+    }
+
+    async fn seismic_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        if let Some(to) = tx.as_builder().and_then(|tx| self.authenticated_call_target(tx)) {
+            // authenticated contracts only see the sender of signed calls
+            return match self.encrypted_call(tx, true, options).await {
+                Err(err)
+                    if matches!(
+                        SeismicError::from_transport_err(&err),
+                        Some(SeismicError::SignerRequired)
+                    ) =>
+                {
+                    Err(SeismicError::UnsignedAuthenticatedCall(to).into())
+                }
+                result => result,
+            };
+        }
+        self.encrypted_call(tx, false, options).await
+    }
+
+    async fn seismic_signed_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        self.encrypted_call(tx, true, options).await
+    }
+
+    async fn send_transaction_internal(
+        &self,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            self.fill_missing_nonce(builder, false)?;
+            if self.should_encrypt_input(builder) {
+                let plaintext_input = self.plaintext_input(builder);
+                let mut tee_pubkey = self.tee_pubkey().await?;
+                let mut retried = false;
+
+                loop {
+                    let encryption_key =
+                        self.encrypt_input(builder, &plaintext_input, &tee_pubkey)?;
+
+                    // sent through the inner fillers, so the input is re-encrypted if
+                    // they change the nonce before signing
+                    let result = self
+                        .inner
+                        .send_transaction_internal(SendableTx::Builder(builder.clone()))
+                        .await;
+                    // the plaintext is not kept once the transaction is signed, only the
+                    // key is, in the key store
+                    self.inputs.remove(&self.crypto.encryption_pubkey(&encryption_key));
+                    match result {
+                        Err(err) if !retried && self.tee_key.is_key_mismatch(&err) => {
+                            // the enclave key may have been rotated, retry once with the new key
+                            match self.rotated_tee_pubkey(&tee_pubkey).await? {
+                                Some(rotated) => tee_pubkey = rotated,
+                                None => return Err(err),
+                            }
+                            retried = true;
+                        }
+                        Ok(pending) => {
+                            if let Some(key_store) = &self.key_store {
+                                let stored =
+                                    StoredEncryptionKey { key: encryption_key, tee_pubkey };
+                                // the transaction is already sent, don't fail because of the store
+                                if let Err(err) = key_store.insert(*pending.tx_hash(), stored) {
+                                    warn!(%err, tx=%pending.tx_hash(), "failed to store encryption key");
+                                }
+                            }
+                            return Ok(pending);
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+        }
+        self.inner.send_transaction_internal(tx).await
+    }
+}
+
+/// Utilities for testing seismic provider
+pub mod test_utils {
+    use alloy_primitives::{hex, Bytes};
+
+    /// Test context for seismic provider
+    #[derive(Debug)]
+    pub struct ContractTestContext;
+    impl ContractTestContext {
+        // ==================== first block for encrypted transaction ====================
+        // Contract deployed
+        //     pragma solidity ^0.8.13;
+        // contract SeismicCounter {
+        //     suint256 number;
+        //     constructor() payable {
+        //         number = 0;
+        //     }
+        //     function setNumber(suint256 newNumber) public {
+        //         number = newNumber;
+        //     }
+        //     function increment() public {
+        //         number++;
+        //     }
+        //     function isOdd() public view returns (bool) {
+        //         return number % 2 == 1;
+        //     }
+        // }
+        /// Get the is odd input plaintext
+        pub fn get_is_odd_input_plaintext() -> Bytes {
+            Bytes::from_static(&hex!("43bd0d70"))
+        }
+
+        /// Get the set number input plaintext
+        pub fn get_set_number_input_plaintext() -> Bytes {
+            Bytes::from_static(&hex!(
+                "24a7f0b70000000000000000000000000000000000000000000000000000000000000003"
+            ))
+        }
+
+        /// Get the increment input plaintext
+        pub fn get_increment_input_plaintext() -> Bytes {
+            Bytes::from_static(&hex!("d09de08a"))
+        }
+
+        /// Get the deploy input plaintext
+        pub fn get_deploy_input_plaintext() -> Bytes {
+            Bytes::from_static(&hex!("60806040525f5f8190b150610285806100175f395ff3fe608060405234801561000f575f5ffd5b506004361061003f575f3560e01c806324a7f0b71461004357806343bd0d701461005f578063d09de08a1461007d575b5f5ffd5b61005d600480360381019061005891906100f6565b610087565b005b610067610090565b604051610074919061013b565b60405180910390f35b6100856100a7565b005b805f8190b15050565b5f600160025fb06100a19190610181565b14905090565b5f5f81b0809291906100b8906101de565b919050b150565b5f5ffd5b5f819050919050565b6100d5816100c3565b81146100df575f5ffd5b50565b5f813590506100f0816100cc565b92915050565b5f6020828403121561010b5761010a6100bf565b5b5f610118848285016100e2565b91505092915050565b5f8115159050919050565b61013581610121565b82525050565b5f60208201905061014e5f83018461012c565b92915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601260045260245ffd5b5f61018b826100c3565b9150610196836100c3565b9250826101a6576101a5610154565b5b828206905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601160045260245ffd5b5f6101e8826100c3565b91507fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff820361021a576102196101b1565b5b60018201905091905056fea2646970667358221220ea421d58b6748a9089335034d76eb2f01bceafe3dfac2e57d9d2e766852904df64736f6c63782c302e382e32382d646576656c6f702e323032342e31322e392b636f6d6d69742e39383863313261662e6d6f64005d"))
+        }
+
+        /// Results from solc compilation
+        pub fn get_code() -> Bytes {
+            Bytes::from_static(&hex!("608060405234801561000f575f5ffd5b506004361061003f575f3560e01c806324a7f0b71461004357806343bd0d701461005f578063d09de08a1461007d575b5f5ffd5b61005d600480360381019061005891906100f6565b610087565b005b610067610090565b604051610074919061013b565b60405180910390f35b6100856100a7565b005b805f8190b15050565b5f600160025fb06100a19190610181565b14905090565b5f5f81b0809291906100b8906101de565b919050b150565b5f5ffd5b5f819050919050565b6100d5816100c3565b81146100df575f5ffd5b50565b5f813590506100f0816100cc565b92915050565b5f6020828403121561010b5761010a6100bf565b5b5f610118848285016100e2565b91505092915050565b5f8115159050919050565b61013581610121565b82525050565b5f60208201905061014e5f83018461012c565b92915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601260045260245ffd5b5f61018b826100c3565b9150610196836100c3565b9250826101a6576101a5610154565b5b828206905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601160045260245ffd5b5f6101e8826100c3565b91507fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff820361021a576102196101b1565b5b60018201905091905056fea2646970667358221220ea421d58b6748a9089335034d76eb2f01bceafe3dfac2e57d9d2e766852904df64736f6c63782c302e382e32382d646576656c6f702e323032342e31322e392b636f6d6d69742e39383863313261662e6d6f64005d"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{Transaction as _, TxEnvelope};
+    use alloy_json_rpc::RpcError;
+    use alloy_network::{AnyNetwork, AnyTxEnvelope, Ethereum, EthereumWallet, ReceiptResponse};
+    use alloy_node_bindings::{Anvil, AnvilInstance};
+    use alloy_primitives::{hex, Address, FixedBytes, TxKind};
+    use alloy_serde::WithOtherFields;
+    use alloy_signer_local::PrivateKeySigner;
+    use seismic_enclave::{rand, Keypair, Secp256k1};
+    use std::{
+        collections::HashSet,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use crate::{
+        fillers::{FillerControlFlow, TxFiller},
+        test_utils::*,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_seismic_signed_call() {
+        let plaintext = ContractTestContext::get_deploy_input_plaintext();
+        let anvil = Anvil::new().spawn();
+        let wallet = get_wallet(&anvil);
+        let provider = SeismicSignedProvider::new(wallet.clone(), anvil.endpoint_url());
+
+        let from = wallet.default_signer().address();
+        let tx = build_seismic_tx(plaintext, TxKind::Create, from);
+
+        let res = provider.seismic_call(SendableTx::Builder(tx)).await.unwrap();
+
+        assert_eq!(res, ContractTestContext::get_code());
+    }
+
+    #[tokio::test]
+    async fn test_seismic_unsigned_call() {
+        let plaintext = ContractTestContext::get_deploy_input_plaintext();
+        let anvil = Anvil::new().spawn();
+
+        let unsigned_provider = SeismicUnsignedProvider::new(anvil.endpoint_url());
+
+        let mut tx = build_seismic_tx(plaintext, TxKind::Create, Address::ZERO);
+        tx.gas_price = None;
+
+        let res = unsigned_provider.seismic_call(SendableTx::Builder(tx)).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_code());
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction() {
+        let plaintext = ContractTestContext::get_deploy_input_plaintext();
+        let anvil = Anvil::new().spawn();
+        let wallet = get_wallet(&anvil);
+        let provider = SeismicSignedProvider::new(wallet.clone(), anvil.endpoint_url());
+        let from = wallet.default_signer().address();
+
+        // testing send transaction
+        let tx = TransactionRequest {
+            input: TransactionInput { input: Some(plaintext), data: None },
+            from: Some(from),
+            to: Some(TxKind::Create),
+            ..Default::default()
+        };
+
+        let contract_address = provider
+            .send_transaction(tx)
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap()
+            .contract_address
+            .unwrap();
+
+        let code = provider.get_code_at(contract_address).await.unwrap();
+        assert_eq!(code, ContractTestContext::get_code());
+    }
+
+    #[tokio::test]
+    async fn test_send_transaction_with_emtpy_input() {
+        let plaintext = Bytes::new();
+        let anvil = Anvil::new().spawn();
+        let wallet = get_wallet(&anvil);
+        let provider = SeismicSignedProvider::new(wallet.clone(), anvil.endpoint_url());
+        let from = wallet.default_signer().address();
+
+        let tx = TransactionRequest::default()
+            .with_input(plaintext)
+            .with_from(from)
+            .with_to(Address::ZERO);
+
+        let res = provider.send_transaction(tx).await.unwrap();
+        let receipt = res.get_receipt().await.unwrap();
+        assert_eq!(receipt.status(), true);
+    }
+
+    fn get_wallet(anvil: &AnvilInstance) -> EthereumWallet {
+        let bob: PrivateKeySigner = anvil.keys()[1].clone().into();
+        let wallet = EthereumWallet::from(bob.clone());
+        wallet
+    }
+
+    #[tokio::test]
+    async fn test_get_tee_pubkey() {
+        let provider =
+            ProviderBuilder::new().network::<Ethereum>().layer(SeismicLayer::new()).on_anvil();
+        let tee_pubkey = provider.get_tee_pubkey().await.unwrap();
+        println!("test_get_tee_pubkey: tee_pubkey: {:?}", tee_pubkey);
+    }
+
+    /// Selector of `Error(string)`.
+    const REVERT_SELECTOR: [u8; 4] = hex!("08c379a0");
+
+    fn signed_calls(node: &MockSeismicNode) -> usize {
+        node.seismic_requests()
+            .iter()
+            .filter(|req| req.kind == MockSeismicRequestKind::SignedCall)
+            .count()
+    }
+
+    fn mock_seismic_call_tx() -> TransactionRequest {
+        let mut tx = build_seismic_tx(
+            ContractTestContext::get_is_odd_input_plaintext(),
+            TxKind::Call(Address::ZERO),
+            Address::ZERO,
+        );
+        tx.nonce = Some(0);
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_tee_pubkey_is_cached() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+
+        for _ in 0..3 {
+            let res =
+                provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap();
+            assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        }
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 1);
+        assert_eq!(node.request_count("eth_call"), 3);
+
+        // explicit refresh always goes to the node
+        provider.refresh_tee_pubkey().await.unwrap();
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_tee_pubkey_zero_ttl() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_tee_key_ttl(Duration::ZERO))
+            .on_client(node.client());
+
+        for _ in 0..3 {
+            provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap();
+        }
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_tee_pubkey_rotation_retries() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+
+        provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap();
+        let stale = provider.tee_key_cache().get().unwrap();
+
+        node.rotate_tee_key();
+
+        // the first attempt is rejected, the key is fetched again and the call retried
+        let res = provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 2);
+        assert_eq!(node.request_count("eth_call"), 3);
+        assert_ne!(provider.tee_key_cache().get().unwrap(), stale);
+    }
+
+    #[tokio::test]
+    async fn test_seismic_batch() {
+        let node = MockSeismicNode::new().with_handler(|req| {
+            if req.input.starts_with(&REVERT_SELECTOR) {
+                Err(req.input.clone())
+            } else {
+                Ok(req.input.clone())
+            }
+        });
+        let contract = Address::with_last_byte(1);
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_authenticated_contracts([contract]))
+            .on_client(node.client());
+
+        let revert = Bytes::from([&REVERT_SELECTOR[..], &[0u8; 32]].concat());
+        let mut reverting = mock_seismic_call_tx();
+        reverting.input = TransactionInput::new(revert.clone());
+        let mut authenticated = mock_seismic_call_tx();
+        authenticated.to = Some(TxKind::Call(contract));
+        let mut without_nonce = mock_seismic_call_tx();
+        without_nonce.nonce = None;
+
+        let batch = provider
+            .seismic_batch()
+            .add_call(mock_seismic_call_tx())
+            .add_call(reverting)
+            .add_call(authenticated)
+            .add_call(without_nonce)
+            .add_call(mock_seismic_call_tx());
+        assert_eq!(batch.len(), 5);
+        let results = batch.send().await.unwrap();
+
+        let plaintext = ContractTestContext::get_is_odd_input_plaintext();
+        assert_eq!(results[0].as_ref().unwrap(), &plaintext);
+        let Err(RpcError::ErrorResp(payload)) = &results[1] else { panic!("{:?}", results[1]) };
+        assert_eq!(payload.as_revert_data(), Some(revert));
+        assert!(matches!(
+            results[2].as_ref().map_err(SeismicError::from_transport_err),
+            Err(Some(SeismicError::UnsignedAuthenticatedCall(_)))
+        ));
+        assert_eq!(results[3].as_ref().unwrap(), &plaintext);
+        assert_eq!(results[4].as_ref().unwrap(), &plaintext);
+
+        // one key for the batch, a client key per call
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 1);
+        assert_eq!(node.request_count("eth_call"), 4);
+        let keys: HashSet<_> =
+            node.seismic_requests().iter().filter_map(|req| req.encryption_pubkey).collect();
+        assert_eq!(keys.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_missing_nonce_policy() {
+        let mut tx = mock_seismic_call_tx();
+        tx.nonce = None;
+        let plaintext = ContractTestContext::get_is_odd_input_plaintext();
+
+        // by default calls are encrypted with a random nonce, transactions are refused
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+        let res = provider.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, plaintext);
+        let call = node.seismic_requests().pop().unwrap();
+        assert!(call.encryption_pubkey.is_some());
+        assert_eq!(call.input, plaintext);
+        let err = provider.send_transaction(tx.clone()).await.unwrap_err();
+        assert!(matches!(SeismicError::from_transport_err(&err), Some(SeismicError::MissingNonce)));
+        assert!(node.transactions().is_empty());
+
+        // calls can be refused as well
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_missing_nonce_policy(MissingNoncePolicy::Reject))
+            .on_client(node.client());
+        let err = provider.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap_err();
+        assert!(matches!(SeismicError::from_transport_err(&err), Some(SeismicError::MissingNonce)));
+        assert_eq!(node.request_count("eth_call"), 0);
+
+        // or sent in plaintext when explicitly allowed
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_missing_nonce_policy(MissingNoncePolicy::Plaintext))
+            .on_client(node.client());
+        let res = provider.seismic_call(SendableTx::Builder(tx)).await.unwrap();
+        assert_eq!(res, plaintext);
+        let call = node.seismic_requests().pop().unwrap();
+        assert_eq!(call.encryption_pubkey, None);
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_signed_call_uses_pending_nonce() {
+        let node = MockSeismicNode::new();
+        let signer = PrivateKeySigner::random();
+        let mut tx = mock_seismic_call_tx();
+        tx.from = Some(signer.address());
+        tx.nonce = None;
+        tx.chain_id = Some(1);
+        tx.gas = Some(100_000);
+        tx.gas_price = Some(1);
+
+        // the random nonce policy only applies to unsigned calls
+        let provider =
+            ProviderBuilder::new().seismic(EthereumWallet::from(signer)).on_client(node.client());
+        let res = provider.seismic_signed_call(SendableTx::Builder(tx)).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        let call = node.seismic_requests().pop().unwrap();
+        assert_eq!(call.kind, MockSeismicRequestKind::SignedCall);
+        assert_eq!(call.nonce, 0);
+        assert_eq!(node.request_count("eth_getTransactionCount"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_seismic_batch_key_rotation() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+        provider.tee_pubkey().await.unwrap();
+        node.rotate_tee_key();
+
+        // the calls rejected with the stale key are retried with the rotated one
+        let results = provider
+            .seismic_batch()
+            .add_call(mock_seismic_call_tx())
+            .add_call(mock_seismic_call_tx())
+            .send()
+            .await
+            .unwrap();
+        for result in results {
+            assert_eq!(result.unwrap(), ContractTestContext::get_is_odd_input_plaintext());
+        }
+        assert_eq!(node.request_count("seismic_getTeePublicKey"), 2);
+        assert_eq!(node.request_count("eth_call"), 4);
+    }
+
+    /// A backend using the same client key for every request.
+    #[derive(Clone, Debug)]
+    struct FixedKeyCrypto {
+        key: Keypair,
+        generated: Arc<AtomicUsize>,
+    }
+
+    impl SeismicCrypto for FixedKeyCrypto {
+        type Key = Keypair;
+
+        fn generate_key(&self) -> Result<Keypair, SeismicCryptoError> {
+            self.generated.fetch_add(1, Ordering::SeqCst);
+            Ok(self.key)
+        }
+
+        fn encryption_pubkey(&self, key: &Keypair) -> FixedBytes<33> {
+            EcdhCrypto.encryption_pubkey(key)
+        }
+
+        fn encrypt(
+            &self,
+            tee_pubkey: &PublicKey,
+            key: &Keypair,
+            plaintext: &[u8],
+            nonce: u64,
+        ) -> Result<Bytes, SeismicCryptoError> {
+            EcdhCrypto.encrypt(tee_pubkey, key, plaintext, nonce)
+        }
+
+        fn decrypt(
+            &self,
+            tee_pubkey: &PublicKey,
+            key: &Keypair,
+            ciphertext: &[u8],
+            nonce: u64,
+        ) -> Result<Bytes, SeismicCryptoError> {
+            EcdhCrypto.decrypt(tee_pubkey, key, ciphertext, nonce)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_crypto() {
+        let node = MockSeismicNode::new();
+        let crypto = FixedKeyCrypto {
+            key: Keypair::new(&Secp256k1::new(), &mut rand::thread_rng()),
+            generated: Arc::default(),
+        };
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_crypto(crypto.clone()))
+            .on_client(node.client());
+
+        let mut tx = mock_seismic_call_tx();
+        let res = provider.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(crypto.generated.load(Ordering::SeqCst), 1);
+
+        // the request is encrypted with the key of the backend
+        let tee_pubkey = provider.tee_pubkey().await.unwrap();
+        let key = provider.generate_encryption_key().unwrap();
+        provider
+            .encrypt_input(&mut tx, &ContractTestContext::get_is_odd_input_plaintext(), &tee_pubkey)
+            .unwrap();
+        assert_eq!(tx.encryption_pubkey, Some(FixedBytes(key.public_key().serialize())));
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_own_transaction() {
+        let node = MockSeismicNode::new();
+        let key_store = InMemoryKeyStore::new();
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_key_store(key_store.clone()))
+            .on_client(node.client());
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        let mut tx = mock_seismic_call_tx();
+        tx.input = TransactionInput::new(plaintext.clone());
+        let pending = provider.send_transaction(tx).await.unwrap();
+        let tx_hash = *pending.tx_hash();
+
+        // the transaction was sent encrypted
+        let sent = provider.get_transaction_by_hash(tx_hash).await.unwrap().unwrap();
+        assert_ne!(sent.input(), &plaintext);
+        assert!(key_store.get(&tx_hash).unwrap().is_some());
+
+        assert_eq!(provider.decrypt_own_transaction(tx_hash).await.unwrap(), plaintext);
+
+        // the envelope decrypts with the stored key, but not with another one
+        let stored = key_store.get(&tx_hash).unwrap().unwrap();
+        let decrypted = provider.decrypt_transaction(sent.inner.clone(), &stored).unwrap();
+        assert_eq!(decrypted.input, plaintext);
+        assert_eq!(decrypted.tx, sent.inner);
+        let other =
+            StoredEncryptionKey { key: provider.generate_encryption_key().unwrap(), ..stored };
+        assert!(provider.decrypt_transaction(sent.inner, &other).is_err());
+
+        // keys of unknown transactions are not available
+        let unknown = TxHash::with_last_byte(1);
+        let err = provider.decrypt_own_transaction(unknown).await.unwrap_err();
+        assert!(matches!(
+            SeismicError::from_transport_err(&err),
+            Some(SeismicError::UnknownTransactionKey(hash)) if *hash == unknown
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_contract_requires_signed_call() {
+        let node = MockSeismicNode::new();
+        let contract = Address::with_last_byte(1);
+        let layer = SeismicLayer::new().with_authenticated_contracts([contract]);
+
+        let mut tx = mock_seismic_call_tx();
+        tx.to = Some(TxKind::Call(contract));
+        tx.from = None;
+        tx.chain_id = Some(1);
+        tx.gas = Some(100_000);
+        tx.gas_price = Some(1);
+
+        // without a signer, shielded calldata is never sent to the contract
+        let unsigned = ProviderBuilder::new().layer(layer.clone()).on_client(node.client());
+        let err = unsigned.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap_err();
+        assert!(err.to_string().contains("must be signed"), "{err}");
+        assert!(matches!(
+            SeismicError::from_transport_err(&err),
+            Some(SeismicError::UnsignedAuthenticatedCall(to)) if *to == contract
+        ));
+        assert_eq!(node.request_count("eth_call"), 0);
+
+        // with a signer, calls to the contract are signed
+        let signer = PrivateKeySigner::random();
+        let provider = ProviderBuilder::new()
+            .layer(layer)
+            .layer(JoinFill::new(Identity, WalletFiller::new(EthereumWallet::from(signer))))
+            .on_client(node.client());
+        let res = provider.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(signed_calls(&node), 1);
+
+        // other contracts are not affected
+        let mut other = tx.clone();
+        other.to = Some(TxKind::Call(Address::ZERO));
+        provider.seismic_call(SendableTx::Builder(other)).await.unwrap();
+
+        let res = provider.signed_call(tx).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(signed_calls(&node), 3);
+    }
+
+    #[tokio::test]
+    async fn test_seismic_builder() {
+        let node = MockSeismicNode::new();
+        let mut tx = mock_seismic_call_tx();
+        tx.from = None;
+        tx.chain_id = Some(1);
+        tx.gas = Some(100_000);
+        tx.gas_price = Some(1);
+
+        let signed = ProviderBuilder::new()
+            .seismic(EthereumWallet::from(PrivateKeySigner::random()))
+            .on_client(node.client());
+        let res = signed.seismic_signed_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(signed_calls(&node), 1);
+
+        let unsigned = ProviderBuilder::new().seismic_unsigned().on_client(node.client());
+        let res = unsigned.seismic_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert!(unsigned.seismic_signed_call(SendableTx::Builder(tx)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_typed_errors() {
+        let mut tx = mock_seismic_call_tx();
+        tx.from = None;
+        tx.chain_id = Some(1);
+        tx.gas = Some(100_000);
+        tx.gas_price = Some(1);
+        let wallet = EthereumWallet::from(PrivateKeySigner::random());
+
+        // the node serves a key which is not a valid point
+        let node =
+            MockSeismicNode::new().with_response("seismic_getTeePublicKey", Bytes::from([5; 33]));
+        let provider = ProviderBuilder::new().seismic(wallet.clone()).on_client(node.client());
+        let err = provider.seismic_signed_call(SendableTx::Builder(tx.clone())).await.unwrap_err();
+        assert!(matches!(
+            SeismicError::from_transport_err(&err),
+            Some(SeismicError::InvalidTeePubkey(_))
+        ));
+        assert_eq!(node.request_count("eth_call"), 0);
+
+        // the node answers with an output which is not encrypted
+        let node = MockSeismicNode::new().with_response("eth_call", Bytes::from([1; 32]));
+        let provider = ProviderBuilder::new().seismic(wallet).on_client(node.client());
+        let err = provider.seismic_signed_call(SendableTx::Builder(tx)).await.unwrap_err();
+        match SeismicError::from_transport_err(&err) {
+            Some(SeismicError::Decryption { nonce, .. }) => assert_eq!(*nonce, 0),
+            _ => panic!("unexpected error: {err}"),
+        }
+
+        // errors of the node are passed through
+        let err = provider.root().get_block_receipts(Default::default()).await.unwrap_err();
+        assert!(SeismicError::from_transport_err(&err).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_revert() {
+        // calls with `Error(string)` calldata revert with it
+        let node = MockSeismicNode::new().with_handler(|req| {
+            if req.input.starts_with(&REVERT_SELECTOR) {
+                Err(req.input.clone())
+            } else {
+                Ok(req.input.clone())
+            }
+        });
+        let provider = ProviderBuilder::new().layer(SeismicLayer::new()).on_client(node.client());
+
+        let revert = Bytes::from([&REVERT_SELECTOR[..], &[0u8; 32]].concat());
+        let mut tx = mock_seismic_call_tx();
+        tx.input = TransactionInput::new(revert.clone());
+
+        let err = provider.seismic_call(SendableTx::Builder(tx)).await.unwrap_err();
+        let RpcError::ErrorResp(payload) = err else { panic!("unexpected error: {err}") };
+        assert_eq!(payload.as_revert_data(), Some(revert));
+    }
+
+    /// A filler moving transactions from nonce 0 to 1, like a nonce manager
+    /// reset after a failed send.
+    #[derive(Clone, Debug)]
+    struct BumpNonce;
+
+    impl<N: Network> TxFiller<N> for BumpNonce {
+        type Fillable = ();
+
+        fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+            if tx.nonce() == Some(0) {
+                FillerControlFlow::Ready
+            } else {
+                FillerControlFlow::Finished
+            }
+        }
+
+        fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+        async fn prepare<P, T>(
+            &self,
+            _provider: &P,
+            _tx: &N::TransactionRequest,
+        ) -> TransportResult<()>
+        where
+            P: Provider<T, N>,
+            T: Transport + Clone,
+        {
+            Ok(())
+        }
+
+        async fn fill(
+            &self,
+            _fillable: (),
+            mut tx: SendableTx<N>,
+        ) -> TransportResult<SendableTx<N>> {
+            if let Some(builder) = tx.as_mut_builder() {
+                builder.set_nonce(1);
+            }
+            Ok(tx)
+        }
+    }
+
+    /// A filler overwriting the input of transactions.
+    #[derive(Clone, Debug)]
+    struct ReplaceInput;
+
+    impl<N: Network> TxFiller<N> for ReplaceInput {
+        type Fillable = ();
+
+        fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+            if tx.input().map_or(false, |input| input[..] == [0xff]) {
+                FillerControlFlow::Finished
+            } else {
+                FillerControlFlow::Ready
+            }
+        }
+
+        fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+        async fn prepare<P, T>(
+            &self,
+            _provider: &P,
+            _tx: &N::TransactionRequest,
+        ) -> TransportResult<()>
+        where
+            P: Provider<T, N>,
+            T: Transport + Clone,
+        {
+            Ok(())
+        }
+
+        async fn fill(
+            &self,
+            _fillable: (),
+            mut tx: SendableTx<N>,
+        ) -> TransportResult<SendableTx<N>> {
+            if let Some(builder) = tx.as_mut_builder() {
+                builder.set_input(Bytes::from_static(&[0xff]));
+            }
+            Ok(tx)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reencrypt_on_nonce_change() {
+        let node = MockSeismicNode::new();
+        let key_store = InMemoryKeyStore::new();
+        let layer = SeismicLayer::new().with_key_store(key_store);
+        let provider = ProviderBuilder::new()
+            .layer(layer.clone())
+            .layer(JoinFill::new(BumpNonce, layer.encryption_guard()))
+            .on_client(node.client());
+
+        // the node decrypts the call with the bumped nonce, and so does the provider
+        let res = provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+        assert_eq!(node.request_count("eth_call"), 1);
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        let mut tx = mock_seismic_call_tx();
+        tx.input = TransactionInput::new(plaintext.clone());
+        let tx_hash = *provider.send_transaction(tx).await.unwrap().tx_hash();
+
+        let sent = provider.get_transaction_by_hash(tx_hash).await.unwrap().unwrap();
+        assert_eq!(sent.nonce(), 1);
+        assert_eq!(provider.decrypt_own_transaction(tx_hash).await.unwrap(), plaintext);
+    }
+
+    #[tokio::test]
+    async fn test_replaced_ciphertext_is_refused() {
+        let node = MockSeismicNode::new();
+        let layer = SeismicLayer::new();
+        let provider = ProviderBuilder::new()
+            .layer(layer.clone())
+            .layer(JoinFill::new(ReplaceInput, layer.encryption_guard()))
+            .on_client(node.client());
+
+        let err =
+            provider.seismic_call(SendableTx::Builder(mock_seismic_call_tx())).await.unwrap_err();
+        assert!(err.to_string().contains("replaced after encryption"), "{err}");
+        let err = provider.send_transaction(mock_seismic_call_tx()).await.unwrap_err();
+        assert!(err.to_string().contains("replaced after encryption"), "{err}");
+
+        assert_eq!(node.request_count("eth_call"), 0);
+        assert!(node.transactions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_encrypted_transaction() {
+        let node = MockSeismicNode::new();
+        let provider = ProviderBuilder::new()
+            .layer(SeismicLayer::new().with_key_store(InMemoryKeyStore::new()))
+            .on_client(node.client());
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        let mut tx = mock_seismic_call_tx();
+        tx.input = TransactionInput::new(plaintext.clone());
+        let tx_hash = *provider.send_transaction(tx).await.unwrap().tx_hash();
+        // only the key store keeps the key of the sent transaction
+        assert_eq!(provider.inputs.len(), 0);
+
+        // the replacement of the fetched transaction encrypts its plaintext for the new nonce
+        let sent = provider.get_transaction_by_hash(tx_hash).await.unwrap().unwrap();
+        let TxEnvelope::Seismic(signed) = sent.inner else {
+            panic!("expected a seismic transaction")
+        };
+        let mut replacement: TransactionRequest = signed.strip_signature().into();
+        replacement.from = Some(sent.from);
+        replacement.nonce = Some(1);
+        replacement.input =
+            TransactionInput::new(provider.decrypt_own_transaction(tx_hash).await.unwrap());
+        let tx_hash = *provider.send_transaction(replacement).await.unwrap().tx_hash();
+        assert_eq!(provider.inputs.len(), 0);
+
+        assert_eq!(provider.decrypt_own_transaction(tx_hash).await.unwrap(), plaintext);
+    }
+
+    #[tokio::test]
+    async fn test_any_network() {
+        let node = MockSeismicNode::new();
+        let wallet = EthereumWallet::from(PrivateKeySigner::random());
+        let provider = ProviderBuilder::new()
+            .network::<AnyNetwork>()
+            .layer(SeismicLayer::new().with_key_store(InMemoryKeyStore::new()))
+            .layer(JoinFill::new(Identity, WalletFiller::new(wallet)))
+            .on_client(node.client());
+
+        let mut tx = mock_seismic_call_tx();
+        tx.from = None;
+        tx.chain_id = Some(1);
+        tx.gas = Some(100_000);
+        tx.gas_price = Some(1);
+        let res = provider
+            .seismic_signed_call(SendableTx::Builder(WithOtherFields::new(tx.clone())))
+            .await
+            .unwrap();
+        assert_eq!(res, ContractTestContext::get_is_odd_input_plaintext());
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        tx.input = TransactionInput::new(plaintext.clone());
+        let receipt = provider
+            .send_transaction(WithOtherFields::new(tx))
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+        assert_eq!(receipt.inner.inner.r#type, TxSeismic::TX_TYPE);
+
+        // the transaction is decoded as a seismic transaction, not an unknown one
+        let tx_hash = receipt.transaction_hash;
+        let sent = provider.get_transaction_by_hash(tx_hash).await.unwrap().unwrap();
+        let AnyTxEnvelope::Ethereum(TxEnvelope::Seismic(signed)) = &sent.inner.inner else {
+            panic!("expected a seismic transaction")
+        };
+        assert_eq!(sent.encryption_pubkey(), Some(&signed.tx().encryption_pubkey));
+        assert_eq!(provider.decrypt_own_transaction(tx_hash).await.unwrap(), plaintext);
+        assert_eq!(node.seismic_requests().last().unwrap().input, plaintext);
+    }
+}
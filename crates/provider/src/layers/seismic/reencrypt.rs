@@ -0,0 +1,178 @@
+//! Re-encryption of seismic inputs whose nonce changed after they were encrypted.
+
+use super::{EcdhCrypto, SeismicCrypto, SeismicError};
+use crate::{
+    fillers::{FillerControlFlow, TxFiller},
+    provider::SendableTx,
+    Provider,
+};
+use alloy_consensus::transaction::EncryptionPublicKey;
+use alloy_network::{Network, TransactionBuilder};
+use alloy_primitives::Bytes;
+use alloy_transport::{Transport, TransportResult};
+use schnellru::{ByLength, LruMap};
+use seismic_enclave::PublicKey;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// The number of encrypted inputs remembered, the least recently used are
+/// forgotten first.
+const MAX_ENCRYPTED_INPUTS: u32 = 1024;
+
+/// An input encrypted by a [`SeismicProvider`](super::SeismicProvider).
+#[derive(Debug, Clone)]
+pub(crate) struct EncryptedInput<K> {
+    /// The input before encryption.
+    pub(crate) plaintext: Bytes,
+    /// The input after encryption.
+    pub(crate) ciphertext: Bytes,
+    /// The nonce the ciphertext is bound to.
+    pub(crate) nonce: u64,
+    /// The client key of the request.
+    pub(crate) key: K,
+    /// The TEE public key the input was encrypted to.
+    pub(crate) tee_pubkey: PublicKey,
+}
+
+/// The inputs encrypted by the providers of a [`SeismicLayer`](super::SeismicLayer),
+/// keyed by the encryption public key of their transaction.
+///
+/// Shared with the [`EncryptionGuard`] of the layer, which re-encrypts an input
+/// when a filler changes the nonce of its transaction.
+pub(crate) struct EncryptedInputs<K> {
+    inner: Arc<Mutex<LruMap<EncryptionPublicKey, EncryptedInput<K>>>>,
+}
+
+impl<K> Clone for EncryptedInputs<K> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<K> Default for EncryptedInputs<K> {
+    fn default() -> Self {
+        Self { inner: Arc::new(Mutex::new(LruMap::new(ByLength::new(MAX_ENCRYPTED_INPUTS)))) }
+    }
+}
+
+impl<K> fmt::Debug for EncryptedInputs<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.inner.lock().unwrap_or_else(|e| e.into_inner()).len();
+        f.debug_struct("EncryptedInputs").field("len", &len).finish()
+    }
+}
+
+impl<K: Clone> EncryptedInputs<K> {
+    /// Records an input encrypted for the transaction with `encryption_pubkey`.
+    pub(crate) fn insert(&self, encryption_pubkey: EncryptionPublicKey, input: EncryptedInput<K>) {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).insert(encryption_pubkey, input);
+    }
+
+    /// Returns the input encrypted for the transaction with `encryption_pubkey`.
+    pub(crate) fn get(&self, encryption_pubkey: &EncryptionPublicKey) -> Option<EncryptedInput<K>> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).get(encryption_pubkey).cloned()
+    }
+
+    /// Forgets the input encrypted for the transaction with `encryption_pubkey`.
+    pub(crate) fn remove(
+        &self,
+        encryption_pubkey: &EncryptionPublicKey,
+    ) -> Option<EncryptedInput<K>> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).remove(encryption_pubkey)
+    }
+
+    /// Returns the number of inputs remembered.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+}
+
+/// A filler keeping encrypted inputs bound to the nonce of their transaction.
+///
+/// The ciphertext of a seismic transaction is only valid for the nonce it was
+/// encrypted with. Fillers running after the [`SeismicProvider`] may still
+/// change the nonce, e.g. a nonce manager that was reset after a failed send.
+/// This filler re-encrypts the input with the new nonce and the original key,
+/// and fails if the input no longer is the ciphertext produced by the
+/// provider.
+///
+/// It must run after every filler that may touch the nonce and before the
+/// transaction is signed. [`ProviderBuilder::seismic`] installs it right
+/// before the signing filler, a filler for a custom stack is returned by
+/// [`SeismicLayer::encryption_guard`].
+///
+/// [`SeismicProvider`]: super::SeismicProvider
+/// [`SeismicLayer::encryption_guard`]: super::SeismicLayer::encryption_guard
+/// [`ProviderBuilder::seismic`]: crate::ProviderBuilder::seismic
+#[derive(Debug, Clone)]
+pub struct EncryptionGuard<C: SeismicCrypto = EcdhCrypto> {
+    /// Encryption backend.
+    crypto: C,
+    /// Inputs encrypted by the providers of the layer.
+    inputs: EncryptedInputs<C::Key>,
+}
+
+impl<C: SeismicCrypto> EncryptionGuard<C> {
+    /// Create a guard over the inputs encrypted with `crypto`.
+    pub(crate) const fn new(crypto: C, inputs: EncryptedInputs<C::Key>) -> Self {
+        Self { crypto, inputs }
+    }
+}
+
+impl<C: SeismicCrypto, N: Network> TxFiller<N> for EncryptionGuard<C> {
+    type Fillable = ();
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        let Some(input) = tx.encryption_pubkey().and_then(|pubkey| self.inputs.get(pubkey)) else {
+            return FillerControlFlow::Finished;
+        };
+        if tx.input() == Some(&input.ciphertext) && tx.nonce() == Some(input.nonce) {
+            FillerControlFlow::Finished
+        } else {
+            FillerControlFlow::Ready
+        }
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+    async fn prepare<P, T>(
+        &self,
+        _provider: &P,
+        _tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: Provider<T, N>,
+        T: Transport + Clone,
+    {
+        Ok(())
+    }
+
+    async fn fill(
+        &self,
+        _fillable: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        let Some(builder) = tx.as_mut_builder() else { return Ok(tx) };
+        let Some(encryption_pubkey) = builder.encryption_pubkey().copied() else { return Ok(tx) };
+        let Some(mut input) = self.inputs.get(&encryption_pubkey) else { return Ok(tx) };
+
+        if builder.input() != Some(&input.ciphertext) {
+            return Err(SeismicError::CiphertextReplaced(encryption_pubkey).into());
+        }
+        let nonce = builder.nonce().ok_or(SeismicError::NonceUnset(encryption_pubkey))?;
+
+        if nonce != input.nonce {
+            input.ciphertext = self
+                .crypto
+                .encrypt(&input.tee_pubkey, &input.key, &input.plaintext, nonce)
+                .map_err(|source| SeismicError::Encryption { nonce, source })?;
+            input.nonce = nonce;
+            builder.set_input(input.ciphertext.clone());
+            self.inputs.insert(encryption_pubkey, input);
+        }
+        Ok(tx)
+    }
+}
@@ -0,0 +1,229 @@
+//! Caching of the TEE public key used to encrypt seismic transactions.
+
+use alloy_json_rpc::RpcError;
+use alloy_transport::TransportError;
+use seismic_enclave::PublicKey;
+use std::{
+    borrow::Cow,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+/// The default time a fetched TEE public key is considered valid for.
+pub const DEFAULT_TEE_KEY_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Default JSON-RPC error code identifying the failure of a node to decrypt the
+/// input of a transaction or call.
+///
+/// Nodes reporting the failure differently are configured with
+/// [`TeeKeyCache::with_decryption_error`].
+pub const TEE_DECRYPTION_ERROR_CODE: i64 = -32000;
+
+/// Default prefix of the error message identifying the failure of a node to
+/// decrypt the input of a transaction or call.
+///
+/// Nodes reporting the failure differently are configured with
+/// [`TeeKeyCache::with_decryption_error`].
+pub const TEE_DECRYPTION_ERROR_MESSAGE: &str = "failed to decrypt transaction input";
+
+/// A TEE public key along with the time it was fetched at.
+#[derive(Debug, Clone, Copy)]
+struct CachedTeeKey {
+    key: PublicKey,
+    fetched_at: Instant,
+}
+
+/// Shareable cache for the TEE public key of a seismic node.
+///
+/// The key is fetched with `seismic_getTeePublicKey` the first time it is
+/// needed and reused until its time-to-live expires, it is explicitly
+/// invalidated, or the node rejects a ciphertext encrypted against it.
+///
+/// A TTL of [`Duration::ZERO`] disables caching, the key is then fetched
+/// before every encrypted request.
+#[derive(Debug, Clone)]
+pub struct TeeKeyCache {
+    inner: Arc<RwLock<Option<CachedTeeKey>>>,
+    ttl: Duration,
+    /// Code of the error the node returns when it fails to decrypt an input.
+    decryption_error_code: i64,
+    /// Prefix of the message of the error the node returns when it fails to
+    /// decrypt an input.
+    decryption_error_message: Cow<'static, str>,
+}
+
+impl Default for TeeKeyCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TEE_KEY_TTL)
+    }
+}
+
+impl TeeKeyCache {
+    /// Instantiate a new, empty cache whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(None)),
+            ttl,
+            decryption_error_code: TEE_DECRYPTION_ERROR_CODE,
+            decryption_error_message: Cow::Borrowed(TEE_DECRYPTION_ERROR_MESSAGE),
+        }
+    }
+
+    /// Returns an empty cache whose entries expire after `ttl`, recognizing the
+    /// same decryption error as this one.
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Self { inner: Arc::new(RwLock::new(None)), ttl, ..self }
+    }
+
+    /// Sets the error the node returns when it fails to decrypt an input: its
+    /// code, and the prefix of its message. Defaults to
+    /// [`TEE_DECRYPTION_ERROR_CODE`] and [`TEE_DECRYPTION_ERROR_MESSAGE`].
+    pub fn with_decryption_error(
+        mut self,
+        code: i64,
+        message: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.decryption_error_code = code;
+        self.decryption_error_message = message.into();
+        self
+    }
+
+    /// Returns `true` if the node rejected a request because it could not
+    /// decrypt the transaction input, see [`is_tee_key_mismatch`].
+    pub fn is_key_mismatch(&self, err: &TransportError) -> bool {
+        is_decryption_error(err, self.decryption_error_code, &self.decryption_error_message)
+    }
+
+    /// Returns the time-to-live of a cached key.
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the cached key, if one is present and has not expired.
+    pub fn get(&self) -> Option<PublicKey> {
+        let cached = (*self.inner.read().unwrap_or_else(|e| e.into_inner()))?;
+        (cached.fetched_at.elapsed() < self.ttl).then_some(cached.key)
+    }
+
+    /// Stores a freshly fetched key in the cache.
+    pub fn set(&self, key: PublicKey) {
+        let entry = CachedTeeKey { key, fetched_at: Instant::now() };
+        *self.inner.write().unwrap_or_else(|e| e.into_inner()) = Some(entry);
+    }
+
+    /// Removes the cached key, forcing the next request to fetch it again.
+    pub fn invalidate(&self) {
+        *self.inner.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// Returns `true` if the node rejected a request because it could not decrypt
+/// the transaction input.
+///
+/// This happens when the enclave key of the node has been rotated since the
+/// TEE public key used to encrypt the input was fetched. Only the node's
+/// [decryption error](TEE_DECRYPTION_ERROR_MESSAGE) matches, so reverts whose
+/// reason mentions decryption are not mistaken for it.
+///
+/// Providers match the error configured with
+/// [`TeeKeyCache::with_decryption_error`] instead, this only matches the
+/// default one.
+pub fn is_tee_key_mismatch(err: &TransportError) -> bool {
+    is_decryption_error(err, TEE_DECRYPTION_ERROR_CODE, TEE_DECRYPTION_ERROR_MESSAGE)
+}
+
+fn is_decryption_error(err: &TransportError, code: i64, message: &str) -> bool {
+    match err {
+        RpcError::ErrorResp(payload) => {
+            payload.code == code && payload.message.starts_with(message)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::ErrorPayload;
+    use seismic_enclave::{rand, Keypair, Secp256k1};
+
+    fn random_key() -> PublicKey {
+        Keypair::new(&Secp256k1::new(), &mut rand::thread_rng()).public_key()
+    }
+
+    #[test]
+    fn caches_until_invalidated() {
+        let cache = TeeKeyCache::default();
+        assert!(cache.get().is_none());
+
+        let key = random_key();
+        cache.set(key);
+        assert_eq!(cache.get(), Some(key));
+
+        cache.invalidate();
+        assert!(cache.get().is_none());
+    }
+
+    #[test]
+    fn zero_ttl_disables_caching() {
+        let cache = TeeKeyCache::new(Duration::ZERO);
+        cache.set(random_key());
+        assert!(cache.get().is_none());
+    }
+
+    #[test]
+    fn detects_decryption_failures() {
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: TEE_DECRYPTION_ERROR_CODE,
+            message: TEE_DECRYPTION_ERROR_MESSAGE.into(),
+            data: None,
+        });
+        assert!(is_tee_key_mismatch(&err));
+
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: 3,
+            message: "execution reverted: could not decrypt ballot".into(),
+            data: None,
+        });
+        assert!(!is_tee_key_mismatch(&err));
+
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: TEE_DECRYPTION_ERROR_CODE,
+            message: "failed to decrypt output".into(),
+            data: None,
+        });
+        assert!(!is_tee_key_mismatch(&err));
+
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: -32000,
+            message: "nonce too low".into(),
+            data: None,
+        });
+        assert!(!is_tee_key_mismatch(&err));
+    }
+
+    #[test]
+    fn detects_configured_decryption_failures() {
+        let cache = TeeKeyCache::default().with_decryption_error(-32602, "invalid ciphertext");
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: -32602,
+            message: "invalid ciphertext: bad tag".into(),
+            data: None,
+        });
+        assert!(cache.is_key_mismatch(&err));
+        assert!(cache.with_ttl(Duration::ZERO).is_key_mismatch(&err));
+
+        let err = TransportError::ErrorResp(ErrorPayload {
+            code: TEE_DECRYPTION_ERROR_CODE,
+            message: TEE_DECRYPTION_ERROR_MESSAGE.into(),
+            data: None,
+        });
+        assert!(!cache.is_key_mismatch(&err));
+    }
+}
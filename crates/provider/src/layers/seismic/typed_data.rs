@@ -0,0 +1,145 @@
+//! Submission of seismic transactions signed over EIP-712 typed data.
+
+use crate::{
+    PendingTransactionBuilder, Provider, ProviderLayer, RootProvider, SeismicCallOptions,
+    SendableTx,
+};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_json_rpc::RpcError;
+use alloy_network::SeismicNetwork;
+use alloy_primitives::Bytes;
+use alloy_rpc_types_eth::SeismicCallRequest;
+use alloy_transport::{Transport, TransportResult};
+use std::marker::PhantomData;
+
+/// A layer submitting seismic transactions signed over EIP-712 typed data in
+/// their typed data form, which the node requires instead of their 2718
+/// encoding.
+///
+/// Signed transactions are otherwise sent as raw bytes, so the layer must be
+/// below every signer, [`ProviderBuilder::seismic`](crate::ProviderBuilder::seismic)
+/// installs it right above the transport.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeismicTypedDataLayer;
+
+impl<P, T, N> ProviderLayer<P, T, N> for SeismicTypedDataLayer
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: SeismicNetwork,
+{
+    type Provider = SeismicTypedDataProvider<P, T, N>;
+
+    fn layer(&self, inner: P) -> Self::Provider {
+        SeismicTypedDataProvider { inner, _pd: PhantomData }
+    }
+}
+
+/// A provider submitting seismic transactions signed over EIP-712 typed data
+/// in their typed data form, see [`SeismicTypedDataLayer`].
+#[derive(Debug, Clone)]
+pub struct SeismicTypedDataProvider<P, T, N> {
+    /// Inner provider.
+    inner: P,
+    /// Phantom data
+    _pd: PhantomData<(T, N)>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<P, T, N> Provider<T, N> for SeismicTypedDataProvider<P, T, N>
+where
+    P: Provider<T, N>,
+    T: Transport + Clone,
+    N: SeismicNetwork,
+{
+    fn root(&self) -> &RootProvider<T, N> {
+        self.inner.root()
+    }
+
+    async fn seismic_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        match tx {
+            SendableTx::Envelope(tx) => {
+                let request = N::encode_712(&tx).map_err(RpcError::local_usage)?.map_or_else(
+                    || SeismicCallRequest::Bytes(tx.encoded_2718().into()),
+                    SeismicCallRequest::TypedData,
+                );
+                self.inner.seismic_call_request(&request).seismic_options(&options).await
+            }
+            tx => self.inner.seismic_call_with(tx, options).await,
+        }
+    }
+
+    async fn seismic_signed_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        match tx {
+            tx @ SendableTx::Envelope(_) => self.seismic_call_with(tx, options).await,
+            tx => self.inner.seismic_signed_call_with(tx, options).await,
+        }
+    }
+
+    async fn send_transaction_internal(
+        &self,
+        tx: SendableTx<N>,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        if let SendableTx::Envelope(envelope) = &tx {
+            if let Some(typed_data) = N::encode_712(envelope).map_err(RpcError::local_usage)? {
+                // see `Provider::send_transaction_internal`
+                let _handle = self.root().get_heart();
+                return self.inner.send_typed_data_transaction(typed_data).await;
+            }
+        }
+        self.inner.send_transaction_internal(tx).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::ContractTestContext, MockSeismicNode, MockSeismicRequestKind, ProviderBuilder,
+    };
+    use alloy_network::{EthereumWallet, TransactionBuilder};
+    use alloy_primitives::Address;
+    use alloy_rpc_types_eth::TransactionRequest;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn typed_data_transactions_are_sent_as_typed_data() {
+        let node = MockSeismicNode::new();
+        let signer = PrivateKeySigner::random();
+        let from = signer.address();
+        let provider =
+            ProviderBuilder::new().seismic(EthereumWallet::from(signer)).on_client(node.client());
+
+        let plaintext = ContractTestContext::get_set_number_input_plaintext();
+        let mut tx = TransactionRequest::default()
+            .with_from(from)
+            .with_to(Address::with_last_byte(1))
+            .with_input(plaintext.clone());
+        tx.message_version = Some(2);
+        let res = provider.seismic_signed_call(SendableTx::Builder(tx.clone())).await.unwrap();
+        assert_eq!(res, plaintext);
+        let tx_hash = *provider.send_transaction(tx.clone()).await.unwrap().tx_hash();
+        assert_eq!(node.transaction(&tx_hash).unwrap().from, from);
+
+        // transactions signed over their RLP encoding are still sent as raw bytes
+        tx.message_version = Some(0);
+        provider.send_transaction(tx).await.unwrap();
+
+        let requests = node.seismic_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].kind, MockSeismicRequestKind::SignedCall);
+        assert!(requests[0].typed_data);
+        assert_eq!(requests[1].kind, MockSeismicRequestKind::Transaction);
+        assert!(requests[1].typed_data);
+        assert!(!requests[2].typed_data);
+    }
+}
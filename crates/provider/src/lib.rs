@@ -36,17 +36,21 @@ pub mod fillers;
 
 mod heart;
 pub use heart::{
-    PendingTransaction, PendingTransactionBuilder, PendingTransactionConfig,
+    EscalationPolicy, PendingTransaction, PendingTransactionBuilder, PendingTransactionConfig,
     PendingTransactionError, WatchTxError,
 };
 
 pub mod layers;
 pub use layers::seismic::*;
 
+mod logs;
+pub use logs::{LogEvent, LogStream};
+
 mod provider;
 pub use provider::{
     builder, Caller, EthCall, EthCallParams, FilterPollerBuilder, ParamsWithBlock, Provider,
-    ProviderCall, RootProvider, RpcWithBlock, SendableTx, WalletProvider,
+    ProviderCall, RootProvider, RpcWithBlock, SeismicCall, SeismicCallOptions, SeismicCallParams,
+    SendableTx, WalletProvider,
 };
 
 pub mod utils;
@@ -0,0 +1,425 @@
+use alloy_json_rpc::ErrorPayload;
+use alloy_primitives::{B256, U64};
+use alloy_rpc_client::{NoParams, PollerBuilder, RpcClientInner, WeakClient};
+use alloy_rpc_types_eth::{BlockNumberOrTag, Filter, Log};
+use alloy_transport::{RpcError, Transport, TransportErrorKind, TransportResult};
+use async_stream::try_stream;
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+#[cfg(feature = "pubsub")]
+use futures::future::Either;
+
+/// Default maximum number of blocks queried by a single `eth_getLogs` request.
+const DEFAULT_CHUNK_SIZE: u64 = 2_000;
+
+/// Default number of recent blocks whose hashes are tracked to detect reorgs.
+const DEFAULT_REORG_DEPTH: u64 = 64;
+
+/// JSON-RPC error code returned by some nodes when a query exceeds their limits.
+const LIMIT_EXCEEDED_CODE: i64 = -32005;
+
+/// An event of a [`LogStream`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LogEvent {
+    /// A log matching the filter was added to the chain.
+    Added(Log),
+    /// A log previously delivered as [`LogEvent::Added`] was removed from the chain by a reorg.
+    ///
+    /// The log has its `removed` flag set.
+    Removed(Log),
+}
+
+impl LogEvent {
+    /// Returns the log of the event.
+    pub const fn log(&self) -> &Log {
+        match self {
+            Self::Added(log) | Self::Removed(log) => log,
+        }
+    }
+
+    /// Returns true if the log was removed by a reorg.
+    pub const fn is_removed(&self) -> bool {
+        matches!(self, Self::Removed(_))
+    }
+}
+
+/// A builder for a stream of the logs matching a filter, that backfills the logs from a start block
+/// and then follows the chain.
+///
+/// Historical logs are fetched with `eth_getLogs` in chunks of blocks, whose size shrinks when
+/// the node refuses a query for returning too many results. Once caught up, new blocks are
+/// followed with a `newHeads` subscription on pubsub clients, and by polling `eth_blockNumber`
+/// otherwise.
+///
+/// The hashes of the most recent blocks are tracked, and when a reorg replaces blocks whose logs
+/// were already delivered, a [`LogEvent::Removed`] event is emitted for each of these logs, in
+/// reverse order, before the logs of the new blocks. Reorgs deeper than the
+/// [reorg depth](Self::reorg_depth) are not detected.
+///
+/// Created by [`Provider::log_stream`](crate::Provider::log_stream).
+#[derive(Debug)]
+#[must_use = "LogStream does nothing unless turned into a stream"]
+pub struct LogStream<T> {
+    client: WeakClient<T>,
+    filter: Filter,
+    /// The next block to fetch logs for, or `None` to start after the current head.
+    next: Option<u64>,
+    /// Maximum number of blocks per `eth_getLogs` request.
+    max_chunk_size: u64,
+    /// Current number of blocks per `eth_getLogs` request.
+    chunk_size: u64,
+    reorg_depth: u64,
+    poll_interval: Option<Duration>,
+    /// Recent blocks, and the logs delivered for them.
+    blocks: BTreeMap<u64, TrackedBlock>,
+}
+
+/// A recent block whose logs were delivered.
+#[derive(Debug)]
+struct TrackedBlock {
+    hash: B256,
+    logs: Vec<Log>,
+}
+
+/// The fields of a block header needed to follow the chain.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockRef {
+    number: U64,
+    hash: B256,
+}
+
+impl<T: Transport + Clone> LogStream<T> {
+    /// Creates a new log stream for the filter.
+    ///
+    /// The stream starts at the filter's `fromBlock` if it is a block number or `earliest`, and
+    /// after the current head otherwise. The filter's `toBlock` and block hash are ignored.
+    pub fn new(client: WeakClient<T>, filter: Filter) -> Self {
+        let next = match filter.block_option.get_from_block() {
+            Some(BlockNumberOrTag::Number(number)) => Some(*number),
+            Some(BlockNumberOrTag::Earliest) => Some(0),
+            _ => None,
+        };
+        Self {
+            client,
+            filter,
+            next,
+            max_chunk_size: DEFAULT_CHUNK_SIZE,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            reorg_depth: DEFAULT_REORG_DEPTH,
+            poll_interval: None,
+            blocks: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the block to start streaming logs from.
+    pub const fn from_block(mut self, block: u64) -> Self {
+        self.next = Some(block);
+        self
+    }
+
+    /// Sets the maximum number of blocks queried by a single `eth_getLogs` request.
+    pub fn chunk_size(mut self, chunk_size: u64) -> Self {
+        self.max_chunk_size = chunk_size.max(1);
+        self.chunk_size = self.max_chunk_size;
+        self
+    }
+
+    /// Sets the number of recent blocks whose hashes are tracked to detect reorgs.
+    ///
+    /// Logs of these blocks are fetched one block at a time, by block hash.
+    pub const fn reorg_depth(mut self, reorg_depth: u64) -> Self {
+        self.reorg_depth = reorg_depth;
+        self
+    }
+
+    /// Sets the interval at which new blocks are polled for, on clients without pubsub. Defaults
+    /// to the poll interval of the client.
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Returns the stream of log events.
+    ///
+    /// The stream ends after yielding the first error.
+    pub fn into_stream(mut self) -> impl Stream<Item = TransportResult<LogEvent>> + 'static {
+        try_stream! {
+            let client = self.upgrade()?;
+            let mut head = block_number(&client).await?;
+            if self.next.is_none() {
+                self.next = Some(head + 1);
+            }
+
+            while let Some(events) = self.step(&client, head).await? {
+                for event in events {
+                    yield event;
+                }
+            }
+
+            let heads = self.heads(&client).await?;
+            let mut heads = std::pin::pin!(heads);
+
+            // Catch up with the blocks mined during the backfill, as the subscription only
+            // reports newer ones.
+            head = block_number(&client).await?;
+            drop(client);
+
+            loop {
+                let client = self.upgrade()?;
+                while let Some(events) = self.step(&client, head).await? {
+                    for event in events {
+                        yield event;
+                    }
+                }
+                drop(client);
+
+                let Some(new_head) = heads.next().await else {
+                    debug!("block stream ended");
+                    break;
+                };
+                head = new_head;
+            }
+        }
+    }
+
+    fn upgrade(&self) -> TransportResult<Arc<RpcClientInner<T>>> {
+        self.client.upgrade().ok_or_else(TransportErrorKind::backend_gone)
+    }
+
+    /// Returns a stream of the new head block numbers.
+    async fn heads(
+        &self,
+        client: &RpcClientInner<T>,
+    ) -> TransportResult<impl Stream<Item = u64> + 'static> {
+        #[cfg(feature = "pubsub")]
+        if let Some(pubsub) = client.pubsub_frontend() {
+            let id = client.request("eth_subscribe", ("newHeads",)).await?;
+            let sub = pubsub.get_subscription(id).await?;
+            let heads =
+                sub.into_typed::<BlockRef>().into_stream().map(|block| block.number.to::<u64>());
+            return Ok(Either::Left(heads));
+        }
+        #[cfg(not(feature = "pubsub"))]
+        let _ = client;
+
+        let mut poller: PollerBuilder<T, NoParams, U64> =
+            PollerBuilder::new(self.client.clone(), "eth_blockNumber", []);
+        if let Some(poll_interval) = self.poll_interval {
+            poller.set_poll_interval(poll_interval);
+        }
+        let heads = poller.spawn().into_stream().map(|number| number.to::<u64>());
+
+        #[cfg(feature = "pubsub")]
+        let right = Either::Right;
+        #[cfg(not(feature = "pubsub"))]
+        let right = std::convert::identity;
+        Ok(right(heads))
+    }
+
+    /// Fetches the next batch of events up to the given head, or returns `None` if caught up.
+    ///
+    /// Reverts the logs of reorged blocks first. Blocks older than the reorg depth are then
+    /// fetched in chunks, and more recent ones one by one, tracking their hashes.
+    async fn step(
+        &mut self,
+        client: &RpcClientInner<T>,
+        head: u64,
+    ) -> TransportResult<Option<Vec<LogEvent>>> {
+        let removed = self.unwind(client).await?;
+        if !removed.is_empty() {
+            return Ok(Some(removed));
+        }
+
+        let next = self.next.unwrap_or_default();
+        if next > head {
+            return Ok(None);
+        }
+
+        let tracked_from = (head + 1).saturating_sub(self.reorg_depth);
+        if next < tracked_from {
+            let (logs, to) = self.get_logs_chunked(client, next, tracked_from - 1).await?;
+            self.next = Some(to + 1);
+            return Ok(Some(logs.into_iter().map(LogEvent::Added).collect()));
+        }
+
+        let Some(block) = get_block_ref(client, next).await? else {
+            // The chain was reorged to a shorter one, and the tracked blocks are still canonical:
+            // wait for the next head.
+            return Ok(None);
+        };
+        let filter = self.filter.clone().at_block_hash(block.hash);
+        let logs: Vec<Log> = client.request("eth_getLogs", (filter,)).await?;
+        trace!(number = next, logs = logs.len(), "fetched block logs");
+
+        self.blocks.insert(next, TrackedBlock { hash: block.hash, logs: logs.clone() });
+        while self.blocks.len() as u64 > self.reorg_depth {
+            self.blocks.pop_first();
+        }
+        self.next = Some(next + 1);
+
+        Ok(Some(logs.into_iter().map(LogEvent::Added).collect()))
+    }
+
+    /// Checks the tracked blocks against the chain, from the most recent one, and reverts the
+    /// logs of those that were reorged.
+    async fn unwind(&mut self, client: &RpcClientInner<T>) -> TransportResult<Vec<LogEvent>> {
+        let mut removed = Vec::new();
+        while let Some((&number, tracked)) = self.blocks.last_key_value() {
+            let canonical = get_block_ref(client, number).await?;
+            if canonical.is_some_and(|block| block.hash == tracked.hash) {
+                break;
+            }
+
+            debug!(number, "block was reorged, reverting its logs");
+            let Some((_, tracked)) = self.blocks.pop_last() else { break };
+            removed.extend(tracked.logs.into_iter().rev().map(|mut log| {
+                log.removed = true;
+                LogEvent::Removed(log)
+            }));
+            self.next = Some(number);
+        }
+        Ok(removed)
+    }
+
+    /// Fetches the logs of the blocks from `from` up to at most `to`, in a single request, and
+    /// returns them with the last block they cover.
+    ///
+    /// Shrinks the range while the node returns too many results, and grows it back on success.
+    async fn get_logs_chunked(
+        &mut self,
+        client: &RpcClientInner<T>,
+        from: u64,
+        to: u64,
+    ) -> TransportResult<(Vec<Log>, u64)> {
+        loop {
+            let end = to.min(from.saturating_add(self.chunk_size - 1));
+            let filter = self.filter.clone().select(from..=end);
+            match client.request::<_, Vec<Log>>("eth_getLogs", (filter,)).await {
+                Ok(logs) => {
+                    trace!(from, to = end, logs = logs.len(), "fetched logs");
+                    self.chunk_size = self.max_chunk_size.min(self.chunk_size.saturating_mul(2));
+                    return Ok((logs, end));
+                }
+                Err(RpcError::ErrorResp(err)) if end > from && is_too_many_results(&err) => {
+                    self.chunk_size = (end - from + 1) / 2;
+                    debug!(
+                        from,
+                        to = end,
+                        chunk_size = self.chunk_size,
+                        "too many logs, shrinking"
+                    );
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// Fetches the current head block number.
+async fn block_number<T: Transport + Clone>(client: &RpcClientInner<T>) -> TransportResult<u64> {
+    let number: U64 = client.request_noparams("eth_blockNumber").await?;
+    Ok(number.to())
+}
+
+/// Fetches the hash of the block with the given number, if it exists.
+async fn get_block_ref<T: Transport + Clone>(
+    client: &RpcClientInner<T>,
+    number: u64,
+) -> TransportResult<Option<BlockRef>> {
+    client.request("eth_getBlockByNumber", (BlockNumberOrTag::Number(number), false)).await
+}
+
+/// Returns true if the node refused a query for covering too many blocks or logs.
+///
+/// Nodes do not agree on an error code for this, so the message is checked too.
+fn is_too_many_results(err: &ErrorPayload) -> bool {
+    let message = err.message.to_lowercase();
+    err.code == LIMIT_EXCEEDED_CODE
+        || ["too many", "more than", "limit exceeded", "block range", "response size"]
+            .iter()
+            .any(|pattern| message.contains(pattern))
+}
+
+#[cfg(all(test, feature = "anvil-api"))] // Tests rely heavily on ability to mine blocks on demand.
+mod tests {
+    use super::*;
+    use crate::{ext::AnvilApi, Provider, ProviderBuilder};
+    use alloy_network::TransactionBuilder;
+    use alloy_node_bindings::Anvil;
+    use alloy_primitives::{bytes, U256};
+    use alloy_rpc_types_anvil::ReorgOptions;
+    use alloy_rpc_types_eth::TransactionRequest;
+    use std::future::Future;
+
+    async fn timeout<T: Future>(future: T) -> T::Output {
+        tokio::time::timeout(Duration::from_secs(2), future).await.expect("Timeout")
+    }
+
+    #[tokio::test]
+    async fn backfill_tail_and_revert() {
+        let anvil = Anvil::new().spawn();
+        let provider = ProviderBuilder::new().on_builtin(&anvil.endpoint()).await.unwrap();
+        let from = anvil.addresses()[0];
+
+        // A contract that emits an empty `LOG0` whenever it is called.
+        let tx = TransactionRequest::default()
+            .with_from(from)
+            .with_deploy_code(bytes!("6006600c60003960066000f360006000a000"));
+        let receipt = provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap();
+        let emitter = receipt.contract_address.unwrap();
+        let emit = || async {
+            let tx = TransactionRequest::default().with_from(from).with_to(emitter);
+            provider.send_transaction(tx).await.unwrap().get_receipt().await.unwrap()
+        };
+
+        // Backfill, in chunks of one block, past the tracked blocks.
+        let first = emit().await;
+        provider.anvil_mine(Some(U256::from(3)), None).await.unwrap();
+        let filter = Filter::new().address(emitter).from_block(0);
+        let stream = provider
+            .log_stream(&filter)
+            .chunk_size(1)
+            .reorg_depth(2)
+            .poll_interval(Duration::from_millis(50))
+            .into_stream();
+        let mut stream = Box::pin(stream);
+
+        let event = timeout(stream.next()).await.unwrap().unwrap();
+        assert_eq!(event.log().transaction_hash, Some(first.transaction_hash));
+        assert!(!event.is_removed());
+
+        // Tail.
+        let second = emit().await;
+        let event = timeout(stream.next()).await.unwrap().unwrap();
+        assert!(!event.is_removed());
+        assert_eq!(event.log().transaction_hash, Some(second.transaction_hash));
+
+        // Replace the block of the second log with an empty one.
+        provider.anvil_reorg(ReorgOptions { depth: 1, tx_block_pairs: vec![] }).await.unwrap();
+        let event = timeout(stream.next()).await.unwrap().unwrap();
+        assert!(event.is_removed());
+        assert!(event.log().removed);
+        assert_eq!(event.log().transaction_hash, Some(second.transaction_hash));
+    }
+
+    #[tokio::test]
+    async fn waits_for_head_after_rollback() {
+        let anvil = Anvil::new().spawn();
+        let provider = ProviderBuilder::new().on_builtin(&anvil.endpoint()).await.unwrap();
+        provider.anvil_mine(Some(U256::from(5)), None).await.unwrap();
+
+        let mut stream = provider.log_stream(&Filter::new().from_block(0)).reorg_depth(2);
+        let client = stream.upgrade().unwrap();
+        while stream.step(&client, 4).await.unwrap().is_some() {}
+        assert_eq!(stream.next, Some(5));
+
+        // Block 5 is gone, while the tracked blocks 3 and 4 are still canonical.
+        let _: () = provider.client().request("anvil_rollback", (Some(1u64),)).await.unwrap();
+        assert!(stream.step(&client, 5).await.unwrap().is_none());
+        assert_eq!(stream.next, Some(5));
+        assert_eq!(stream.blocks.keys().copied().collect::<Vec<_>>(), [3, 4]);
+    }
+}
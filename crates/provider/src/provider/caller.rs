@@ -1,59 +1,61 @@
 use super::EthCallParams;
 use crate::ProviderCall;
-use alloy_json_rpc::RpcReturn;
+use alloy_json_rpc::{RpcParam, RpcReturn};
 use alloy_network::Network;
 use alloy_rpc_client::WeakClient;
 use alloy_transport::{Transport, TransportErrorKind, TransportResult};
 
 /// Trait that helpes convert `EthCall` into a `ProviderCall`.
-pub trait Caller<T, N, Resp>: Send + Sync
+pub trait Caller<T, N, Resp, Req = <N as Network>::TransactionRequest>: Send + Sync
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
+    Req: RpcParam + 'static,
 {
     /// Method that needs to be implemented to convert to a `ProviderCall`.
     ///
     /// This method sends the request to relevant data source and returns a `ProviderCall`.
     fn call(
         &self,
-        params: EthCallParams<'_, N>,
-    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>>;
+        params: EthCallParams<'_, N, Req>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N, Req>, Resp>>;
 
     /// Method that needs to be implemented for estimating gas using "eth_estimateGas" for the
     /// transaction.
     fn estimate_gas(
         &self,
-        params: EthCallParams<'_, N>,
-    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>>;
+        params: EthCallParams<'_, N, Req>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N, Req>, Resp>>;
 }
 
-impl<T, N, Resp> Caller<T, N, Resp> for WeakClient<T>
+impl<T, N, Resp, Req> Caller<T, N, Resp, Req> for WeakClient<T>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
+    Req: RpcParam + 'static,
 {
     fn call(
         &self,
-        params: EthCallParams<'_, N>,
-    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>> {
+        params: EthCallParams<'_, N, Req>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N, Req>, Resp>> {
         provider_rpc_call(self, "eth_call", params)
     }
 
     fn estimate_gas(
         &self,
-        params: EthCallParams<'_, N>,
-    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>> {
+        params: EthCallParams<'_, N, Req>,
+    ) -> TransportResult<ProviderCall<T, EthCallParams<'static, N, Req>, Resp>> {
         provider_rpc_call(self, "eth_estimateGas", params)
     }
 }
 
-fn provider_rpc_call<T: Transport + Clone, N: Network, Resp: RpcReturn>(
+fn provider_rpc_call<T: Transport + Clone, N: Network, Resp: RpcReturn, Req: RpcParam + 'static>(
     client: &WeakClient<T>,
     method: &'static str,
-    params: EthCallParams<'_, N>,
-) -> TransportResult<ProviderCall<T, EthCallParams<'static, N>, Resp>> {
+    params: EthCallParams<'_, N, Req>,
+) -> TransportResult<ProviderCall<T, EthCallParams<'static, N, Req>, Resp>> {
     let client = client.upgrade().ok_or_else(TransportErrorKind::backend_gone)?;
 
     let rpc_call = client.request(method, params.into_owned());
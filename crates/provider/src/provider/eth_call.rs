@@ -1,7 +1,8 @@
 use alloy_eips::BlockId;
-use alloy_json_rpc::RpcReturn;
+use alloy_json_rpc::{RpcParam, RpcReturn};
 use alloy_network::Network;
-use alloy_rpc_types_eth::state::StateOverride;
+use alloy_primitives::Bytes;
+use alloy_rpc_types_eth::{state::StateOverride, SeismicCallRequest};
 use alloy_transport::{Transport, TransportResult};
 use futures::FutureExt;
 use serde::ser::SerializeSeq;
@@ -10,20 +11,66 @@ use std::{borrow::Cow, future::Future, marker::PhantomData, sync::Arc, task::Pol
 use crate::{Caller, ProviderCall};
 
 /// The parameters for an `"eth_call"` RPC request.
+///
+/// The request is the network transaction request by default, and a
+/// [`SeismicCallRequest`] for [`SeismicCallParams`].
 #[derive(Clone, Debug)]
-pub struct EthCallParams<'req, N: Network> {
-    data: Cow<'req, N::TransactionRequest>,
+pub struct EthCallParams<'req, N: Network, Req: Clone = <N as Network>::TransactionRequest> {
+    data: Cow<'req, Req>,
     block: Option<BlockId>,
     overrides: Option<Cow<'req, StateOverride>>,
+    _pd: PhantomData<fn() -> N>,
+}
+
+/// The parameters for an `"eth_call"` RPC request made with a
+/// [`SeismicCallRequest`].
+pub type SeismicCallParams<'req, N> = EthCallParams<'req, N, SeismicCallRequest>;
+
+/// The block and state overrides of a seismic call, see
+/// [`Provider::seismic_call_with`].
+///
+/// They are forwarded down the provider stack with the call and set on the
+/// [`SeismicCallParams`] of the request.
+///
+/// [`Provider::seismic_call_with`]: crate::Provider::seismic_call_with
+#[derive(Clone, Debug, Default)]
+pub struct SeismicCallOptions {
+    block: Option<BlockId>,
+    overrides: Option<StateOverride>,
 }
 
-impl<'req, N> EthCallParams<'req, N>
+impl SeismicCallOptions {
+    /// Sets the block to use for the call.
+    pub const fn with_block(mut self, block: BlockId) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the state overrides for the call.
+    pub fn with_overrides(mut self, overrides: StateOverride) -> Self {
+        self.overrides = Some(overrides);
+        self
+    }
+
+    /// Returns the block.
+    pub const fn block(&self) -> Option<BlockId> {
+        self.block
+    }
+
+    /// Returns a reference to the state overrides if set.
+    pub const fn overrides(&self) -> Option<&StateOverride> {
+        self.overrides.as_ref()
+    }
+}
+
+impl<'req, N, Req> EthCallParams<'req, N, Req>
 where
     N: Network,
+    Req: Clone,
 {
     /// Instantiates a new `EthCallParams` with the given data (transaction).
-    pub const fn new(data: &'req N::TransactionRequest) -> Self {
-        Self { data: Cow::Borrowed(data), block: None, overrides: None }
+    pub const fn new(data: &'req Req) -> Self {
+        Self { data: Cow::Borrowed(data), block: None, overrides: None, _pd: PhantomData }
     }
 
     /// Sets the block to use for this call.
@@ -44,7 +91,7 @@ where
     }
 
     /// Returns a reference to the transaction data.
-    pub fn data(&self) -> &N::TransactionRequest {
+    pub fn data(&self) -> &Req {
         &self.data
     }
 
@@ -54,16 +101,17 @@ where
     }
 
     /// Clones the tx data and overrides into owned data.
-    pub fn into_owned(self) -> EthCallParams<'static, N> {
+    pub fn into_owned(self) -> EthCallParams<'static, N, Req> {
         EthCallParams {
             data: Cow::Owned(self.data.into_owned()),
             block: self.block,
             overrides: self.overrides.map(|o| Cow::Owned(o.into_owned())),
+            _pd: PhantomData,
         }
     }
 }
 
-impl<N: Network> serde::Serialize for EthCallParams<'_, N> {
+impl<N: Network, Req: Clone + serde::Serialize> serde::Serialize for EthCallParams<'_, N, Req> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let len = if self.overrides().is_some() { 3 } else { 2 };
 
@@ -86,27 +134,29 @@ impl<N: Network> serde::Serialize for EthCallParams<'_, N> {
 #[doc(hidden)] // Not public API.
 #[allow(unnameable_types)]
 #[pin_project::pin_project]
-pub struct EthCallFut<'req, T, N, Resp, Output, Map>
+pub struct EthCallFut<'req, T, N, Resp, Output, Map, Req = <N as Network>::TransactionRequest>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Output: 'static,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
-    inner: EthCallFutInner<'req, T, N, Resp, Output, Map>,
+    inner: EthCallFutInner<'req, T, N, Resp, Output, Map, Req>,
 }
 
-enum EthCallFutInner<'req, T, N, Resp, Output, Map>
+enum EthCallFutInner<'req, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     Preparing {
-        caller: Arc<dyn Caller<T, N, Resp>>,
-        data: &'req N::TransactionRequest,
+        caller: Arc<dyn Caller<T, N, Resp, Req>>,
+        data: &'req Req,
         overrides: Option<&'req StateOverride>,
         block: Option<BlockId>,
         method: &'static str,
@@ -114,18 +164,20 @@ where
     },
     Running {
         map: Map,
-        fut: ProviderCall<T, EthCallParams<'static, N>, Resp>,
+        fut: ProviderCall<T, EthCallParams<'static, N, Req>, Resp>,
     },
     Polling,
 }
 
-impl<T, N, Resp, Output, Map> core::fmt::Debug for EthCallFutInner<'_, T, N, Resp, Output, Map>
+impl<T, N, Resp, Output, Map, Req> core::fmt::Debug
+    for EthCallFutInner<'_, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Output: 'static,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -142,13 +194,14 @@ where
     }
 }
 
-impl<T, N, Resp, Output, Map> EthCallFut<'_, T, N, Resp, Output, Map>
+impl<T, N, Resp, Output, Map, Req> EthCallFut<'_, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Output: 'static,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     /// Returns `true` if the future is in the preparing state.
     const fn is_preparing(&self) -> bool {
@@ -171,6 +224,7 @@ where
             data: Cow::Borrowed(data),
             block,
             overrides: overrides.map(Cow::Borrowed),
+            _pd: PhantomData,
         };
 
         let fut =
@@ -190,13 +244,14 @@ where
     }
 }
 
-impl<T, N, Resp, Output, Map> Future for EthCallFut<'_, T, N, Resp, Output, Map>
+impl<T, N, Resp, Output, Map, Req> Future for EthCallFut<'_, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Output: 'static,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     type Output = TransportResult<Output>;
 
@@ -221,15 +276,23 @@ where
 /// [`Provider::call`]: crate::Provider::call
 #[must_use = "EthCall must be awaited to execute the call"]
 #[derive(Clone)]
-pub struct EthCall<'req, T, N, Resp, Output = Resp, Map = fn(Resp) -> Output>
-where
+pub struct EthCall<
+    'req,
+    T,
+    N,
+    Resp,
+    Output = Resp,
+    Map = fn(Resp) -> Output,
+    Req = <N as Network>::TransactionRequest,
+> where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
-    caller: Arc<dyn Caller<T, N, Resp>>,
-    data: &'req N::TransactionRequest,
+    caller: Arc<dyn Caller<T, N, Resp, Req>>,
+    data: &'req Req,
     overrides: Option<&'req StateOverride>,
     block: Option<BlockId>,
     method: &'static str,
@@ -237,11 +300,23 @@ where
     _pd: PhantomData<fn() -> (Resp, Output)>,
 }
 
-impl<T, N, Resp> core::fmt::Debug for EthCall<'_, T, N, Resp>
+/// A builder for an `"eth_call"` request made with a [`SeismicCallRequest`].
+/// This type is returned by the [`Provider::seismic_call_request`] method.
+///
+/// The request can be an unsigned transaction request, a signed raw
+/// transaction or EIP-712 typed data with its signature.
+///
+/// [`Provider::seismic_call_request`]: crate::Provider::seismic_call_request
+pub type SeismicCall<'req, T, N> =
+    EthCall<'req, T, N, Bytes, Bytes, fn(Bytes) -> Bytes, SeismicCallRequest>;
+
+impl<T, N, Resp, Output, Map, Req> core::fmt::Debug for EthCall<'_, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
+    Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("EthCall")
@@ -253,17 +328,15 @@ where
     }
 }
 
-impl<'req, T, N, Resp> EthCall<'req, T, N, Resp>
+impl<'req, T, N, Resp, Req> EthCall<'req, T, N, Resp, Resp, fn(Resp) -> Resp, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
+    Req: RpcParam + 'static,
 {
     /// Create a new CallBuilder.
-    pub fn new(
-        caller: impl Caller<T, N, Resp> + 'static,
-        data: &'req N::TransactionRequest,
-    ) -> Self {
+    pub fn new(caller: impl Caller<T, N, Resp, Req> + 'static, data: &'req Req) -> Self {
         Self {
             caller: Arc::new(caller),
             data,
@@ -276,10 +349,7 @@ where
     }
 
     /// Create new EthCall for gas estimates.
-    pub fn gas_estimate(
-        caller: impl Caller<T, N, Resp> + 'static,
-        data: &'req N::TransactionRequest,
-    ) -> Self {
+    pub fn gas_estimate(caller: impl Caller<T, N, Resp, Req> + 'static, data: &'req Req) -> Self {
         Self {
             caller: Arc::new(caller),
             data,
@@ -292,12 +362,13 @@ where
     }
 }
 
-impl<'req, T, N, Resp, Output, Map> EthCall<'req, T, N, Resp, Output, Map>
+impl<'req, T, N, Resp, Output, Map, Req> EthCall<'req, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     /// Map the response to a different type. This is usable for converting
     /// the response to a more usable type, e.g. changing `U64` to `u64`.
@@ -313,7 +384,7 @@ where
     pub fn map_resp<NewOutput, NewMap>(
         self,
         map: NewMap,
-    ) -> EthCall<'req, T, N, Resp, NewOutput, NewMap>
+    ) -> EthCall<'req, T, N, Resp, NewOutput, NewMap, Req>
     where
         NewMap: Fn(Resp) -> NewOutput,
     {
@@ -339,20 +410,28 @@ where
         self.block = Some(block);
         self
     }
+
+    /// Set the block and state overrides of a seismic call.
+    pub(crate) const fn seismic_options(mut self, options: &'req SeismicCallOptions) -> Self {
+        self.block = options.block;
+        self.overrides = options.overrides.as_ref();
+        self
+    }
 }
 
-impl<'req, T, N, Resp, Output, Map> std::future::IntoFuture
-    for EthCall<'req, T, N, Resp, Output, Map>
+impl<'req, T, N, Resp, Output, Map, Req> std::future::IntoFuture
+    for EthCall<'req, T, N, Resp, Output, Map, Req>
 where
     T: Transport + Clone,
     N: Network,
     Resp: RpcReturn,
     Output: 'static,
     Map: Fn(Resp) -> Output,
+    Req: RpcParam + 'static,
 {
     type Output = TransportResult<Output>;
 
-    type IntoFuture = EthCallFut<'req, T, N, Resp, Output, Map>;
+    type IntoFuture = EthCallFut<'req, T, N, Resp, Output, Map, Req>;
 
     fn into_future(self) -> Self::IntoFuture {
         EthCallFut {
@@ -371,10 +450,57 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{Provider, ProviderBuilder};
     use alloy_eips::BlockNumberOrTag;
+    use alloy_json_rpc::{
+        ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest,
+    };
     use alloy_network::{Ethereum, TransactionBuilder};
-    use alloy_primitives::{address, U256};
-    use alloy_rpc_types_eth::{state::StateOverride, TransactionRequest};
+    use alloy_primitives::{address, bytes, keccak256, U256};
+    use alloy_rpc_client::RpcClient;
+    use alloy_rpc_types_eth::{state::StateOverride, SeismicRawTxRequest, TransactionRequest};
+    use alloy_transport::{TransportError, TransportFut};
+    use serde_json::value::to_raw_value;
+    use std::task::Context;
+
+    /// A transport answering `eth_call` with its serialized params and
+    /// `eth_sendRawTransaction` with their hash.
+    #[derive(Clone, Debug)]
+    struct EchoTransport;
+
+    impl EchoTransport {
+        fn respond(req: &SerializedRequest) -> Response {
+            let params = req.params().map(|p| p.get().as_bytes().to_vec()).unwrap_or_default();
+            let payload = match req.method() {
+                "eth_call" => ResponsePayload::Success(to_raw_value(&Bytes::from(params)).unwrap()),
+                "eth_sendRawTransaction" => {
+                    ResponsePayload::Success(to_raw_value(&keccak256(params)).unwrap())
+                }
+                _ => ResponsePayload::Failure(ErrorPayload::method_not_found()),
+            };
+            Response { id: req.id().clone(), payload }
+        }
+    }
+
+    impl tower::Service<RequestPacket> for EchoTransport {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: RequestPacket) -> Self::Future {
+            let response = match req {
+                RequestPacket::Single(req) => ResponsePacket::Single(Self::respond(&req)),
+                RequestPacket::Batch(reqs) => {
+                    ResponsePacket::Batch(reqs.iter().map(Self::respond).collect())
+                }
+            };
+            Box::pin(async move { Ok(response) })
+        }
+    }
 
     #[test]
     fn test_serialize_eth_call_params() {
@@ -439,4 +565,53 @@ mod test {
             r#"[{"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","maxFeePerGas":"0x4a817c800","maxPriorityFeePerGas":"0x3b9aca00","gas":"0x5208","value":"0x64","nonce":"0x0","chainId":"0x1"},"0x1"]"#
         );
     }
+
+    #[tokio::test]
+    async fn test_seismic_requests() {
+        let provider = ProviderBuilder::new().on_client(RpcClient::new(EchoTransport, true));
+
+        let overrides = StateOverride::default();
+        let output = provider
+            .seismic_call_request(&bytes!("4a01").into())
+            .block(BlockId::Number(BlockNumberOrTag::Number(1)))
+            .overrides(&overrides)
+            .await
+            .unwrap();
+        assert_eq!(output, Bytes::from_static(br#"["0x4a01","0x1",{}]"#));
+
+        let pending = provider
+            .send_seismic_raw_transaction(SeismicRawTxRequest::Bytes(bytes!("4a01")))
+            .await
+            .unwrap();
+        assert_eq!(*pending.tx_hash(), keccak256(br#"["0x4a01"]"#));
+
+        // unknown methods are answered with a JSON-RPC error
+        let err = provider.get_chain_id().await.unwrap_err();
+        assert_eq!(err.as_error_resp().unwrap().code, ErrorPayload::<()>::method_not_found().code);
+    }
+
+    #[test]
+    fn test_serialize_seismic_call_params() {
+        let data = SeismicCallRequest::Bytes(bytes!("4a01"));
+        let block = BlockId::Number(BlockNumberOrTag::Number(1));
+        let overrides = StateOverride::default();
+
+        // Expected: [data]
+        let params: SeismicCallParams<'_, Ethereum> = EthCallParams::new(&data);
+        assert_eq!(serde_json::to_string(&params).unwrap(), r#"["0x4a01"]"#);
+
+        // Expected: [data, block, overrides]
+        let params: SeismicCallParams<'_, Ethereum> =
+            EthCallParams::new(&data).with_block(block).with_overrides(&overrides);
+        assert_eq!(serde_json::to_string(&params).unwrap(), r#"["0x4a01","0x1",{}]"#);
+
+        // Expected: [data, (default), overrides]
+        let params: SeismicCallParams<'_, Ethereum> =
+            EthCallParams::new(&data).with_overrides(&overrides);
+        assert_eq!(serde_json::to_string(&params).unwrap(), r#"["0x4a01","latest",{}]"#);
+
+        // Expected: [data, block]
+        let params: SeismicCallParams<'_, Ethereum> = EthCallParams::new(&data).with_block(block);
+        assert_eq!(serde_json::to_string(&params).unwrap(), r#"["0x4a01","0x1"]"#);
+    }
 }
@@ -5,11 +5,12 @@
 use crate::{
     heart::PendingTransactionError,
     utils::{self, Eip1559Estimation, EstimatorFunction},
-    EthCall, Identity, PendingTransaction, PendingTransactionBuilder, PendingTransactionConfig,
-    ProviderBuilder, ProviderCall, RootProvider, RpcWithBlock, SendableTx,
+    EthCall, Identity, LogStream, PendingTransaction, PendingTransactionBuilder,
+    PendingTransactionConfig, ProviderBuilder, ProviderCall, RootProvider, RpcWithBlock,
+    SeismicCall, SeismicCallOptions, SendableTx,
 };
 use alloy_consensus::BlockHeader;
-use alloy_eips::eip2718::Encodable2718;
+use alloy_eips::{eip2718::Encodable2718, eip712::TypedDataRequest};
 use alloy_json_rpc::{RpcError, RpcParam, RpcReturn};
 use alloy_network::{Ethereum, Network};
 use alloy_network_primitives::{BlockResponse, BlockTransactionsKind, ReceiptResponse};
@@ -21,7 +22,7 @@ use alloy_rpc_client::{ClientRef, NoParams, PollerBuilder, WeakClient};
 use alloy_rpc_types_eth::{
     simulate::{SimulatePayload, SimulatedBlock},
     AccessListResult, BlockId, BlockNumberOrTag, EIP1186AccountProofResponse, FeeHistory, Filter,
-    FilterChanges, Index, Log, SyncStatus,
+    FilterChanges, Index, Log, SeismicCallRequest, SeismicRawTxRequest, SyncStatus,
 };
 use alloy_transport::{BoxTransport, Transport, TransportResult};
 use serde_json::value::RawValue;
@@ -156,20 +157,94 @@ pub trait Provider<T: Transport + Clone = BoxTransport, N: Network = Ethereum>:
     }
 
     /// Simulate a seismic transaction signed or unsigned
+    ///
+    /// Signed transactions are sent as raw bytes, the
+    /// [`SeismicTypedDataLayer`](crate::SeismicTypedDataLayer) sends those
+    /// signed over EIP-712 typed data in their typed data form.
     async fn seismic_call(&self, tx: SendableTx<N>) -> TransportResult<Bytes> {
+        self.seismic_call_with(tx, SeismicCallOptions::default()).await
+    }
+
+    /// Simulate a seismic transaction on the block and with the state
+    /// overrides of `options`, see [`seismic_call`](Self::seismic_call).
+    ///
+    /// Layers handling seismic calls override this method rather than
+    /// [`seismic_call`](Self::seismic_call), so that the options reach the
+    /// root provider.
+    async fn seismic_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
         match tx {
             SendableTx::Builder(tx) => {
-                let output = self.client().request("eth_call", (tx,)).await?;
-                Ok(output)
+                EthCall::new(self.weak_client(), &tx).seismic_options(&options).await
             }
             SendableTx::Envelope(tx) => {
-                let encoded_tx = tx.encoded_2718();
-                let output = self.client().request("eth_call", (encoded_tx,)).await?;
-                Ok(output)
+                let request = SeismicCallRequest::Bytes(tx.encoded_2718().into());
+                self.seismic_call_request(&request).seismic_options(&options).await
             }
         }
     }
 
+    /// Simulate a seismic transaction that must be signed.
+    ///
+    /// Wallet fillers on the way down the stack sign the transaction. Unlike
+    /// [`seismic_call`](Self::seismic_call), a transaction that reaches the
+    /// root provider unsigned is rejected instead of being sent as an unsigned
+    /// request.
+    async fn seismic_signed_call(&self, tx: SendableTx<N>) -> TransportResult<Bytes> {
+        self.seismic_signed_call_with(tx, SeismicCallOptions::default()).await
+    }
+
+    /// Simulate a seismic transaction that must be signed, on the block and
+    /// with the state overrides of `options`, see
+    /// [`seismic_signed_call`](Self::seismic_signed_call) and
+    /// [`seismic_call_with`](Self::seismic_call_with).
+    async fn seismic_signed_call_with(
+        &self,
+        tx: SendableTx<N>,
+        options: SeismicCallOptions,
+    ) -> TransportResult<Bytes> {
+        if tx.is_builder() {
+            return Err(crate::SeismicError::SignerRequired.into());
+        }
+        self.seismic_call_with(tx, options).await
+    }
+
+    /// Execute a [`SeismicCallRequest`] via `eth_call`.
+    ///
+    /// The request is either an unsigned transaction request, a signed raw
+    /// seismic transaction or EIP-712 typed data with its signature. Unlike
+    /// [`seismic_call`](Self::seismic_call), the request is sent as is, its
+    /// input is neither encrypted nor is the output decrypted.
+    ///
+    /// Returns a [`SeismicCall`] which can be used to set the block and state
+    /// overrides of the call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use alloy_provider::Provider;
+    /// # use alloy_eips::BlockId;
+    /// # use alloy_rpc_types_eth::{state::StateOverride, SeismicCallRequest};
+    /// # async fn example(provider: impl Provider, request: SeismicCallRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let output = provider
+    ///     .seismic_call_request(&request)
+    ///     .block(BlockId::latest())
+    ///     .overrides(&StateOverride::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "eth_call")]
+    fn seismic_call_request<'req>(
+        &self,
+        request: &'req SeismicCallRequest,
+    ) -> SeismicCall<'req, T, N> {
+        SeismicCall::new(self.weak_client(), request)
+    }
+
     /// Executes an arbitrary number of transactions on top of the requested state.
     ///
     /// The transactions are packed into individual blocks. Overrides can be provided.
@@ -480,6 +555,41 @@ pub trait Provider<T: Transport + Clone = BoxTransport, N: Network = Ethereum>:
         Ok(PollerBuilder::new(self.weak_client(), "eth_getFilterChanges", (id,)))
     }
 
+    /// Stream the logs matching the filter, backfilling them from the filter's `fromBlock` and
+    /// then following the chain, with reverts for the logs of reorged blocks.
+    ///
+    /// Returns a builder that is used to configure the stream. See [`LogStream`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// Print the USDC transfer logs since block 21000000, as the chain changes:
+    ///
+    /// ```no_run
+    /// # async fn example(provider: impl alloy_provider::Provider) -> Result<(), Box<dyn std::error::Error>> {
+    /// use alloy_primitives::{address, b256};
+    /// use alloy_provider::LogEvent;
+    /// use alloy_rpc_types_eth::Filter;
+    /// use futures::StreamExt;
+    ///
+    /// let address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    /// let transfer_signature = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    /// let filter =
+    ///     Filter::new().address(address).event_signature(transfer_signature).from_block(21000000);
+    ///
+    /// let mut stream = Box::pin(provider.log_stream(&filter).into_stream());
+    /// while let Some(event) = stream.next().await {
+    ///     match event? {
+    ///         LogEvent::Added(log) => println!("new log: {log:#?}"),
+    ///         LogEvent::Removed(log) => println!("reverted log: {log:#?}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn log_stream(&self, filter: &Filter) -> LogStream<T> {
+        LogStream::new(self.weak_client(), filter.clone())
+    }
+
     /// Watch for new pending transaction bodies by polling the provider with
     /// [`eth_getFilterChanges`](Self::get_filter_changes).
     ///
@@ -752,6 +862,37 @@ pub trait Provider<T: Transport + Clone = BoxTransport, N: Network = Ethereum>:
         Ok(PendingTransactionBuilder::new(self.root().clone(), tx_hash))
     }
 
+    /// Broadcasts a transaction signed over its [EIP-712] typed data to the
+    /// network.
+    ///
+    /// The node hashes the transaction the same way as
+    /// [`SignableTransaction::into_signed`], so the returned hash matches the
+    /// hash of the signed transaction.
+    ///
+    /// See [`send_transaction`](Self::send_transaction) for more details.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    /// [`SignableTransaction::into_signed`]: alloy_consensus::SignableTransaction::into_signed
+    async fn send_typed_data_transaction(
+        &self,
+        typed_data: TypedDataRequest,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        self.send_seismic_raw_transaction(SeismicRawTxRequest::TypedData(typed_data)).await
+    }
+
+    /// Broadcasts a [`SeismicRawTxRequest`] to the network, either the raw
+    /// bytes of a signed transaction or EIP-712 typed data with its signature.
+    ///
+    /// See [`send_transaction`](Self::send_transaction) for more details.
+    #[doc(alias = "eth_sendRawTransaction")]
+    async fn send_seismic_raw_transaction(
+        &self,
+        request: SeismicRawTxRequest,
+    ) -> TransportResult<PendingTransactionBuilder<T, N>> {
+        let tx_hash = self.client().request("eth_sendRawTransaction", (request,)).await?;
+        Ok(PendingTransactionBuilder::new(self.root().clone(), tx_hash))
+    }
+
     /// Broadcasts a transaction to the network.
     ///
     /// Returns a [`PendingTransactionBuilder`] which can be used to configure
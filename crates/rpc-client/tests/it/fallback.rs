@@ -0,0 +1,23 @@
+use alloy_primitives::U64;
+use alloy_rpc_client::RpcClient;
+use alloy_transport::{layers::FallbackLayer, mock::MockTransport, Transport};
+use similar_asserts::assert_eq;
+use tower::Layer;
+
+#[tokio::test]
+async fn it_falls_back_to_the_next_endpoint() {
+    let transports = [MockTransport::failing(), MockTransport::with_result(&U64::from(7)).unwrap()];
+    let service = FallbackLayer::new().layer(transports.iter().map(Transport::as_boxed).collect());
+    let client = RpcClient::new(service, true);
+
+    let block: U64 = client.request_noparams("eth_blockNumber").await.unwrap();
+    assert_eq!(block, U64::from(7));
+    assert_eq!(transports[0].request_count(), 1);
+    assert_eq!(transports[1].request_count(), 1);
+
+    // the failing endpoint is ranked last from now on
+    let block: U64 = client.request_noparams("eth_blockNumber").await.unwrap();
+    assert_eq!(block, U64::from(7));
+    assert_eq!(transports[0].request_count(), 1);
+    assert_eq!(transports[1].request_count(), 2);
+}
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 #![allow(missing_docs)]
 
+mod fallback;
+
 #[cfg(feature = "reqwest")]
 mod http;
 
@@ -2,7 +2,7 @@
 
 use alloy_consensus::{
     Signed, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip7702, TxEnvelope, TxLegacy,
-    Typed2718,
+    TxSeismic, TxSeismicEip1559, Typed2718,
 };
 use alloy_eips::{eip2718::Encodable2718, eip7702::SignedAuthorization};
 use alloy_network_primitives::TransactionResponse;
@@ -174,6 +174,34 @@ impl TryFrom<Transaction> for Signed<TxEip7702> {
     }
 }
 
+impl TryFrom<Transaction> for Signed<TxSeismic> {
+    type Error = ConversionError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx.inner {
+            TxEnvelope::Seismic(tx) => Ok(tx),
+            _ => Err(ConversionError::Custom(format!(
+                "expected Seismic, got {}",
+                tx.inner.tx_type()
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Signed<TxSeismicEip1559> {
+    type Error = ConversionError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx.inner {
+            TxEnvelope::SeismicEip1559(tx) => Ok(tx),
+            _ => Err(ConversionError::Custom(format!(
+                "expected SeismicEip1559, got {}",
+                tx.inner.tx_type()
+            ))),
+        }
+    }
+}
+
 impl From<Transaction> for TxEnvelope {
     fn from(tx: Transaction) -> Self {
         tx.inner
@@ -432,6 +460,63 @@ mod tests {
         assert_eq!(legacy_tx.effective_gas_price, Some(0x23237dee));
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_seismic_roundtrip() {
+        use alloy_consensus::SignableTransaction;
+        use alloy_primitives::{address, b256, hex, PrimitiveSignature};
+
+        let signature = PrimitiveSignature::new(U256::from(1), U256::from(2), true);
+        let tx = TxSeismic {
+            chain_id: 5124,
+            nonce: 3,
+            gas_price: 1_000_000_000,
+            gas_limit: 100_000,
+            to: address!("d3e8763675e4c425df46cc3b5c0f6cbdac396046").into(),
+            value: U256::from(7),
+            encryption_pubkey: hex!(
+                "028e76821eb4d77fd30223ca971c49738eb5b5b71eabe93f96b348fdce788ae5a0"
+            )
+            .into(),
+            // signed over EIP-712 typed data, the hash is not that of the RLP encoding
+            message_version: 2,
+            input: hex!("deadbeef").into(),
+        };
+        let signed = tx.clone().into_signed(signature);
+        let rpc_tx = Transaction {
+            inner: TxEnvelope::Seismic(signed.clone()),
+            block_hash: Some(b256!(
+                "6e4e53d1de650d5a5ebed19b38321db369ef1dc357904284ecf4d89b8834969c"
+            )),
+            block_number: Some(2),
+            transaction_index: Some(0),
+            effective_gas_price: Some(1_000_000_000),
+            from: address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+        };
+
+        let json = serde_json::to_value(&rpc_tx).unwrap();
+        assert_eq!(json["type"], "0x4a");
+        assert_eq!(
+            json["encryptionPubkey"],
+            "0x028e76821eb4d77fd30223ca971c49738eb5b5b71eabe93f96b348fdce788ae5a0"
+        );
+        assert_eq!(json["messageVersion"], 2);
+        assert_eq!(json["hash"], signed.hash().to_string());
+
+        let decoded = serde_json::from_value::<Transaction>(json).unwrap();
+        assert_eq!(decoded, rpc_tx);
+
+        let request = decoded.clone().into_request();
+        assert_eq!(request.encryption_pubkey, Some(tx.encryption_pubkey));
+        assert_eq!(request.message_version, Some(2));
+
+        let decoded: Signed<TxSeismic> = decoded.try_into().unwrap();
+        assert_eq!(decoded.tx(), &tx);
+        assert_eq!(decoded.hash(), signed.hash());
+        assert_eq!(decoded.signature(), &signature);
+        assert!(Signed::<TxSeismicEip1559>::try_from(rpc_tx).is_err());
+    }
+
     // <https://github.com/alloy-rs/alloy/issues/1643>
     #[test]
     fn deserialize_7702_v() {
@@ -1,8 +1,8 @@
 use crate::Log;
 use alloc::vec::Vec;
-use alloy_consensus::{ReceiptEnvelope, TxReceipt, TxType};
+use alloy_consensus::{transaction::EncryptionPublicKey, ReceiptEnvelope, TxReceipt, TxType};
 use alloy_eips::eip7702::SignedAuthorization;
-use alloy_network_primitives::ReceiptResponse;
+use alloy_network_primitives::{ReceiptResponse, TransactionResponse};
 use alloy_primitives::{Address, BlockHash, TxHash, B256};
 
 /// Transaction receipt
@@ -88,7 +88,8 @@ impl TransactionReceipt {
             | ReceiptEnvelope::Eip4844(receipt)
             | ReceiptEnvelope::Eip7702(receipt)
             | ReceiptEnvelope::Legacy(receipt)
-            | ReceiptEnvelope::Seismic(receipt) => receipt.receipt.status.coerce_status(),
+            | ReceiptEnvelope::Seismic(receipt)
+            | ReceiptEnvelope::SeismicEip1559(receipt) => receipt.receipt.status.coerce_status(),
             _ => false,
         }
     }
@@ -99,6 +100,37 @@ impl TransactionReceipt {
         self.inner.tx_type()
     }
 
+    /// Returns true if this is the receipt of a seismic transaction.
+    pub const fn is_seismic(&self) -> bool {
+        matches!(self.inner, ReceiptEnvelope::Seismic(_) | ReceiptEnvelope::SeismicEip1559(_))
+    }
+
+    /// Returns the encryption public key of `tx`, if this is the receipt of `tx` and `tx` is a
+    /// seismic transaction.
+    ///
+    /// Receipts don't carry the key themselves, so it is read from the transaction they are for.
+    pub fn encryption_pubkey<'a, Tx: TransactionResponse>(
+        &self,
+        tx: &'a Tx,
+    ) -> Option<&'a EncryptionPublicKey> {
+        if !self.is_seismic() || tx.tx_hash() != self.transaction_hash {
+            return None;
+        }
+        tx.encryption_pubkey()
+    }
+
+    /// Returns the logs of this receipt along with the encryption public key of `tx`, if this is
+    /// the receipt of the seismic transaction `tx`, see [`Self::encryption_pubkey`].
+    ///
+    /// Logs carry no marker of whether their data is encrypted, so they are only told apart by
+    /// the transaction that emitted them.
+    pub fn encrypted_logs<'a, Tx: TransactionResponse>(
+        &'a self,
+        tx: &'a Tx,
+    ) -> Option<(&'a EncryptionPublicKey, &'a [Log])> {
+        Some((self.encryption_pubkey(tx)?, self.inner.logs()))
+    }
+
     /// Calculates the address that will be created by the transaction, if any.
     ///
     /// Returns `None` if the transaction is not a contract creation (the `to` field is set), or if
@@ -260,6 +292,139 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn seismic_receipt() {
+        let receipt_json = r#"
+        {
+            "transactionHash": "0x21f6554c28453a01e7276c1db2fc1695bb512b170818bfa98fa8136433100616",
+            "blockHash": "0x4acbdefb861ef4adedb135ca52865f6743451bfbfa35db78076f881a40401a5e",
+            "blockNumber": "0x2",
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "gasUsed": "0xbde1",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0xbde1",
+            "transactionIndex": "0x0",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "to": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+            "type": "0x4a",
+            "effectiveGasPrice": "0x3b9aca00",
+            "logs": [
+                {
+                    "address": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                    "topics": [],
+                    "data": "0x0000000000000000000000000000000000000000000000000000000000000042",
+                    "removed": false,
+                    "blockNumber": "0x2",
+                    "transactionIndex": "0x0",
+                    "logIndex": "0x0"
+                },
+                {
+                    "address": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                    "topics": [],
+                    "data": "0x00000000000000000000000000000000000000000000000000000000000000420102030405060708090a0b0c0d0e0f",
+                    "removed": false,
+                    "blockNumber": "0x2",
+                    "transactionIndex": "0x0",
+                    "logIndex": "0x1"
+                }
+            ],
+            "status": "0x1"
+        }
+        "#;
+
+        let receipt = serde_json::from_str::<TransactionReceipt>(receipt_json).unwrap();
+        assert!(receipt.is_seismic());
+        assert_eq!(receipt.transaction_type(), TxType::Seismic);
+        assert!(receipt.status());
+        assert_eq!(receipt.inner.logs().len(), 2);
+
+        let receipt_json = receipt_json.replace(r#""type": "0x4a""#, r#""type": "0x2""#);
+        let receipt = serde_json::from_str::<TransactionReceipt>(&receipt_json).unwrap();
+        assert!(!receipt.is_seismic());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn seismic_receipt_encrypted_logs() {
+        use crate::Transaction;
+        use alloy_consensus::{SignableTransaction, TxEnvelope, TxSeismic};
+        use alloy_primitives::{hex, PrimitiveSignature, U256};
+
+        let tx = TxSeismic {
+            chain_id: 5124,
+            nonce: 3,
+            gas_price: 1_000_000_000,
+            gas_limit: 100_000,
+            to: address!("5fbdb2315678afecb367f032d93f642f64180aa3").into(),
+            value: U256::ZERO,
+            encryption_pubkey: hex!(
+                "028e76821eb4d77fd30223ca971c49738eb5b5b71eabe93f96b348fdce788ae5a0"
+            )
+            .into(),
+            message_version: 0,
+            input: hex!("deadbeef").into(),
+        };
+        let signature = PrimitiveSignature::new(U256::from(1), U256::from(2), true);
+        let rpc_tx = Transaction {
+            inner: TxEnvelope::Seismic(tx.clone().into_signed(signature)),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+            from: address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+        };
+
+        let log = Log {
+            inner: alloy_primitives::Log::new_unchecked(
+                tx.to.to().copied().unwrap(),
+                vec![],
+                vec![0x42; 32].into(),
+            ),
+            ..Default::default()
+        };
+        let mut receipt = TransactionReceipt {
+            inner: ReceiptEnvelope::Seismic(ReceiptWithBloom {
+                receipt: Receipt {
+                    status: Eip658Value::Eip658(true),
+                    cumulative_gas_used: 0xbde1,
+                    logs: vec![log.clone()],
+                },
+                logs_bloom: Bloom::default(),
+            }),
+            transaction_hash: rpc_tx.tx_hash(),
+            transaction_index: None,
+            block_hash: None,
+            block_number: None,
+            gas_used: 0xbde1,
+            effective_gas_price: 1_000_000_000,
+            blob_gas_used: None,
+            blob_gas_price: None,
+            from: rpc_tx.from,
+            to: tx.to.to().copied(),
+            contract_address: None,
+            authorization_list: None,
+        };
+
+        assert_eq!(receipt.encryption_pubkey(&rpc_tx), Some(&tx.encryption_pubkey));
+        let (pubkey, logs) = receipt.encrypted_logs(&rpc_tx).unwrap();
+        assert_eq!(pubkey, &tx.encryption_pubkey);
+        assert_eq!(logs, [log]);
+
+        // the receipt of another transaction
+        let transaction_hash = receipt.transaction_hash;
+        receipt.transaction_hash = B256::ZERO;
+        assert_eq!(receipt.encryption_pubkey(&rpc_tx), None);
+        assert!(receipt.encrypted_logs(&rpc_tx).is_none());
+
+        // the receipt of a transaction that is not seismic
+        receipt.transaction_hash = transaction_hash;
+        receipt.inner =
+            ReceiptEnvelope::Eip1559(receipt.inner.as_receipt_with_bloom().unwrap().clone());
+        assert_eq!(receipt.encryption_pubkey(&rpc_tx), None);
+        assert!(receipt.encrypted_logs(&rpc_tx).is_none());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn deserialize_pre_eip658_receipt() {
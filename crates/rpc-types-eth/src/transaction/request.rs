@@ -2,8 +2,9 @@
 
 use crate::{transaction::AccessList, BlobTransactionSidecar, Transaction, TransactionTrait};
 use alloy_consensus::{
-    transaction::TxSeismic, TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant,
-    TxEip4844WithSidecar, TxEip7702, TxEnvelope, TxLegacy, TxType, Typed2718, TypedTransaction,
+    transaction::{TxSeismic, TxSeismicEip1559},
+    TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxEip7702, TxEnvelope,
+    TxLegacy, TxType, Typed2718, TypedTransaction,
 };
 use alloy_eips::eip7702::SignedAuthorization;
 use alloy_network_primitives::{TransactionBuilder4844, TransactionBuilder7702};
@@ -473,6 +474,34 @@ impl TransactionRequest {
         })
     }
 
+    /// Build a dynamic-fee seismic transaction.
+    ///
+    /// Returns an error if required fields are missing.
+    /// Use `complete_seismic_eip1559` to check if the request can be built.
+    fn build_seismic_eip1559(self) -> Result<TxSeismicEip1559, &'static str> {
+        let checked_to = self.to.ok_or("Missing 'to' field for seismic transaction.")?;
+
+        Ok(TxSeismicEip1559 {
+            chain_id: self.chain_id.ok_or("Missing 'chain_id' field for seismic transaction.")?,
+            nonce: self.nonce.ok_or("Missing 'nonce' field for seismic transaction.")?,
+            gas_limit: self.gas.ok_or("Missing 'gas_limit' for seismic transaction.")?,
+            max_fee_per_gas: self
+                .max_fee_per_gas
+                .ok_or("Missing 'max_fee_per_gas' field for seismic transaction.")?,
+            max_priority_fee_per_gas: self
+                .max_priority_fee_per_gas
+                .ok_or("Missing 'max_priority_fee_per_gas' field for seismic transaction.")?,
+            to: checked_to,
+            value: self.value.unwrap_or_default(),
+            access_list: self.access_list.unwrap_or_default(),
+            encryption_pubkey: self
+                .encryption_pubkey
+                .ok_or("Missing 'encryption_pubkey' for seismic transaction")?,
+            message_version: self.message_version.unwrap_or(0),
+            input: self.input.into_input().unwrap_or_default(),
+        })
+    }
+
     fn check_reqd_fields(&self) -> Vec<&'static str> {
         let mut missing = Vec::with_capacity(12);
         if self.nonce.is_none() {
@@ -562,12 +591,23 @@ impl TransactionRequest {
                 self.access_list = None;
                 self.authorization_list = None;
             }
+            TxType::SeismicEip1559 => {
+                self.gas_price = None;
+                self.max_fee_per_blob_gas = None;
+                self.blob_versioned_hashes = None;
+                self.sidecar = None;
+                self.authorization_list = None;
+            }
         }
     }
 
     /// Check this builder's preferred type, based on the fields that are set.
     ///
     /// Types are preferred as follows:
+    /// - Seismic EIP-1559 if encryption_pubkey is set, gas_price is unset, and either the
+    ///   transaction_type is 0x4B or one of max_fee_per_gas, max_priority_fee_per_gas or
+    ///   access_list is set
+    /// - Seismic if encryption_pubkey is set
     /// - EIP-7702 if authorization_list is set
     /// - EIP-4844 if sidecar or max_blob_fee_per_gas is set
     /// - EIP-2930 if access_list is set
@@ -575,7 +615,16 @@ impl TransactionRequest {
     /// - EIP-1559 in all other cases
     pub const fn preferred_type(&self) -> TxType {
         if self.is_seismic() {
-            TxType::Seismic
+            if self.gas_price.is_none()
+                && (matches!(self.transaction_type, Some(TxSeismicEip1559::TX_TYPE))
+                    || self.max_fee_per_gas.is_some()
+                    || self.max_priority_fee_per_gas.is_some()
+                    || self.access_list.is_some())
+            {
+                TxType::SeismicEip1559
+            } else {
+                TxType::Seismic
+            }
         } else if self.authorization_list.is_some() {
             TxType::Eip7702
         } else if self.sidecar.is_some() || self.max_fee_per_blob_gas.is_some() {
@@ -600,6 +649,7 @@ impl TransactionRequest {
         if let Err(missing) = match pref {
             TxType::Legacy => self.complete_legacy(),
             TxType::Seismic => self.complete_seismic(),
+            TxType::SeismicEip1559 => self.complete_seismic_eip1559(),
             TxType::Eip2930 => self.complete_2930(),
             TxType::Eip1559 => self.complete_1559(),
             TxType::Eip4844 => self.complete_4844(),
@@ -710,6 +760,23 @@ impl TransactionRequest {
         }
     }
 
+    /// Check if all necessary keys are present to build a dynamic-fee seismic transaction,
+    /// returning a list of keys that are missing.
+    pub fn complete_seismic_eip1559(&self) -> Result<(), Vec<&'static str>> {
+        let mut missing = self.check_reqd_fields();
+        self.check_1559_fields(&mut missing);
+
+        if self.chain_id.is_none() {
+            missing.push("chain_id");
+        }
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     /// Return the tx type this request can be built as. Computed by checking
     /// the preferred type, and then checking for completeness.
     pub fn buildable_type(&self) -> Option<TxType> {
@@ -717,6 +784,7 @@ impl TransactionRequest {
         match pref {
             TxType::Legacy => self.complete_legacy().ok(),
             TxType::Seismic => self.complete_seismic().ok(), // the same as legacy
+            TxType::SeismicEip1559 => self.complete_seismic_eip1559().ok(),
             TxType::Eip2930 => self.complete_2930().ok(),
             TxType::Eip1559 => self.complete_1559().ok(),
             TxType::Eip4844 => self.complete_4844().ok(),
@@ -737,6 +805,7 @@ impl TransactionRequest {
         Ok(match tx_type {
             TxType::Legacy => self.build_legacy().expect("checked)").into(),
             TxType::Seismic => self.build_seismic().expect("checked)").into(),
+            TxType::SeismicEip1559 => self.build_seismic_eip1559().expect("checked)").into(),
             TxType::Eip2930 => self.build_2930().expect("checked)").into(),
             TxType::Eip1559 => self.build_1559().expect("checked)").into(),
             // `sidecar` is a hard requirement since this must be a _sendable_ transaction.
@@ -760,6 +829,7 @@ impl TransactionRequest {
         match self.preferred_type() {
             TxType::Legacy => self.clone().build_legacy().map(Into::into),
             TxType::Seismic => self.clone().build_seismic().map(Into::into),
+            TxType::SeismicEip1559 => self.clone().build_seismic_eip1559().map(Into::into),
             TxType::Eip2930 => self.clone().build_2930().map(Into::into),
             TxType::Eip1559 => self.clone().build_1559().map(Into::into),
             TxType::Eip4844 => self.clone().build_4844_variant().map(Into::into),
@@ -995,11 +1065,46 @@ impl From<TxSeismic> for TransactionRequest {
     }
 }
 
+impl From<TxSeismicEip1559> for TransactionRequest {
+    fn from(tx: TxSeismicEip1559) -> Self {
+        let ty = tx.ty();
+        let TxSeismicEip1559 {
+            chain_id,
+            nonce,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to,
+            value,
+            access_list,
+            encryption_pubkey,
+            message_version,
+            input,
+        } = tx;
+        Self {
+            to: Some(to.into()),
+            gas: Some(gas_limit),
+            max_fee_per_gas: Some(max_fee_per_gas),
+            max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+            value: Some(value),
+            input: input.into(),
+            nonce: Some(nonce),
+            chain_id: Some(chain_id),
+            access_list: Some(access_list),
+            transaction_type: Some(ty),
+            encryption_pubkey: Some(encryption_pubkey),
+            message_version: Some(message_version),
+            ..Default::default()
+        }
+    }
+}
+
 impl From<TypedTransaction> for TransactionRequest {
     fn from(tx: TypedTransaction) -> Self {
         match tx {
             TypedTransaction::Legacy(tx) => tx.into(),
             TypedTransaction::Seismic(tx) => tx.into(),
+            TypedTransaction::SeismicEip1559(tx) => tx.into(),
             TypedTransaction::Eip2930(tx) => tx.into(),
             TypedTransaction::Eip1559(tx) => tx.into(),
             TypedTransaction::Eip4844(tx) => tx.into(),
@@ -1096,6 +1201,40 @@ impl From<TxEnvelope> for TransactionRequest {
                     tx.strip_signature().into()
                 }
             }
+            TxEnvelope::Seismic(tx) => {
+                #[cfg(feature = "k256")]
+                {
+                    let from = tx.recover_signer().ok();
+                    let tx: Self = tx.strip_signature().into();
+                    if let Some(from) = from {
+                        tx.from(from)
+                    } else {
+                        tx
+                    }
+                }
+
+                #[cfg(not(feature = "k256"))]
+                {
+                    tx.strip_signature().into()
+                }
+            }
+            TxEnvelope::SeismicEip1559(tx) => {
+                #[cfg(feature = "k256")]
+                {
+                    let from = tx.recover_signer().ok();
+                    let tx: Self = tx.strip_signature().into();
+                    if let Some(from) = from {
+                        tx.from(from)
+                    } else {
+                        tx
+                    }
+                }
+
+                #[cfg(not(feature = "k256"))]
+                {
+                    tx.strip_signature().into()
+                }
+            }
             _ => Default::default(),
         }
     }
@@ -1524,5 +1663,55 @@ mod tests {
                 eip4844_request_incorrect_to.build_consensus_tx();
             assert_matches!(maybe_eip4844_tx, Err(..));
         }
+
+        // Seismic
+        {
+            use alloy_consensus::transaction::EncryptionPublicKey;
+
+            // Legacy gas price
+            let seismic_request = TransactionRequest {
+                to: Some(TxKind::Call(Address::repeat_byte(0xDE))),
+                gas_price: Some(1234),
+                nonce: Some(57),
+                gas: Some(123456),
+                chain_id: Some(1),
+                encryption_pubkey: Some(EncryptionPublicKey::repeat_byte(0x02)),
+                ..Default::default()
+            };
+            assert_eq!(seismic_request.preferred_type(), TxType::Seismic);
+            let maybe_seismic_tx: Result<TypedTransaction, _> =
+                seismic_request.build_consensus_tx();
+            assert_matches!(maybe_seismic_tx, Ok(TypedTransaction::Seismic(..)));
+
+            // Dynamic fee
+            let max_prio_fee = 987;
+            let seismic_request = TransactionRequest {
+                to: Some(TxKind::Call(Address::repeat_byte(0xDE))),
+                max_fee_per_gas: Some(1234),
+                max_priority_fee_per_gas: Some(max_prio_fee),
+                nonce: Some(57),
+                gas: Some(123456),
+                chain_id: Some(1),
+                encryption_pubkey: Some(EncryptionPublicKey::repeat_byte(0x02)),
+                ..Default::default()
+            };
+            assert_eq!(seismic_request.preferred_type(), TxType::SeismicEip1559);
+            let maybe_seismic_tx: Result<TypedTransaction, _> =
+                seismic_request.clone().build_consensus_tx();
+            assert_matches!(maybe_seismic_tx, Ok(TypedTransaction::SeismicEip1559(TxSeismicEip1559 { max_priority_fee_per_gas, .. })) if max_priority_fee_per_gas == max_prio_fee);
+
+            // Dynamic fee requested by type, fees still missing
+            let seismic_request_missing_fees = TransactionRequest {
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                transaction_type: Some(TxSeismicEip1559::TX_TYPE),
+                ..seismic_request
+            };
+            assert_eq!(seismic_request_missing_fees.preferred_type(), TxType::SeismicEip1559);
+            assert_eq!(
+                seismic_request_missing_fees.complete_seismic_eip1559(),
+                Err(vec!["max_fee_per_gas", "max_priority_fee_per_gas"])
+            );
+        }
     }
 }
@@ -77,6 +77,12 @@ impl alloy_network::TxSigner<Signature> for LedgerSigner {
             encoded => sign_transaction_with_chain_id!(self, tx, self.sign_tx_rlp(encoded).await),
         }
     }
+
+    #[cfg(feature = "eip712")]
+    #[inline]
+    async fn sign_dynamic_typed_data(&self, payload: &TypedData) -> Result<Signature> {
+        Signer::sign_dynamic_typed_data(self, payload).await
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
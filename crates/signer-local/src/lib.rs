@@ -7,6 +7,8 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 use alloy_consensus::SignableTransaction;
+#[cfg(feature = "eip712")]
+use alloy_dyn_abi::TypedData;
 use alloy_network::{TxSigner, TxSignerSync};
 use alloy_primitives::{Address, ChainId, PrimitiveSignature as Signature, B256};
 use alloy_signer::{sign_transaction_with_chain_id, Result, Signer, SignerSync};
@@ -190,6 +192,19 @@ where
     ) -> alloy_signer::Result<Signature> {
         sign_transaction_with_chain_id!(self, tx, self.sign_hash_sync(&tx.signature_hash()))
     }
+
+    #[cfg(feature = "eip712")]
+    async fn sign_dynamic_typed_data(&self, payload: &TypedData) -> Result<Signature> {
+        if let (Some(signer), Some(tx)) = (self.chain_id, payload.domain.chain_id) {
+            if alloy_primitives::U256::from(signer) != tx {
+                return Err(alloy_signer::Error::TransactionChainIdMismatch {
+                    signer,
+                    tx: tx.saturating_to(),
+                });
+            }
+        }
+        self.sign_hash_sync(&payload.eip712_signing_hash().map_err(alloy_signer::Error::other)?)
+    }
 }
 
 impl<C> TxSignerSync<Signature> for LocalSigner<C>
@@ -289,4 +304,72 @@ mod test {
         let expected_error = alloy_signer::Error::TransactionChainIdMismatch { signer: 1, tx: 2 };
         assert_eq!(error.to_string(), expected_error.to_string());
     }
+
+    #[tokio::test]
+    #[cfg(feature = "eip712")]
+    async fn signs_seismic_typed_data() {
+        use alloy_consensus::{TxEnvelope, TxSeismic};
+        use alloy_eips::eip712::Encodable712;
+        use alloy_network::{EthereumWallet, NetworkWallet};
+        use alloy_primitives::FixedBytes;
+
+        let signer: PrivateKeySigner =
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".parse().unwrap();
+        let mut tx = TxSeismic {
+            chain_id: 1,
+            nonce: 2,
+            gas_price: 21_000_000_000,
+            gas_limit: 2_000_000,
+            to: address!("F0109fC8DF283027b6285cc889F5aA624EaC1F55").into(),
+            value: U256::from(1_000_000_000),
+            encryption_pubkey: FixedBytes::repeat_byte(2),
+            message_version: 2,
+            input: Default::default(),
+        };
+
+        // signing the typed data is equivalent to signing the signature hash
+        let sig = TxSigner::sign_dynamic_typed_data(&signer, &tx.eip712_to_type_data().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(sig, TxSigner::sign_transaction(&signer, &mut tx).await.unwrap());
+        assert_eq!(
+            sig.recover_address_from_prehash(&tx.signature_hash()).unwrap(),
+            signer.address()
+        );
+
+        let wallet = EthereumWallet::new(signer);
+        let envelope = NetworkWallet::<alloy_network::Ethereum>::sign_transaction_from(
+            &wallet,
+            wallet.default_signer().address(),
+            tx.clone().into(),
+        )
+        .await
+        .unwrap();
+        let TxEnvelope::Seismic(signed) = &envelope else { panic!("expected seismic tx") };
+        assert_eq!(signed.signature(), &sig);
+        assert_eq!(signed.hash(), tx.clone().into_signed(sig).hash());
+
+        let typed_data = envelope.encode_712().unwrap().unwrap();
+        assert_eq!(typed_data.data, tx.eip712_to_type_data().unwrap());
+        assert_eq!(typed_data.signature, sig);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "eip712")]
+    async fn typed_data_chain_id_mismatch() {
+        use alloy_consensus::TxSeismic;
+
+        let mut signer: PrivateKeySigner =
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".parse().unwrap();
+        let tx = TxSeismic { chain_id: 2, message_version: 2, ..Default::default() };
+        let typed_data = tx.eip712_to_type_data().unwrap();
+
+        signer.set_chain_id(Some(1));
+        let error = TxSigner::sign_dynamic_typed_data(&signer, &typed_data).await.unwrap_err();
+        let expected_error = alloy_signer::Error::TransactionChainIdMismatch { signer: 1, tx: 2 };
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        signer.set_chain_id(Some(2));
+        assert!(TxSigner::sign_dynamic_typed_data(&signer, &typed_data).await.is_ok());
+    }
 }
@@ -0,0 +1,722 @@
+use crate::{
+    error::{TransportError, TransportErrorKind},
+    utils::Spawnable,
+    BoxTransport, TransportFut, TransportResult,
+};
+use alloy_json_rpc::{
+    Id, Request, RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest,
+};
+use futures_util::{
+    future::join_all,
+    stream::{FuturesUnordered, StreamExt},
+};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, Once, PoisonError, Weak,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower::{Layer, Service};
+use tracing::{debug, trace};
+
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::{std::Instant, tokio::sleep};
+
+#[cfg(not(target_arch = "wasm32"))]
+use {std::time::Instant, tokio::time::sleep};
+
+/// Weight of the latest sample in the moving averages of latency and error rate.
+const EWMA_WEIGHT: f64 = 0.25;
+
+/// Score penalty, in milliseconds, of an endpoint whose requests all fail.
+const ERROR_RATE_PENALTY_MS: f64 = 1_000.0;
+
+/// Score penalty, in milliseconds, of each block an endpoint is behind the most advanced one.
+const HEAD_LAG_PENALTY_MS: f64 = 250.0;
+
+/// Error rate above which an endpoint is not used for round-robin reads.
+const MAX_READ_ERROR_RATE: f64 = 0.5;
+
+/// Number of blocks an endpoint can be behind before it is not used for round-robin reads.
+const MAX_READ_HEAD_LAG: u64 = 2;
+
+/// A Transport Layer that spreads requests across several transports, falling back to the next
+/// one when a request fails. See [`FallbackService`].
+///
+/// The layer wraps a `Vec<BoxTransport>`, and the resulting service is itself a transport, so it
+/// can be passed to `RpcClient::new` and from there to `ProviderBuilder::on_client`.
+///
+/// By default, every request is sent to the healthiest endpoint, and to the next healthiest one
+/// if it fails. Endpoints are ranked by a health score combining their latency, error rate, and
+/// how many blocks they are behind the most advanced endpoint. The last one is only known if
+/// the [health probe](FallbackLayer::with_health_probe) is enabled.
+///
+/// Filters only exist on the endpoint that created them, so the requests to a filter, e.g.
+/// `eth_getFilterChanges`, are only sent to that endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct FallbackLayer {
+    /// Whether read requests are spread over the healthy endpoints in turn.
+    round_robin_reads: bool,
+    /// The quorum required for some methods, if any.
+    quorum: Option<Quorum>,
+    /// The interval at which the endpoints are probed, if any.
+    probe_interval: Option<Duration>,
+}
+
+impl FallbackLayer {
+    /// Creates a new fallback layer that sends every request to the healthiest endpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether read requests, e.g. `eth_call` or `eth_getBalance`, are sent to the healthy
+    /// endpoints in turn rather than always to the healthiest one.
+    pub const fn with_round_robin_reads(mut self, round_robin_reads: bool) -> Self {
+        self.round_robin_reads = round_robin_reads;
+        self
+    }
+
+    /// Requires the responses of `required` endpoints to agree for requests to the given methods.
+    ///
+    /// Such requests are sent to all the endpoints at once. The first `required` successful
+    /// responses are compared, and the request fails with a [`QuorumError`] if they differ or if
+    /// fewer endpoints respond successfully.
+    pub fn with_quorum<I, S>(mut self, required: usize, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.quorum = Some(Quorum {
+            required: required.max(1),
+            methods: methods.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Probes every endpoint with `eth_blockNumber` at the given interval, in a background task,
+    /// to keep their health up to date and find out how far behind they are.
+    ///
+    /// The task is spawned along with the first request, so that the service can be built outside
+    /// of an async runtime, and stops once the service is dropped.
+    pub const fn with_health_probe(mut self, interval: Duration) -> Self {
+        self.probe_interval = Some(interval);
+        self
+    }
+}
+
+impl Layer<Vec<BoxTransport>> for FallbackLayer {
+    type Service = FallbackService;
+
+    fn layer(&self, transports: Vec<BoxTransport>) -> Self::Service {
+        let endpoints = transports
+            .into_iter()
+            .map(|transport| Endpoint { transport, health: Mutex::default() })
+            .collect();
+        FallbackService {
+            inner: Arc::new(FallbackInner {
+                endpoints,
+                round_robin_reads: self.round_robin_reads,
+                quorum: self.quorum.clone(),
+                next_read: AtomicUsize::new(0),
+                probe_interval: self.probe_interval,
+                probe_started: Once::new(),
+                filters: Mutex::default(),
+            }),
+        }
+    }
+}
+
+/// The methods that require a quorum, and how many endpoints must agree.
+#[derive(Debug, Clone)]
+struct Quorum {
+    required: usize,
+    methods: HashSet<String>,
+}
+
+impl Quorum {
+    /// Returns true if the request calls any of the methods that require a quorum.
+    fn applies_to(&self, request: &RequestPacket) -> bool {
+        requests(request).iter().any(|req| self.methods.contains(req.method()))
+    }
+}
+
+/// Error returned when a request requiring a quorum fails to reach it.
+#[derive(Debug, thiserror::Error)]
+pub enum QuorumError {
+    /// Fewer endpoints than required responded successfully.
+    #[error("quorum not reached: {received} of {required} endpoints responded successfully")]
+    NotReached {
+        /// The number of endpoints required to agree.
+        required: usize,
+        /// The number of endpoints that responded successfully.
+        received: usize,
+    },
+    /// The endpoints responded with different results.
+    #[error("quorum not reached: the responses of {required} endpoints disagree")]
+    Disagreement {
+        /// The number of endpoints required to agree.
+        required: usize,
+    },
+}
+
+/// Health of an endpoint, as observed by a [`FallbackService`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EndpointHealth {
+    /// Moving average of the latency of requests to the endpoint.
+    pub latency: Duration,
+    /// Moving average of the fraction of requests to the endpoint that failed, from 0 to 1.
+    pub error_rate: f64,
+    /// The latest block number reported by the endpoint, if it has been probed.
+    pub head: Option<u64>,
+    /// The number of blocks the endpoint is behind the most advanced endpoint.
+    pub head_lag: u64,
+}
+
+impl EndpointHealth {
+    /// Returns the score of the endpoint, in milliseconds. Lower is healthier.
+    pub fn score(&self) -> f64 {
+        self.latency.as_secs_f64() * 1_000.0
+            + self.error_rate * ERROR_RATE_PENALTY_MS
+            + self.head_lag as f64 * HEAD_LAG_PENALTY_MS
+    }
+
+    /// Returns true if the endpoint can serve round-robin reads.
+    fn is_healthy(&self) -> bool {
+        self.error_rate < MAX_READ_ERROR_RATE && self.head_lag <= MAX_READ_HEAD_LAG
+    }
+
+    /// Records the outcome of a request.
+    fn record(&mut self, latency: Duration, failed: bool) {
+        if self.latency.is_zero() {
+            self.latency = latency;
+        } else {
+            self.latency = self.latency.mul_f64(1.0 - EWMA_WEIGHT) + latency.mul_f64(EWMA_WEIGHT);
+        }
+        let sample = if failed { 1.0 } else { 0.0 };
+        self.error_rate = self.error_rate * (1.0 - EWMA_WEIGHT) + sample * EWMA_WEIGHT;
+    }
+}
+
+/// A Tower Service used by the FallbackLayer that is responsible for spreading requests across
+/// several transports. See [`FallbackLayer`].
+#[derive(Debug, Clone)]
+pub struct FallbackService {
+    inner: Arc<FallbackInner>,
+}
+
+#[derive(Debug)]
+struct FallbackInner {
+    /// The endpoints, in the order they were given.
+    endpoints: Vec<Endpoint>,
+    /// Whether read requests are spread over the healthy endpoints in turn.
+    round_robin_reads: bool,
+    /// The quorum required for some methods, if any.
+    quorum: Option<Quorum>,
+    /// Counter used to pick the endpoint serving the next round-robin read.
+    next_read: AtomicUsize,
+    /// The interval at which the endpoints are probed, if any.
+    probe_interval: Option<Duration>,
+    /// Set once the health probe was spawned.
+    probe_started: Once,
+    /// The index of the endpoint that created each filter, by filter ID.
+    filters: Mutex<HashMap<String, usize>>,
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    transport: BoxTransport,
+    health: Mutex<EndpointHealth>,
+}
+
+impl Endpoint {
+    fn health(&self) -> MutexGuard<'_, EndpointHealth> {
+        self.health.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl FallbackService {
+    /// Returns the health of every endpoint, in the order the transports were given.
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let mut health: Vec<_> =
+            self.inner.endpoints.iter().map(|endpoint| *endpoint.health()).collect();
+        let best_head = health.iter().filter_map(|health| health.head).max();
+        if let Some(best_head) = best_head {
+            for health in &mut health {
+                health.head_lag = health.head.map_or(0, |head| best_head.saturating_sub(head));
+            }
+        }
+        health
+    }
+
+    /// Returns the indices of the endpoints to try for the request, in order.
+    fn order(&self, request: &RequestPacket) -> Vec<usize> {
+        let health = self.health();
+
+        let mut ranked: Vec<usize> = (0..health.len()).collect();
+        ranked.sort_by(|a, b| health[*a].score().total_cmp(&health[*b].score()));
+
+        let is_read = requests(request).iter().all(|req| is_read_method(req.method()));
+        if !self.inner.round_robin_reads || !is_read {
+            return ranked;
+        }
+
+        // Healthy endpoints take turns in a fixed order, so that reads are spread evenly, and the
+        // others are only used as fallbacks.
+        let mut healthy: Vec<usize> =
+            (0..health.len()).filter(|index| health[*index].is_healthy()).collect();
+        if healthy.is_empty() {
+            return ranked;
+        }
+        let next = self.inner.next_read.fetch_add(1, Ordering::Relaxed) % healthy.len();
+        healthy.rotate_left(next);
+        healthy.extend(ranked.into_iter().filter(|index| !health[*index].is_healthy()));
+        healthy
+    }
+
+    /// Sends the request to the endpoint with the given index, and records the outcome in its
+    /// health.
+    async fn send_to(
+        &self,
+        index: usize,
+        request: RequestPacket,
+    ) -> TransportResult<ResponsePacket> {
+        let endpoint = &self.inner.endpoints[index];
+        let start = Instant::now();
+        let result = endpoint.transport.clone().call(request).await;
+        let failed = result.as_ref().map_or(true, is_retryable);
+        endpoint.health().record(start.elapsed(), failed);
+        result
+    }
+
+    /// Sends the request to the endpoints in order, until one responds successfully.
+    ///
+    /// Responses with a retryable error, e.g. a rate limit, also move on to the next endpoint. If
+    /// every endpoint fails, the last outcome is returned.
+    async fn send_with_fallback(&self, request: RequestPacket) -> TransportResult<ResponsePacket> {
+        let mut last = None;
+        for index in self.order(&request) {
+            match self.send_to(index, request.clone()).await {
+                Ok(response) if !is_retryable(&response) => {
+                    self.record_filters(index, &request, &response);
+                    return Ok(response);
+                }
+                Ok(response) => {
+                    trace!(endpoint = index, "endpoint returned a retryable error, trying next");
+                    last = Some(Ok(response));
+                }
+                Err(err) => {
+                    trace!(%err, endpoint = index, "endpoint failed, trying next");
+                    last = Some(Err(err));
+                }
+            }
+        }
+        last.unwrap_or_else(|| Err(TransportErrorKind::custom_str("no endpoints to send to")))
+    }
+
+    /// Sends the request to all the endpoints at once, and returns the response once the quorum
+    /// agrees on it.
+    async fn send_with_quorum(
+        &self,
+        quorum: &Quorum,
+        request: RequestPacket,
+    ) -> TransportResult<ResponsePacket> {
+        let mut pending: FuturesUnordered<_> = self
+            .order(&request)
+            .into_iter()
+            .map(|index| self.send_to(index, request.clone()))
+            .collect();
+
+        let mut responses = Vec::with_capacity(quorum.required);
+        while let Some(result) = pending.next().await {
+            match result {
+                Ok(response) if !is_retryable(&response) => {
+                    responses.push(response);
+                    if responses.len() == quorum.required {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => trace!(%err, "endpoint failed while gathering quorum"),
+            }
+        }
+
+        if responses.len() < quorum.required {
+            return Err(TransportErrorKind::custom(QuorumError::NotReached {
+                required: quorum.required,
+                received: responses.len(),
+            }));
+        }
+
+        let expected = fingerprint(&responses[0]);
+        if responses[1..].iter().any(|response| fingerprint(response) != expected) {
+            debug!(required = quorum.required, "endpoints disagree");
+            return Err(TransportErrorKind::custom(QuorumError::Disagreement {
+                required: quorum.required,
+            }));
+        }
+
+        Ok(responses.swap_remove(0))
+    }
+
+    /// Returns the index of the endpoint that created the filter the request is about, if known.
+    fn filter_endpoint(&self, request: &RequestPacket) -> Option<usize> {
+        let filters = self.inner.filters.lock().unwrap_or_else(PoisonError::into_inner);
+        requests(request)
+            .iter()
+            .filter(|req| is_filter_method(req.method()))
+            .find_map(|req| filters.get(&filter_id(req)?).copied())
+    }
+
+    /// Records which endpoint created the filters in the response, and forgets the uninstalled
+    /// ones.
+    fn record_filters(&self, index: usize, request: &RequestPacket, response: &ResponsePacket) {
+        let mut filters = self.inner.filters.lock().unwrap_or_else(PoisonError::into_inner);
+        for req in requests(request) {
+            if req.method() == "eth_uninstallFilter" {
+                if let Some(id) = filter_id(req) {
+                    filters.remove(&id);
+                }
+                continue;
+            }
+            if !is_new_filter_method(req.method()) {
+                continue;
+            }
+            let created = responses(response).iter().find(|resp| resp.id == *req.id());
+            if let Some(Response { payload: ResponsePayload::Success(result), .. }) = created {
+                if let Ok(id) = serde_json::from_str::<String>(result.get()) {
+                    filters.insert(id, index);
+                }
+            }
+        }
+    }
+
+    /// Sends a request about a filter to the endpoint that created it.
+    async fn send_to_filter_endpoint(
+        &self,
+        index: usize,
+        request: RequestPacket,
+    ) -> TransportResult<ResponsePacket> {
+        let response = self.send_to(index, request.clone()).await?;
+        self.record_filters(index, &request, &response);
+        Ok(response)
+    }
+
+    /// Spawns the health probe, if enabled and not spawned yet.
+    fn start_health_probe(&self) {
+        if let Some(interval) = self.inner.probe_interval {
+            self.inner
+                .probe_started
+                .call_once(|| spawn_health_probe(Arc::downgrade(&self.inner), interval));
+        }
+    }
+
+    /// Probes every endpoint with `eth_blockNumber`, recording the block they report.
+    async fn probe(&self) {
+        let Ok(request) = Request::new("eth_blockNumber", Id::Number(0), [(); 0]).serialize()
+        else {
+            return;
+        };
+        let request = RequestPacket::Single(request);
+
+        let probes = (0..self.inner.endpoints.len()).map(|index| {
+            let request = request.clone();
+            async move { (index, self.send_to(index, request).await) }
+        });
+        for (index, result) in join_all(probes).await {
+            let head = result.ok().as_ref().and_then(parse_block_number);
+            if head.is_some() {
+                self.inner.endpoints[index].health().head = head;
+            }
+        }
+    }
+}
+
+impl Service<RequestPacket> for FallbackService {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Each request picks its endpoints when it is sent, so the service is always ready.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let this = self.clone();
+        Box::pin(async move {
+            // Spawned from the future, which runs on the runtime of the caller.
+            this.start_health_probe();
+            if let Some(index) = this.filter_endpoint(&request) {
+                return this.send_to_filter_endpoint(index, request).await;
+            }
+            match this.inner.quorum.as_ref().filter(|quorum| quorum.applies_to(&request)) {
+                Some(quorum) => this.send_with_quorum(quorum, request).await,
+                None => this.send_with_fallback(request).await,
+            }
+        })
+    }
+}
+
+/// Spawns a task probing the endpoints at the given interval, until the service is dropped.
+fn spawn_health_probe(inner: Weak<FallbackInner>, interval: Duration) {
+    async move {
+        while let Some(inner) = inner.upgrade() {
+            FallbackService { inner }.probe().await;
+            sleep(interval).await;
+        }
+    }
+    .spawn_task();
+}
+
+/// Returns the requests in the packet.
+fn requests(request: &RequestPacket) -> &[SerializedRequest] {
+    match request {
+        RequestPacket::Single(req) => std::slice::from_ref(req),
+        RequestPacket::Batch(reqs) => reqs,
+    }
+}
+
+/// Returns the responses in the packet.
+fn responses(response: &ResponsePacket) -> &[Response] {
+    match response {
+        ResponsePacket::Single(resp) => std::slice::from_ref(resp),
+        ResponsePacket::Batch(resps) => resps,
+    }
+}
+
+/// Returns true if the method only reads chain state, so it can be served by any endpoint.
+///
+/// Filter methods are excluded, as filters only exist on the endpoint that created them.
+fn is_read_method(method: &str) -> bool {
+    matches!(
+        method,
+        "eth_blockNumber"
+            | "eth_call"
+            | "eth_chainId"
+            | "eth_createAccessList"
+            | "eth_estimateGas"
+            | "eth_feeHistory"
+            | "eth_gasPrice"
+            | "eth_maxPriorityFeePerGas"
+            | "eth_blobBaseFee"
+            | "eth_syncing"
+            | "net_version"
+            | "web3_clientVersion"
+    ) || (method.starts_with("eth_get") && !method.starts_with("eth_getFilter"))
+}
+
+/// Returns true if the method creates a filter.
+fn is_new_filter_method(method: &str) -> bool {
+    matches!(method, "eth_newFilter" | "eth_newBlockFilter" | "eth_newPendingTransactionFilter")
+}
+
+/// Returns true if the method is about an existing filter.
+fn is_filter_method(method: &str) -> bool {
+    matches!(method, "eth_getFilterChanges" | "eth_getFilterLogs" | "eth_uninstallFilter")
+}
+
+/// Returns the ID of the filter the request is about, its first parameter.
+fn filter_id(req: &SerializedRequest) -> Option<String> {
+    let (id,): (String,) = serde_json::from_str(req.params()?.get()).ok()?;
+    Some(id)
+}
+
+/// Returns true if the response contains an error that another endpoint may not return, e.g. a
+/// rate limit.
+fn is_retryable(response: &ResponsePacket) -> bool {
+    response.iter_errors().any(|err| err.is_retry_err())
+}
+
+/// Returns the content of the responses in the packet, ordered by request ID, so that the
+/// responses of different endpoints can be compared regardless of formatting.
+fn fingerprint(response: &ResponsePacket) -> Vec<(String, Value)> {
+    let mut fingerprint: Vec<_> = responses(response)
+        .iter()
+        .map(|resp| {
+            let value = match &resp.payload {
+                ResponsePayload::Success(result) => {
+                    serde_json::from_str(result.get()).unwrap_or(Value::Null)
+                }
+                ResponsePayload::Failure(err) => {
+                    serde_json::json!({ "code": err.code, "message": err.message })
+                }
+            };
+            (resp.id.to_string(), value)
+        })
+        .collect();
+    fingerprint.sort_by(|a, b| a.0.cmp(&b.0));
+    fingerprint
+}
+
+/// Parses the block number from an `eth_blockNumber` response.
+fn parse_block_number(response: &ResponsePacket) -> Option<u64> {
+    let ResponsePacket::Single(Response { payload: ResponsePayload::Success(result), .. }) =
+        response
+    else {
+        return None;
+    };
+    let number: String = serde_json::from_str(result.get()).ok()?;
+    u64::from_str_radix(number.strip_prefix("0x")?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::MockTransport, Transport};
+    use serde_json::value::RawValue;
+    use std::future::Future;
+
+    /// A transport that responds to every request with the same result, or fails if there is none.
+    fn mock(result: Option<&'static str>) -> MockTransport {
+        match result {
+            Some(result) => MockTransport::new(move |_| {
+                Ok(ResponsePayload::Success(RawValue::from_string(result.to_string()).unwrap()))
+            }),
+            None => MockTransport::failing(),
+        }
+    }
+
+    fn fallback(layer: FallbackLayer, transports: &[MockTransport]) -> FallbackService {
+        layer.layer(transports.iter().map(Transport::as_boxed).collect())
+    }
+
+    fn request(method: &'static str) -> RequestPacket {
+        Request::new(method, Id::Number(1), [(); 0]).serialize().unwrap().into()
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(fut)
+    }
+
+    #[test]
+    fn falls_back_to_next_endpoint() {
+        let transports = [mock(None), mock(Some("\"0x1\""))];
+        let mut service = fallback(FallbackLayer::new(), &transports);
+
+        block_on(service.call(request("eth_sendRawTransaction"))).unwrap();
+        assert_eq!(transports[0].request_count(), 1);
+        assert!(service.health()[0].error_rate > 0.0);
+
+        // The failing endpoint is now ranked last.
+        block_on(service.call(request("eth_sendRawTransaction"))).unwrap();
+        assert_eq!(transports[0].request_count(), 1);
+        assert_eq!(transports[1].request_count(), 2);
+    }
+
+    #[test]
+    fn round_robin_reads() {
+        let transports = [
+            mock(Some("\"0x1\"")),
+            mock(Some("\"0x1\"")),
+            mock(Some("\"0x1\"")),
+        ];
+        let mut service = fallback(FallbackLayer::new().with_round_robin_reads(true), &transports);
+
+        for _ in 0..6 {
+            block_on(service.call(request("eth_getBalance"))).unwrap();
+        }
+        assert!(transports.iter().all(|transport| transport.request_count() == 2));
+    }
+
+    #[test]
+    fn quorum() {
+        let transports = [
+            mock(Some("\"0x1\"")),
+            mock(None),
+            mock(Some("\"0x1\"")),
+        ];
+        let mut service =
+            fallback(FallbackLayer::new().with_quorum(2, ["eth_getBalance"]), &transports);
+
+        block_on(service.call(request("eth_getBalance"))).unwrap();
+
+        let mut service =
+            fallback(FallbackLayer::new().with_quorum(3, ["eth_getBalance"]), &transports);
+        let err = block_on(service.call(request("eth_getBalance"))).unwrap_err();
+        assert!(err.to_string().contains("2 of 3 endpoints"));
+    }
+
+    #[test]
+    fn quorum_disagreement() {
+        let transports = [
+            mock(Some("{\"a\":1,\"b\":2}")),
+            mock(Some("{ \"b\": 2, \"a\": 1 }")),
+            mock(Some("{\"a\":1,\"b\":3}")),
+        ];
+        let mut service =
+            fallback(FallbackLayer::new().with_quorum(3, ["eth_getProof"]), &transports);
+
+        let err = block_on(service.call(request("eth_getProof"))).unwrap_err();
+        assert!(err.to_string().contains("disagree"));
+
+        // Requests to other methods are not checked.
+        block_on(service.call(request("eth_getBalance"))).unwrap();
+    }
+
+    #[test]
+    fn probe_head_lag() {
+        let transports = [
+            mock(Some("\"0x8\"")),
+            mock(Some("\"0x10\"")),
+            mock(None),
+        ];
+        let service = fallback(FallbackLayer::new(), &transports);
+
+        block_on(service.probe());
+        let health = service.health();
+        assert_eq!(health[0].head, Some(8));
+        assert_eq!(health[0].head_lag, 8);
+        assert_eq!(health[1].head_lag, 0);
+        assert_eq!(health[2].head, None);
+
+        // The lagging endpoint is ranked after the one that is up to date.
+        assert_eq!(service.order(&request("eth_sendRawTransaction"))[0], 1);
+    }
+
+    #[test]
+    fn pins_filters_to_their_endpoint() {
+        let transports = [mock(Some("\"0x1\"")), mock(Some("\"0x2\""))];
+        let mut service = fallback(FallbackLayer::new().with_round_robin_reads(true), &transports);
+
+        // the first endpoint creates the filter, then becomes the least healthy one
+        block_on(service.call(request("eth_newBlockFilter"))).unwrap();
+        service.inner.endpoints[0].health().error_rate = 1.0;
+
+        let changes = Request::new("eth_getFilterChanges", Id::Number(2), ("0x1",));
+        let changes = RequestPacket::from(changes.serialize().unwrap());
+        block_on(service.call(changes.clone())).unwrap();
+        assert_eq!(transports[0].request_count(), 2);
+        assert_eq!(transports[1].request_count(), 0);
+
+        // once uninstalled, the filter is forgotten
+        let uninstall = Request::new("eth_uninstallFilter", Id::Number(3), ("0x1",));
+        block_on(service.call(uninstall.serialize().unwrap().into())).unwrap();
+        block_on(service.call(changes)).unwrap();
+        assert_eq!(transports[0].request_count(), 3);
+        assert_eq!(transports[1].request_count(), 1);
+    }
+
+    #[test]
+    fn health_probe_starts_with_first_request() {
+        let transports = [mock(Some("\"0x1\""))];
+
+        // building the service outside of a runtime does not spawn the probe
+        let mut service = fallback(
+            FallbackLayer::new().with_health_probe(Duration::from_secs(60)),
+            &transports,
+        );
+        assert_eq!(transports[0].request_count(), 0);
+
+        block_on(async {
+            service.call(request("eth_chainId")).await.unwrap();
+            tokio::task::yield_now().await;
+        });
+        let methods: Vec<_> =
+            transports[0].requests().iter().map(|req| req.method().to_string()).collect();
+        assert_eq!(methods, ["eth_chainId", "eth_blockNumber"]);
+    }
+}
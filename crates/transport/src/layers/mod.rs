@@ -1,6 +1,10 @@
 //! Module for housing transport layers.
 
+mod fallback;
 mod retry;
 
+/// FallbackLayer
+pub use fallback::{EndpointHealth, FallbackLayer, FallbackService, QuorumError};
+
 /// RetryBackoffLayer
 pub use retry::{RateLimitRetryPolicy, RetryBackoffLayer, RetryBackoffService, RetryPolicy};
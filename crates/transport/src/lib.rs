@@ -28,6 +28,9 @@ pub use futures_utils_wasm::{impl_future, BoxFuture};
 
 pub mod layers;
 
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+
 /// Misc. utilities for building transports.
 pub mod utils;
 
@@ -0,0 +1,105 @@
+//! A transport answering requests in-process, for tests.
+
+use crate::{TransportError, TransportFut, TransportResult};
+use alloy_json_rpc::{RequestPacket, Response, ResponsePacket, ResponsePayload, SerializedRequest};
+use serde::Serialize;
+use serde_json::value::RawValue;
+use std::{
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+use tower::Service;
+
+type Handler = dyn Fn(&SerializedRequest) -> TransportResult<ResponsePayload> + Send + Sync;
+
+/// A transport answering every request with a handler, and recording the requests it receives.
+///
+/// The handler returns the payload of the response to a request, or an error to fail the whole
+/// request packet with, e.g. to simulate a connection failure.
+///
+/// ```
+/// use alloy_json_rpc::ResponsePayload;
+/// use alloy_transport::mock::MockTransport;
+///
+/// let transport = MockTransport::new(|req| match req.method() {
+///     "eth_blockNumber" => MockTransport::success(&"0x1"),
+///     _ => Ok(ResponsePayload::Failure(alloy_json_rpc::ErrorPayload::method_not_found())),
+/// });
+/// ```
+#[derive(Clone)]
+pub struct MockTransport {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<SerializedRequest>>>,
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTransport").field("requests", &self.requests()).finish_non_exhaustive()
+    }
+}
+
+impl MockTransport {
+    /// Creates a transport answering every request with the given handler.
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&SerializedRequest) -> TransportResult<ResponsePayload> + Send + Sync + 'static,
+    {
+        Self { handler: Arc::new(handler), requests: Arc::default() }
+    }
+
+    /// Creates a transport answering every request with the given result.
+    pub fn with_result<T: Serialize>(result: &T) -> TransportResult<Self> {
+        let result = serde_json::value::to_raw_value(result).map_err(TransportError::ser_err)?;
+        Ok(Self::new(move |_| Ok(ResponsePayload::Success(result.clone()))))
+    }
+
+    /// Creates a transport failing every request, as if the backend was gone.
+    pub fn failing() -> Self {
+        Self::new(|_| Err(crate::TransportErrorKind::backend_gone()))
+    }
+
+    /// Returns a successful payload holding the given result, for use in handlers.
+    pub fn success<T: Serialize + ?Sized>(result: &T) -> TransportResult<ResponsePayload> {
+        let result: Box<RawValue> =
+            serde_json::value::to_raw_value(result).map_err(TransportError::ser_err)?;
+        Ok(ResponsePayload::Success(result))
+    }
+
+    /// Returns the requests received so far, in order.
+    pub fn requests(&self) -> Vec<SerializedRequest> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Returns the number of requests received so far.
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    fn respond(&self, req: &SerializedRequest) -> TransportResult<Response> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).push(req.clone());
+        Ok(Response { id: req.id().clone(), payload: (self.handler)(req)? })
+    }
+}
+
+impl Service<RequestPacket> for MockTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let response = match request {
+            RequestPacket::Single(req) => self.respond(&req).map(ResponsePacket::Single),
+            RequestPacket::Batch(reqs) => reqs
+                .iter()
+                .map(|req| self.respond(req))
+                .collect::<TransportResult<_>>()
+                .map(ResponsePacket::Batch),
+        };
+        Box::pin(async move { response })
+    }
+}